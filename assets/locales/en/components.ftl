@@ -0,0 +1,19 @@
+components-manager = Components
+components-manager-description = Downloaded versions can be selected globally here, or per game in the game settings
+
+components-manager-downloaded     = Downloaded, {$size}
+components-manager-not-downloaded = Not downloaded
+components-manager-system         = Installed in the system
+components-manager-selected       = Selected
+
+components-manager-download = Download
+components-manager-select   = Select
+components-manager-delete   = Delete
+
+components-manager-skipped-entries = {$count ->
+    [one] Skipped {$count} malformed component entry
+    *[other] Skipped {$count} malformed component entries
+}
+
+components-updates-available = Components updates available
+components-updates-available-description = Newer versions of the selected components are available: {$components}
@@ -6,4 +6,9 @@ details-play = Play
 details-kill = Kill
 details-verify = Verify
 details-manage-addons = Manage addons
+details-settings = Settings
+details-move = Move
+details-uninstall = Uninstall
 details-download = Download
+details-import = Import
+details-pre-download = Pre-install {$version}
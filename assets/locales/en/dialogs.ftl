@@ -1,7 +1,32 @@
 dialog-toast-details = Details
 
-dialog-stop     = Stop
-dialog-disable  = Disable
-dialog-continue = Continue
-dialog-close    = Close
-dialog-save     = Save
+dialog-stop        = Stop
+dialog-disable     = Disable
+dialog-continue    = Continue
+dialog-cancel      = Cancel
+dialog-change-path = Change path
+dialog-close       = Close
+dialog-save        = Save
+dialog-verify      = Verify
+dialog-uninstall   = Uninstall
+dialog-update      = Update
+
+game-imported        = {$game-title} {$version} imported
+game-imported-verify = Verify game files to make sure that the installation is not broken
+
+game-move             = Move {$game-title}
+game-move-description = Select which folder should be moved. It will be moved into the chosen folder, and old files are deleted after moving
+game-move-game        = Game
+game-move-addons      = Addons
+game-move-deployment  = Deployment
+
+game-prefix-creating             = Creating wine prefix for {$game-title}
+game-prefix-creating-description = Launch the game again after the prefix is created
+
+game-uninstall                 = Uninstall {$game-title}?
+game-uninstall-description     = Game files, addons, its own wine prefix and unfinished downloads will be deleted. {$size} will be freed
+game-uninstall-delete-metadata = Delete playtime statistics
+game-uninstall-shared-prefix   = Wine prefix {$path} is used by other games and will be kept
+
+disk-space-insufficient = Not enough disk space
+disk-space-required     = {$path}: {$required} required, {$available} available
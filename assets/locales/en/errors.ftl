@@ -1,5 +1,7 @@
 config-property-update-failed = Failed to update property
 
+cache-clear-failed = Failed to clear downloads cache
+
 tasks-resolve-queued-failed = Failed to resolve queued task
 tasks-get-status-failed     = Failed to get {$game-title} task status
 
@@ -15,13 +17,23 @@ game-find-diff-failed           = Unable to find {$game-title} version diff
 game-find-download-failed       = Unable to find {$game-title} download info
 game-find-path-failed           = Unable to find {$game-title} installation path
 game-get-settings-failed        = Unable to get {$game-title} settings
+game-get-metadata-failed        = Unable to get {$game-title} metadata
 game-get-addons-failed          = Unable to get {$game-title} addons
 game-get-paths-failed           = Unable to get {$game-title} paths
 game-get-integrity-failed       = Unable to get {$game-title} integrity info
 game-get-status-failed          = Unable to get {$game-title} status
 game-not-installed              = {$game-title} is not installed
+game-no-pre-download            = {$game-title} has no pre-download available
+game-import-not-found           = {$game-title} installation is not found in the selected folder
+game-import-version-failed      = Unable to get {$game-title} version
 game-launch-failed              = Failed to launch {$game-title}
 game-kill-failed                = Failed to kill {$game-title}
+game-move-into-itself           = Folder can't be moved into itself
+game-move-destination-not-empty = Destination folder is not empty
+game-move-game-running          = Close the game before moving its files
+game-move-tasks-queued          = Wait until all the {$game-title} tasks are finished before moving its files
+
+diff-patch-tool-missing = Unable to apply {$game-title} patches. Make sure that hpatchz from HDiffPatch is installed
 
 launch-required-addon-unavailable = Addon {$addon-title} from group {$group-title} is unavailable or outdated. You can launch the game without it or continue to use old version
 
@@ -36,8 +48,24 @@ loading-registering-styles-failed    = Failed to register games styles
 loading-checking-wine-version-failed = Failed to check wine version
 loading-checking-dxvk-version-failed = Failed to check dxvk version
 loading-checking-applied-dxvk-failed = Failed to check applied dxvk version
+loading-checking-vkd3d-failed        = Failed to check vkd3d-proton version
+loading-checking-nvapi-failed        = Failed to check dxvk-nvapi version
 loading-checking-games-addons-failed = Failed to check games addons
 
 main-open-launcher-folder-failed = Failed to open launcher folder
 main-open-config-file-failed     = Failed to open config file
 main-open-debug-file-failed      = Failed to open debug file
+
+game-settings-dxvk-failed         = Failed to resolve DXVK version
+game-settings-environment-invalid = Environment variable must be specified as NAME=value
+game-settings-dll-overrides-invalid = DLL override must be specified as dll=value, where value is n, b, n,b, b,n or empty
+game-settings-registry-invalid = Registry value must be specified as key\name=value
+
+components-manager-versions-failed = Failed to get components versions
+
+prefix-manager-wine-failed = Failed to resolve wine version of the prefix
+prefix-manager-tool-failed = Failed to run {$tool}
+
+launch-template-invalid = Launch template must contain %command%, or both %wine% and %args%
+
+downloads-schedule-invalid = Time must be specified as HH:MM, e.g. 23:30
@@ -0,0 +1,31 @@
+game-settings = {$game-title} settings
+game-settings-description = Settings used only by this game. Default values are taken from the launcher preferences
+
+game-settings-default  = Default
+game-settings-enabled  = Enabled
+game-settings-disabled = Disabled
+
+game-settings-separate-prefix = Separate wine prefix
+game-settings-separate-prefix-description = Use own wine prefix instead of the shared one. It will be created on the next launch
+
+game-settings-manage-prefix = Manage wine prefix
+game-settings-manage-prefix-description = Run wine tools, install runtimes or reset the prefix used by this game
+
+game-settings-dxvk-description = Can be changed only for the separate wine prefix
+
+game-settings-environment = Environment
+game-settings-environment-description = Variables added to the global environment when launching the game
+game-settings-environment-add = Add variable (NAME=value)
+
+game-settings-launch = Launch
+game-settings-launch-description = Wrappers added after the global ones when launching the game
+game-settings-launch-template = Launch command template (empty to use the global one)
+
+game-settings-dll-overrides = DLL overrides
+game-settings-dll-overrides-description = Overrides added to the global ones when launching the game
+game-settings-dll-overrides-add = Add override (dll=n,b)
+game-settings-dll-overrides-disabled = Disabled
+
+game-settings-registry = Registry
+game-settings-registry-description = Values set in the wine prefix registry before launching the game
+game-settings-registry-add = Add value (key\name=value or key\name=dword:1)
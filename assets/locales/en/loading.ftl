@@ -11,5 +11,8 @@ loading-registering-styles    = Registering games styles
 loading-checking-wine-version = Checking wine version
 loading-checking-dxvk-version = Checking dxvk version
 loading-checking-applied-dxvk = Checking applied dxvk version
+loading-checking-vkd3d        = Checking vkd3d-proton version
+loading-checking-nvapi        = Checking dxvk-nvapi version
+loading-checking-components-updates = Checking components updates
 loading-checking-wine-prefix  = Checking wine prefix
 loading-checking-games-addons = Checking games addons
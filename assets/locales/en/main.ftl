@@ -1,6 +1,8 @@
 main-menu-launcher-folder = Launcher folder
 main-menu-config-file     = Config file
 main-menu-debug-file      = Debug file
+main-menu-components      = Components
+main-menu-wine-prefix     = Wine prefix
 main-menu-about           = About
 
 main-installed-games = Installed games
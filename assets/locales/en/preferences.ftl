@@ -8,6 +8,33 @@ general-launcher-language-description = Changes after restart
 general-verify-games = Verify games
 general-verify-games-description = Verify games installations after installation or updating
 
+preferences--downloads = Downloads
+
+downloads-speed-limit = Speed limit
+downloads-speed-limit-description = Maximal download speed in KiB/s. 0 means no limit
+
+downloads-pause-while-playing = Pause while playing
+downloads-pause-while-playing-description = Pause downloads while any game is running
+
+downloads-schedule = Schedule
+downloads-schedule-description = Download files only during specified time, e.g. at night
+
+downloads-schedule-enabled = Enabled
+downloads-schedule-from    = From (HH:MM)
+downloads-schedule-to      = To (HH:MM)
+
+downloads-cache = Cache
+downloads-cache-description = Keep downloaded archives to reuse them when reinstalling games
+
+downloads-cache-enabled = Enabled
+
+downloads-cache-max-size = Maximal size
+downloads-cache-max-size-description = Maximal cache size in MiB. Least recently used files are removed first
+
+downloads-cache-clear = Clear cache
+downloads-cache-size = Cache size: {$size}
+downloads-cache-clear-button = Clear
+
 preferences--wine = Wine
 
 wine-language = Language
@@ -29,6 +56,21 @@ preferences--gaming = Gaming
 
 game-hud = HUD
 
+game-hud-options = HUD options
+game-hud-options-description = Elements shown by DXVK HUD and MangoHUD
+game-hud-preset-minimal = Minimal
+game-hud-preset-default = Default
+game-hud-preset-full = Full
+game-hud-preset-custom = Custom
+game-hud-dxvk-preset = DXVK HUD preset
+game-hud-dxvk-elements = DXVK HUD elements (comma separated)
+game-hud-mangohud-source = MangoHUD config
+game-hud-mangohud-source-inline = Options
+game-hud-mangohud-source-file = Config file
+game-hud-mangohud-preset = MangoHUD preset
+game-hud-mangohud-options = MangoHUD options (comma separated)
+game-hud-mangohud-file = MangoHUD config file path
+
 game-fsr = FSR
 game-fsr-description = Upscales game to your monitor size. To use select lower resolution in the game's settings and press Alt + Enter
 
@@ -46,9 +88,40 @@ game-fsr-quality-performance = Performance
 game-fsr-strength = Sharpening strength
 game-fsr-strength-description = Image sharpening strength where 0 is maximal sharpness
 
+game-gamescope = Gamescope
+game-gamescope-description = Run the game in a separate compositor which can upscale it, limit its frame rate and enable HDR
+game-gamescope-enabled = Enabled
+game-gamescope-enabled-description = Gamescope must be installed in the system
+game-gamescope-window-mode = Window mode
+game-gamescope-window-mode-windowed = Windowed
+game-gamescope-window-mode-borderless = Borderless
+game-gamescope-window-mode-fullscreen = Fullscreen
+game-gamescope-output-width = Window width
+game-gamescope-output-height = Window height
+game-gamescope-internal-width = Game width
+game-gamescope-internal-height = Game height
+game-gamescope-refresh-rate = Frame rate limit
+game-gamescope-refresh-rate-description = Zero means no limit
+game-gamescope-upscaler = Upscaler
+game-gamescope-upscaler-linear = Linear
+game-gamescope-upscaler-nearest = Nearest
+game-gamescope-upscaler-pixel = Pixel art
+game-gamescope-sharpness = Sharpness
+game-gamescope-sharpness-description = FSR and NIS sharpness where 0 is maximal sharpness
+game-gamescope-hdr = HDR
+game-gamescope-hdr-description = Requires HDR display and gamescope running in the embedded mode or a HDR capable compositor
+game-gamescope-grab-cursor = Grab cursor
+game-gamescope-grab-cursor-description = Keep the cursor inside of the gamescope window
+
 game-gamemode = Gamemode
 game-gamemode-description = Prioritize the game over the rest of the processes
 
+preferences--launch = Launch
+
+launch-description = Wrapper commands are run in the listed order before the wine command
+launch-template = Launch command template (%command%, %wine%, %args%)
+launch-wrapper-add = Add wrapper command (e.g. prime-run or taskset -c 0-7)
+
 preferences--components = Components
 
 components-wine = Wine version
@@ -60,5 +133,35 @@ components-dxvk-description = Selected version will be downloaded after restart
 components-wine-latest = Latest
 components-dxvk-latest = Latest
 
+components-vkd3d-enabled = Use VKD3D-Proton
+components-vkd3d-enabled-description = Translate DirectX 12 calls to Vulkan
+components-vkd3d = VKD3D-Proton version
+components-vkd3d-description = Selected version will be downloaded after restart
+components-vkd3d-latest = Latest
+
+components-nvapi-enabled = Use DXVK-NVAPI
+components-nvapi-enabled-description = Provide NVIDIA specific features like DLSS. Works only with NVIDIA GPUs
+components-nvapi = DXVK-NVAPI version
+components-nvapi-description = Selected version will be downloaded after restart
+components-nvapi-latest = Latest
+
 components-install-corefonts = Install corefonts
 components-install-corefonts-description = Install default windows fonts to the wine prefix
+
+components-prefix-snapshots = Prefix snapshots
+components-prefix-snapshots-description = Save wine prefix before applying DXVK or updating it to a new wine version
+components-prefix-snapshots-enabled = Take snapshots automatically
+components-prefix-snapshots-mode = Snapshot format
+components-prefix-snapshots-mode-description = Copies are faster to restore and take almost no space on filesystems with reflinks support
+components-prefix-snapshots-mode-copy = Folder copy
+components-prefix-snapshots-mode-tar = Tar archive
+components-prefix-snapshots-keep = Snapshots to keep
+
+components-updates-policy = Components updates
+components-updates-policy-description = What to do when newer versions of the selected components are available. Components with the latest version are always updated
+components-updates-policy-always = Always update
+components-updates-policy-ask = Ask
+components-updates-policy-never = Never update
+
+components-delete-unused = Delete unused components
+components-delete-unused-description = Delete downloaded components which are used neither globally nor by games settings
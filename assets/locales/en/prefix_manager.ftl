@@ -0,0 +1,37 @@
+prefix-manager = Wine prefix
+prefix-manager-not-created = Wine prefix is not created yet
+
+prefix-manager-tools = Tools
+prefix-manager-winecfg = Configure wine settings
+prefix-manager-regedit = Edit the prefix registry
+prefix-manager-taskmgr = Manage processes running in the prefix
+
+prefix-manager-maintenance = Maintenance
+prefix-manager-repair = Repair
+prefix-manager-repair-description = Create the prefix or update it to the selected wine version, and install DXVK and fonts again
+prefix-manager-reset = Reset
+prefix-manager-reset-description = Delete the prefix with all its files and create a new one
+prefix-manager-reset-confirm = All files and settings stored in {$path} will be deleted
+
+prefix-manager-verbs = Runtimes
+prefix-manager-winetricks-missing = Install winetricks to manage runtimes
+prefix-manager-verb-install = Install
+prefix-manager-verb-installed = Installed
+
+prefix-manager-applied = Applied changes
+prefix-manager-components = Components
+prefix-manager-fonts = Fonts
+prefix-manager-dll-overrides = DLL overrides
+prefix-manager-not-installed = Not installed
+prefix-manager-count = {$count ->
+    [one] {$count} item
+    *[other] {$count} items
+}
+
+prefix-manager-snapshots = Snapshots
+prefix-manager-snapshots-description = Saved copies of the prefix which can be restored if something goes wrong
+prefix-manager-snapshot-create = Create snapshot
+prefix-manager-snapshot-restore = Restore snapshot
+prefix-manager-snapshot-restore-game-running = Close the game before restoring the prefix snapshot
+prefix-manager-snapshot-restore-confirm = Current prefix files will be replaced by the snapshot taken at {$date}
+prefix-manager-snapshot-delete = Delete snapshot
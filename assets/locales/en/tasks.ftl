@@ -12,15 +12,24 @@ tasks-preparing-transition = Preparing transition...
 tasks-finishing-transition = Finishing transition...
 tasks-downloading          = Downloading...
 tasks-unpacking            = Unpacking...
+tasks-applying-patches     = Applying patches...
 tasks-deleting-files       = Deleting files...
 tasks-pre-transition-code  = Running task pre-transition code...
 tasks-transition-code      = Running task transition code...
 tasks-post-transition-code = Running task post-transition code...
 tasks-creating-prefix      = Creating prefix...
 tasks-installing-dxvk      = Installing DXVK...
+tasks-installing-vkd3d     = Installing VKD3D-Proton...
+tasks-installing-nvapi     = Installing DXVK-NVAPI...
 tasks-installing-fonts     = Intalling fonts...
+tasks-installing-verbs     = Installing runtimes...
+tasks-creating-snapshot    = Creating snapshot...
+tasks-restoring-snapshot   = Restoring snapshot...
 tasks-verifying-files      = Verifying files...
 tasks-repairing-files      = Repairing files...
+tasks-moving-files         = Moving files...
 tasks-finished             = Finished
 
 tasks-delete-files = Delete files
+tasks-install-verbs = Install runtimes
+tasks-prefix-snapshot = Wine prefix snapshot
@@ -0,0 +1,161 @@
+use std::path::Path;
+
+use serde_json::Value as Json;
+
+use anime_game_core::network::minreq;
+
+use crate::config;
+
+use crate::games::integrations::standards::IntegrationStandard;
+use crate::games::integrations::standards::integrity::{FileHash, HashType};
+
+use crate::network::cache;
+use crate::network::download::DownloadOptions;
+
+use super::Status;
+
+/// Prefix of the channels and URIs stored in the local filesystem
+pub const LOCAL_CHANNEL_PREFIX: &str = "file://";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Component versions listed in the channel manifest
+pub struct ChannelVersions<T> {
+    pub versions: Vec<T>,
+
+    /// Descriptions of the malformed manifest entries
+    /// which were skipped while parsing
+    pub skipped: Vec<String>
+}
+
+/// Get manifest entries of the component build
+///
+/// Manifest is read from `<channel>/<component>/<build>.json`.
+/// Channel can be either remote or local one, e.g. `file:///mnt/mirror/components`
+pub fn get_manifest(component: impl AsRef<str>, build: impl AsRef<str>) -> anyhow::Result<Vec<Json>> {
+    let channel = config::get().components.channel;
+
+    let manifest = format!("{}/{}/{}.json", channel.trim_end_matches('/'), component.as_ref(), build.as_ref());
+
+    match manifest.strip_prefix(LOCAL_CHANNEL_PREFIX) {
+        Some(path) => Ok(serde_json::from_slice(&std::fs::read(path)?)?),
+        None => Ok(minreq::get(manifest).send()?.json::<Vec<Json>>()?)
+    }
+}
+
+/// Get versions of the component build
+///
+/// Entries which can't be parsed are skipped and reported
+/// in the returned struct and in the debug output
+pub fn get_versions<T>(
+    component: impl AsRef<str>,
+    build: impl AsRef<str>,
+    parse: impl Fn(&Json) -> anyhow::Result<T>
+) -> anyhow::Result<ChannelVersions<T>> {
+    let component = component.as_ref();
+    let build = build.as_ref();
+
+    let mut versions = Vec::new();
+    let mut skipped = Vec::new();
+
+    for (i, entry) in get_manifest(component, build)?.iter().enumerate() {
+        match parse(entry) {
+            Ok(version) => versions.push(version),
+
+            Err(err) => {
+                let name = entry.get("name")
+                    .and_then(Json::as_str)
+                    .map(|name| format!("{name} (#{})", i + 1))
+                    .unwrap_or_else(|| format!("#{}", i + 1));
+
+                tracing::warn!("Skipped malformed {component}/{build} manifest entry {name}: {err}");
+
+                skipped.push(format!("{name}: {err}"));
+            }
+        }
+    }
+
+    Ok(ChannelVersions {
+        versions,
+        skipped
+    })
+}
+
+/// Get required string field of the manifest entry
+pub fn get_field(entry: &Json, name: &str) -> anyhow::Result<String> {
+    match entry.get(name) {
+        Some(Json::String(value)) => Ok(value.to_owned()),
+
+        Some(_) => anyhow::bail!("field `{name}` is not a string"),
+        None => anyhow::bail!("field `{name}` is missing")
+    }
+}
+
+/// Get optional archive hash of the manifest entry
+///
+/// Hash is described the same way as in the integration scripts:
+/// `{ "hash": "sha1", "value": "..." }`. Custom hashes are not supported
+/// because they can be calculated only by the integration scripts
+pub fn get_hash(entry: &Json) -> anyhow::Result<Option<FileHash>> {
+    let Some(hash) = entry.get("hash") else {
+        return Ok(None);
+    };
+
+    let hash = FileHash {
+        hash: HashType::from_str(get_field(hash, "hash")?, IntegrationStandard::V1)?,
+        value: get_field(hash, "value")?.to_ascii_lowercase()
+    };
+
+    if let HashType::Custom(name) = &hash.hash {
+        anyhow::bail!("unsupported hash type `{name}`");
+    }
+
+    Ok(Some(hash))
+}
+
+/// Download component archive, or take it from the cache or the local filesystem
+///
+/// Archive is verified if the hash is given.
+/// Archive with wrong hash is deleted, as well as its cached copy
+pub fn download_archive(
+    uri: impl AsRef<str>,
+    path: impl AsRef<Path>,
+    hash: Option<&FileHash>,
+    mut progress: impl FnMut(Status, u64, u64) -> anyhow::Result<()>
+) -> anyhow::Result<()> {
+    let uri = uri.as_ref();
+    let path = path.as_ref();
+
+    match uri.strip_prefix(LOCAL_CHANNEL_PREFIX) {
+        Some(source) => {
+            let size = std::fs::copy(source, path)?;
+
+            progress(Status::Downloading, size, size)?;
+        }
+
+        None => cache::download(uri, path, hash, DownloadOptions::from_config(), |current, total| {
+            progress(Status::Downloading, current, total)
+        })?
+    }
+
+    let Some(hash) = hash else {
+        return Ok(());
+    };
+
+    progress(Status::Verifying, 0, 1)?;
+
+    let Some(hasher) = hash.hash.hasher() else {
+        anyhow::bail!("Unsupported archive hash type: {:?}", hash.hash);
+    };
+
+    if hasher.hash_file(path)? != hash.value {
+        std::fs::remove_file(path)?;
+
+        if let Some(key) = cache::get_key(uri, None, Some(hash)) {
+            cache::remove(key)?;
+        }
+
+        anyhow::bail!("Downloaded archive has wrong hash: {uri}");
+    }
+
+    progress(Status::Verifying, 1, 1)
+}
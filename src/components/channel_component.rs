@@ -0,0 +1,266 @@
+use std::cell::Cell;
+use std::path::{Path, PathBuf};
+use std::marker::PhantomData;
+
+use anime_game_core::archive;
+
+
+use anime_game_core::updater::UpdaterExt;
+
+use crate::COMPONENTS_FOLDER;
+
+use crate::network::download;
+
+use crate::games::integrations::standards::integrity::FileHash;
+
+use crate::components::{
+    Updater,
+    Status
+};
+
+use crate::ui::components::game_card::CardInfo;
+use crate::ui::components::tasks_queue::{QueuedTask, ResolvedTask};
+
+use super::DownloadComponentResolvedTask;
+use super::channel::{self, ChannelVersions};
+use super::prefix_dlls::PrefixDlls;
+
+/// Component which versions are listed in the components channel
+pub trait ChannelComponentKind: std::fmt::Debug + Clone + Copy + PartialEq + Eq + Send + 'static {
+    /// Name of the component in the channel and in the components folder
+    const NAME: &'static str;
+
+    /// Get component build and version selected in the config file
+    fn get_config() -> (String, String);
+}
+
+/// Component which is installed to the wine prefix by copying its DLLs
+pub trait PrefixDllsKind: ChannelComponentKind {
+    const DLLS: PrefixDlls;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChannelComponent<K: ChannelComponentKind> {
+    pub name: String,
+    pub title: String,
+    pub version: String,
+    pub uri: String,
+    pub hash: Option<FileHash>,
+
+    kind: PhantomData<K>
+}
+
+impl<K: ChannelComponentKind> ChannelComponent<K> {
+    #[inline]
+    /// Get versions list of the selected component build
+    pub fn versions() -> anyhow::Result<Vec<Self>> {
+        Self::build_versions(K::get_config().0)
+    }
+
+    #[inline]
+    /// Get versions list of the given component build
+    pub fn build_versions(build: impl AsRef<str>) -> anyhow::Result<Vec<Self>> {
+        Ok(Self::build_channel_versions(build)?.versions)
+    }
+
+    #[inline]
+    /// Get versions list of the selected component build
+    /// with the skipped malformed entries
+    pub fn channel_versions() -> anyhow::Result<ChannelVersions<Self>> {
+        Self::build_channel_versions(K::get_config().0)
+    }
+
+    /// Get versions list of the given component build
+    /// with the skipped malformed entries
+    pub fn build_channel_versions(build: impl AsRef<str>) -> anyhow::Result<ChannelVersions<Self>> {
+        channel::get_versions(K::NAME, build, |entry| Ok(Self {
+            name: channel::get_field(entry, "name")?,
+            title: channel::get_field(entry, "title")?,
+            version: channel::get_field(entry, "version")?,
+            uri: channel::get_field(entry, "uri")?,
+            hash: channel::get_hash(entry)?,
+            kind: PhantomData
+        }))
+    }
+
+    #[inline]
+    /// Resolve component version from the config file
+    pub fn from_config() -> anyhow::Result<Self> {
+        let (build, version) = K::get_config();
+
+        Self::from_version(build, version)
+    }
+
+    /// Resolve component version of the given build
+    pub fn from_version(build: impl AsRef<str>, version: impl AsRef<str>) -> anyhow::Result<Self> {
+        let version = version.as_ref();
+
+        let versions = Self::build_versions(build);
+
+        for component in versions.iter().flatten() {
+            if component.name.contains(version) || component.version.contains(version) || version == "latest" {
+                return Ok(component.clone());
+            }
+        }
+
+        // Use downloaded version which is not listed in the build
+        if version != "latest" && COMPONENTS_FOLDER.join(K::NAME).join(version).exists() {
+            return Ok(Self::from_downloaded(version));
+        }
+
+        versions?;
+
+        anyhow::bail!("No appropriate {} version found", K::NAME)
+    }
+
+    #[inline]
+    /// Describe downloaded version by its folder name
+    ///
+    /// Such version can't be downloaded again because its uri is unknown
+    pub fn from_downloaded(name: impl AsRef<str>) -> Self {
+        Self {
+            name: name.as_ref().to_string(),
+            title: name.as_ref().to_string(),
+            version: name.as_ref().to_string(),
+            uri: String::new(),
+            hash: None,
+            kind: PhantomData
+        }
+    }
+
+    /// Get list of downloaded component versions
+    pub fn downloaded() -> anyhow::Result<Vec<Self>> {
+        let path = COMPONENTS_FOLDER.join(K::NAME);
+
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut versions = Vec::new();
+
+        for entry in path.read_dir()?.flatten() {
+            if entry.file_type()?.is_dir() {
+                versions.push(Self::from_downloaded(entry.file_name().to_string_lossy()));
+            }
+        }
+
+        versions.sort_by(|a, b| b.name.cmp(&a.name));
+
+        Ok(versions)
+    }
+
+    /// Append downloaded versions which are missing in the given list
+    pub fn with_downloaded(versions: impl IntoIterator<Item = Self>) -> Vec<Self> {
+        let mut versions = versions.into_iter().collect::<Vec<_>>();
+
+        for downloaded in Self::downloaded().unwrap_or_default() {
+            if !versions.iter().any(|version| version.name == downloaded.name) {
+                versions.push(downloaded);
+            }
+        }
+
+        versions
+    }
+
+    #[inline]
+    /// Get component folder path
+    pub fn get_folder(&self) -> PathBuf {
+        COMPONENTS_FOLDER
+            .join(K::NAME)
+            .join(&self.name)
+    }
+
+    #[inline]
+    /// Check if the component is downloaded
+    pub fn is_downloaded(&self) -> bool {
+        self.get_folder().exists()
+    }
+
+    /// Download component
+    pub fn download(&self) -> anyhow::Result<Updater> {
+        let (sender, receiver) = flume::unbounded();
+
+        let download_uri = self.uri.clone();
+        let download_hash = self.hash.clone();
+
+        Ok(Updater {
+            status: Cell::new(Status::Downloading),
+            current: Cell::new(0),
+            total: Cell::new(1), // To prevent division by 0
+
+            worker_result: None,
+            updater: receiver,
+
+            worker: Some(std::thread::spawn(move || -> anyhow::Result<()> {
+                let path = COMPONENTS_FOLDER.join(K::NAME);
+                let archive = path.join(download::get_file_name(&download_uri));
+
+                // Create component dir if needed
+
+                std::fs::create_dir_all(&path)?;
+
+                // Download update archive or take it from the cache
+
+                channel::download_archive(&download_uri, &archive, download_hash.as_ref(), |status, current, total| {
+                    Ok(sender.send((status, current, total))?)
+                })?;
+
+                // Extract archive
+
+                let Some(mut updater) = archive::extract(&archive, &path) else {
+                    anyhow::bail!("Unable to extract archive: {:?}", archive);
+                };
+
+                while let Ok(false) = updater.status() {
+                    sender.send((
+                        Status::Unpacking,
+                        updater.current(),
+                        updater.total()
+                    ))?;
+                }
+
+                std::fs::remove_file(archive)?;
+
+                // Finish downloading
+
+                sender.send((Status::Finished, 1, 1))?;
+
+                Ok(())
+            }))
+        })
+    }
+}
+
+impl<K: PrefixDllsKind> ChannelComponent<K> {
+    #[inline]
+    /// Install component DLLs to the wine prefix
+    pub fn install(&self, prefix: impl AsRef<Path>) -> anyhow::Result<()> {
+        K::DLLS.install(self.get_folder(), prefix)
+    }
+
+    #[inline]
+    /// Get name of the component version installed to the wine prefix
+    pub fn get_installed_version(prefix: impl AsRef<Path>) -> anyhow::Result<Option<String>> {
+        K::DLLS.get_version(prefix)
+    }
+}
+
+#[derive(Debug)]
+pub struct DownloadComponentQueuedTask<K: ChannelComponentKind> {
+    pub card_info: CardInfo,
+    pub version: ChannelComponent<K>
+}
+
+impl<K: ChannelComponentKind> QueuedTask for DownloadComponentQueuedTask<K> {
+    #[inline]
+    fn get_info(&self) -> CardInfo {
+        self.card_info.clone()
+    }
+
+    fn resolve(self: Box<Self>) -> anyhow::Result<Box<dyn ResolvedTask>> {
+        Ok(Box::new(DownloadComponentResolvedTask {
+            card_info: self.card_info,
+            updater: self.version.download()?
+        }))
+    }
+}
@@ -6,12 +6,22 @@ use anime_game_core::updater::UpdaterExt;
 use crate::ui::components::tasks_queue::{ResolvedTask, TaskStatus};
 use crate::ui::components::game_card::CardInfo;
 
+pub mod channel;
 pub mod wine;
+pub mod channel_component;
 pub mod dxvk;
+pub mod vkd3d;
+pub mod nvapi;
+pub mod prefix_dlls;
+pub mod prefix_tools;
+pub mod prefix_snapshots;
+pub mod updates;
+pub mod system_wine;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
     Downloading,
+    Verifying,
     Unpacking,
     Finished
 }
@@ -131,6 +141,7 @@ impl ResolvedTask for DownloadComponentResolvedTask {
         match self.updater.status() {
             Ok(status) => Ok(match status {
                 Status::Downloading => TaskStatus::Downloading,
+                Status::Verifying   => TaskStatus::VerifyingFiles,
                 Status::Unpacking   => TaskStatus::Unpacking,
                 Status::Finished    => TaskStatus::Finished
             }),
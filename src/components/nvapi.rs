@@ -0,0 +1,31 @@
+use crate::config;
+
+use super::channel_component::{
+    ChannelComponent,
+    ChannelComponentKind,
+    PrefixDllsKind,
+    DownloadComponentQueuedTask
+};
+
+use super::prefix_dlls::PrefixDlls;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NvapiKind;
+
+impl ChannelComponentKind for NvapiKind {
+    const NAME: &'static str = "nvapi";
+
+    #[inline]
+    fn get_config() -> (String, String) {
+        let nvapi = config::get().components.nvapi;
+
+        (nvapi.build, nvapi.version)
+    }
+}
+
+impl PrefixDllsKind for NvapiKind {
+    const DLLS: PrefixDlls = PrefixDlls::Nvapi;
+}
+
+pub type Nvapi = ChannelComponent<NvapiKind>;
+pub type DownloadNvapiQueuedTask = DownloadComponentQueuedTask<NvapiKind>;
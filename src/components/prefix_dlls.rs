@@ -0,0 +1,115 @@
+use std::path::Path;
+
+/// Components which are installed to the wine prefix
+/// by copying their DLLs and overriding them as native
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PrefixDlls {
+    Vkd3d,
+    Nvapi
+}
+
+impl PrefixDlls {
+    /// Get name of the component used in its DLLs strings
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Vkd3d => "vkd3d-proton",
+            Self::Nvapi => "dxvk-nvapi"
+        }
+    }
+
+    /// Get names of the DLLs which should be overridden as native
+    pub fn dlls(&self) -> &'static [&'static str] {
+        match self {
+            Self::Vkd3d => &["d3d12", "d3d12core"],
+            Self::Nvapi => &["nvapi", "nvapi64"]
+        }
+    }
+
+    #[inline]
+    /// Get `WINEDLLOVERRIDES` entry of the component
+    pub fn get_overrides(&self) -> String {
+        format!("{}=n", self.dlls().join(","))
+    }
+
+    /// Get installed component version
+    ///
+    /// Version is searched in the 64 bit DLL next to the component name,
+    /// e.g. `DXVK-NVAPI v0.7.0`. Returns `None` if the component
+    /// is not installed to the prefix, or if its version is unknown
+    pub fn get_version(&self, prefix: impl AsRef<Path>) -> anyhow::Result<Option<String>> {
+        let dll = match self {
+            Self::Vkd3d => "d3d12.dll",
+            Self::Nvapi => "nvapi64.dll"
+        };
+
+        let path = prefix.as_ref()
+            .join("drive_c/windows/system32")
+            .join(dll);
+
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let bytes = std::fs::read(path)?;
+        let marker = self.name().as_bytes();
+
+        let version = bytes.windows(marker.len())
+            .enumerate()
+            .filter(|(_, window)| window.eq_ignore_ascii_case(marker))
+            .find_map(|(i, _)| {
+                let tail = &bytes[i + marker.len()..];
+
+                // Skip separators like " v" or "-"
+                let start = tail.iter()
+                    .take(3)
+                    .position(u8::is_ascii_digit)?;
+
+                let version = tail[start..].iter()
+                    .take_while(|char| char.is_ascii_digit() || **char == b'.')
+                    .copied()
+                    .collect::<Vec<_>>();
+
+                let version = String::from_utf8(version).ok()?;
+                let version = version.trim_end_matches('.');
+
+                version.contains('.').then(|| version.to_string())
+            });
+
+        Ok(version)
+    }
+
+    /// Install component DLLs from the given folder to the wine prefix
+    ///
+    /// 64 bit DLLs are taken from the `x64` folder, and 32 bit from `x86` or `x32`
+    pub fn install(&self, folder: impl AsRef<Path>, prefix: impl AsRef<Path>) -> anyhow::Result<()> {
+        let folder = folder.as_ref();
+        let prefix = prefix.as_ref();
+
+        let targets = [
+            ("x64", "drive_c/windows/system32"),
+            ("x86", "drive_c/windows/syswow64"),
+            ("x32", "drive_c/windows/syswow64")
+        ];
+
+        for (source, target) in targets {
+            let source = folder.join(source);
+            let target = prefix.join(target);
+
+            if !source.exists() {
+                continue;
+            }
+
+            std::fs::create_dir_all(&target)?;
+
+            for entry in source.read_dir()?.flatten() {
+                let path = entry.path();
+
+                if path.extension().is_some_and(|extension| extension == "dll") {
+                    std::fs::copy(&path, target.join(entry.file_name()))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
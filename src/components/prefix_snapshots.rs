@@ -0,0 +1,238 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::config;
+use crate::games;
+use crate::config::components::wine::snapshots::SnapshotMode;
+
+use crate::utils::get_folder_size;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    /// Path to the snapshot archive or folder
+    pub path: PathBuf,
+
+    /// Snapshot creation timestamp in milliseconds
+    pub created_at: i64,
+
+    pub mode: SnapshotMode
+}
+
+impl Snapshot {
+    /// Describe snapshot by its path
+    ///
+    /// Returns `None` if the path is not a snapshot
+    pub fn from_path(path: impl Into<PathBuf>) -> Option<Self> {
+        let path = path.into();
+        let name = path.file_name()?.to_string_lossy().to_string();
+
+        let (created_at, mode) = match name.strip_suffix(".tar") {
+            Some(created_at) => (created_at.parse().ok()?, SnapshotMode::Tar),
+            None => (name.parse().ok()?, SnapshotMode::Copy)
+        };
+
+        Some(Self {
+            path,
+            created_at,
+            mode
+        })
+    }
+
+    #[inline]
+    /// Get size of the snapshot files
+    pub fn get_size(&self) -> u64 {
+        match self.mode {
+            SnapshotMode::Tar => self.path.metadata()
+                .map(|metadata| metadata.len())
+                .unwrap_or_default(),
+
+            SnapshotMode::Copy => get_folder_size(&self.path).unwrap_or_default()
+        }
+    }
+
+    /// Get formatted snapshot creation time
+    pub fn get_created_at_text(&self) -> String {
+        use chrono::TimeZone;
+
+        chrono::Utc.timestamp_millis_opt(self.created_at)
+            .single()
+            .map(|time| time.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S").to_string())
+            .unwrap_or_default()
+    }
+
+    /// Delete snapshot files
+    pub fn delete(&self) -> anyhow::Result<()> {
+        match self.mode {
+            SnapshotMode::Tar => std::fs::remove_file(&self.path)?,
+            SnapshotMode::Copy => std::fs::remove_dir_all(&self.path)?
+        }
+
+        Ok(())
+    }
+}
+
+/// Run command and return error with its output if it failed
+fn run(command: &mut Command) -> anyhow::Result<()> {
+    let output = command.output()?;
+
+    if !output.status.success() {
+        anyhow::bail!("{:?} failed: {}", command, String::from_utf8_lossy(&output.stderr).trim());
+    }
+
+    Ok(())
+}
+
+/// Get folder where snapshots of the given prefix are stored
+///
+/// Each prefix has its own folder named by the prefix path hash
+pub fn get_folder(prefix: impl AsRef<Path>) -> PathBuf {
+    let hash = xxhash_rust::xxh3::xxh3_128(prefix.as_ref().to_string_lossy().as_bytes());
+
+    config::get().components.wine.prefix.snapshots.path.join(format!("{hash:032x}"))
+}
+
+/// Get snapshots of the given prefix, newest first
+pub fn list(prefix: impl AsRef<Path>) -> anyhow::Result<Vec<Snapshot>> {
+    let folder = get_folder(prefix);
+
+    if !folder.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut snapshots = folder.read_dir()?
+        .flatten()
+        .filter_map(|entry| Snapshot::from_path(entry.path()))
+        .collect::<Vec<_>>();
+
+    snapshots.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+    Ok(snapshots)
+}
+
+/// Create snapshot of the prefix
+pub fn create(prefix: impl AsRef<Path>, mode: SnapshotMode) -> anyhow::Result<Snapshot> {
+    let prefix = prefix.as_ref();
+    let folder = get_folder(prefix);
+
+    if games::is_any_running() {
+        anyhow::bail!("Can't create wine prefix snapshot while the game is running");
+    }
+
+    if !prefix.exists() {
+        anyhow::bail!("Wine prefix doesn't exist: {:?}", prefix);
+    }
+
+    std::fs::create_dir_all(&folder)?;
+
+    let created_at = chrono::Utc::now().timestamp_millis();
+
+    let name = match mode {
+        SnapshotMode::Tar => format!("{created_at}.tar"),
+        SnapshotMode::Copy => created_at.to_string()
+    };
+
+    // Don't leave partially created snapshots
+    let temp = folder.join(format!("{name}.tmp"));
+
+    let result = match mode {
+        SnapshotMode::Tar => run(Command::new("tar")
+            .arg("-cf").arg(&temp)
+            .arg("-C").arg(prefix)
+            .arg(".")),
+
+        SnapshotMode::Copy => run(Command::new("cp")
+            .arg("-a")
+            .arg("--reflink=auto")
+            .arg(prefix)
+            .arg(&temp))
+    };
+
+    if let Err(err) = result {
+        if temp.is_dir() {
+            std::fs::remove_dir_all(&temp)?;
+        } else if temp.exists() {
+            std::fs::remove_file(&temp)?;
+        }
+
+        return Err(err);
+    }
+
+    let path = folder.join(name);
+
+    std::fs::rename(temp, &path)?;
+
+    Ok(Snapshot {
+        path,
+        created_at,
+        mode
+    })
+}
+
+/// Replace prefix with its snapshot
+///
+/// Current prefix is kept until the snapshot is restored
+pub fn restore(snapshot: &Snapshot, prefix: impl AsRef<Path>) -> anyhow::Result<()> {
+    let prefix = prefix.as_ref();
+
+    let Some(name) = prefix.file_name() else {
+        anyhow::bail!("Wrong wine prefix path: {:?}", prefix);
+    };
+
+    // Append suffix instead of replacing the extension
+    // so prefixes like "pfx.v2" don't collide with each other
+    let backup = prefix.with_file_name(format!("{}.restoring", name.to_string_lossy()));
+
+    if games::is_any_running() {
+        anyhow::bail!("Can't restore wine prefix while the game is running");
+    }
+
+    if backup.exists() {
+        std::fs::remove_dir_all(&backup)?;
+    }
+
+    if prefix.exists() {
+        std::fs::rename(prefix, &backup)?;
+    }
+
+    let result = match snapshot.mode {
+        SnapshotMode::Tar => std::fs::create_dir_all(prefix)
+            .map_err(anyhow::Error::from)
+            .and_then(|_| run(Command::new("tar")
+                .arg("-xf").arg(&snapshot.path)
+                .arg("-C").arg(prefix))),
+
+        SnapshotMode::Copy => run(Command::new("cp")
+            .arg("-a")
+            .arg("--reflink=auto")
+            .arg(&snapshot.path)
+            .arg(prefix))
+    };
+
+    if let Err(err) = result {
+        if prefix.exists() {
+            std::fs::remove_dir_all(prefix)?;
+        }
+
+        if backup.exists() {
+            std::fs::rename(&backup, prefix)?;
+        }
+
+        return Err(err);
+    }
+
+    if backup.exists() {
+        std::fs::remove_dir_all(backup)?;
+    }
+
+    Ok(())
+}
+
+/// Delete the oldest snapshots of the prefix
+/// so only `keep` newest ones are left
+pub fn cleanup(prefix: impl AsRef<Path>, keep: u64) -> anyhow::Result<()> {
+    for snapshot in list(prefix)?.into_iter().skip(keep as usize) {
+        snapshot.delete()?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,285 @@
+use std::path::{Path, PathBuf};
+use std::process::{Command, Child};
+
+use crate::config::games::wine::registry::{self, RegistryValue};
+
+use super::wine::Wine;
+use super::system_wine;
+
+/// Name of the file where winetricks lists applied verbs
+pub const WINETRICKS_LOG: &str = "winetricks.log";
+
+/// Name of the file where hash of the applied registry values is stored
+pub const REGISTRY_HASH_FILE: &str = "launcher-registry.hash";
+
+/// Wine tools which can be run in the prefix
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PrefixTool {
+    Winecfg,
+    Regedit,
+    Taskmgr
+}
+
+impl PrefixTool {
+    #[inline]
+    pub fn list() -> &'static [Self] {
+        &[Self::Winecfg, Self::Regedit, Self::Taskmgr]
+    }
+
+    #[inline]
+    /// Get name of the tool's executable
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Winecfg => "winecfg",
+            Self::Regedit => "regedit",
+            Self::Taskmgr => "taskmgr"
+        }
+    }
+}
+
+/// Runtimes which can be installed to the prefix using winetricks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PrefixVerb {
+    Vcrun2022,
+    Vcrun2019,
+    Vcrun2010,
+    D3dcompiler43,
+    D3dcompiler47,
+    Dotnet48,
+    Corefonts
+}
+
+impl PrefixVerb {
+    #[inline]
+    pub fn list() -> &'static [Self] {
+        &[
+            Self::Vcrun2022,
+            Self::Vcrun2019,
+            Self::Vcrun2010,
+            Self::D3dcompiler43,
+            Self::D3dcompiler47,
+            Self::Dotnet48,
+            Self::Corefonts
+        ]
+    }
+
+    #[inline]
+    /// Get winetricks verb name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Vcrun2022     => "vcrun2022",
+            Self::Vcrun2019     => "vcrun2019",
+            Self::Vcrun2010     => "vcrun2010",
+            Self::D3dcompiler43 => "d3dcompiler_43",
+            Self::D3dcompiler47 => "d3dcompiler_47",
+            Self::Dotnet48      => "dotnet48",
+            Self::Corefonts     => "corefonts"
+        }
+    }
+
+    #[inline]
+    pub fn title(&self) -> &'static str {
+        match self {
+            Self::Vcrun2022     => "Visual C++ 2015-2022",
+            Self::Vcrun2019     => "Visual C++ 2015-2019",
+            Self::Vcrun2010     => "Visual C++ 2010",
+            Self::D3dcompiler43 => "d3dcompiler_43.dll",
+            Self::D3dcompiler47 => "d3dcompiler_47.dll",
+            Self::Dotnet48      => ".NET Framework 4.8",
+            Self::Corefonts     => "Microsoft Core Fonts"
+        }
+    }
+}
+
+/// Get path to the wine prefix inside of the given prefix folder
+///
+/// Proton keeps the wine prefix in the "pfx" subfolder of its data folder
+pub fn get_wine_prefix(wine: &Wine, prefix: impl AsRef<Path>) -> PathBuf {
+    let prefix = prefix.as_ref();
+
+    if wine.get_proton_script().is_some() {
+        prefix.join("pfx")
+    } else {
+        prefix.to_path_buf()
+    }
+}
+
+/// Prepare wine command with the prefix environment
+fn wine_command(wine: &Wine, prefix: impl AsRef<Path>, executable: impl AsRef<Path>) -> Command {
+    let mut command = Command::new(executable.as_ref());
+
+    command.env("WINEARCH", "win64");
+    command.env("WINEPREFIX", get_wine_prefix(wine, prefix));
+    command.env("WINE", wine.get_executable());
+
+    // Winetricks looks for wineserver next to the wine binary otherwise
+    let wineserver = wine.get_folder().join("bin/wineserver");
+
+    if wineserver.exists() {
+        command.env("WINESERVER", wineserver);
+    }
+
+    command
+}
+
+#[inline]
+/// Run wine tool in the prefix without waiting for it
+pub fn run_tool(wine: &Wine, prefix: impl AsRef<Path>, tool: PrefixTool) -> anyhow::Result<Child> {
+    Ok(wine_command(wine, prefix, wine.get_executable())
+        .arg(tool.name())
+        .spawn()?)
+}
+
+#[inline]
+/// Find winetricks script in the system
+pub fn find_winetricks() -> Option<PathBuf> {
+    system_wine::find_executable("winetricks")
+}
+
+/// Install verb to the prefix using winetricks
+pub fn install_verb(wine: &Wine, prefix: impl AsRef<Path>, verb: PrefixVerb) -> anyhow::Result<()> {
+    let Some(winetricks) = find_winetricks() else {
+        anyhow::bail!("Winetricks is not installed");
+    };
+
+    let output = wine_command(wine, prefix, winetricks)
+        .arg("--unattended")
+        .arg(verb.name())
+        .output()?;
+
+    if !output.status.success() {
+        anyhow::bail!("Failed to install {}: {}", verb.name(), String::from_utf8_lossy(&output.stderr).trim());
+    }
+
+    Ok(())
+}
+
+/// Set values in the prefix registry using regedit
+///
+/// All the values are imported from a single `.reg` file
+/// so wine is started only once. Regedit is not started
+/// if the same values were already applied to the prefix
+pub fn apply_registry(wine: &Wine, prefix: impl AsRef<Path>, values: &[RegistryValue]) -> anyhow::Result<()> {
+    if values.is_empty() {
+        return Ok(());
+    }
+
+    let prefix = prefix.as_ref();
+    let wine_prefix = get_wine_prefix(wine, prefix);
+
+    // Proton creates its prefix on the first launch by itself
+    // so values are applied only on the next launches
+    if !wine_prefix.exists() {
+        tracing::warn!("Wine prefix doesn't exist, registry values are not applied: {:?}", wine_prefix);
+
+        return Ok(());
+    }
+
+    let reg_file = registry::to_reg_file(values)?;
+    let hash = format!("{:016x}", xxhash_rust::xxh3::xxh3_64(reg_file.as_bytes()));
+
+    let hash_file = wine_prefix.join(REGISTRY_HASH_FILE);
+
+    if std::fs::read_to_string(&hash_file).is_ok_and(|applied| applied.trim() == hash) {
+        return Ok(());
+    }
+
+    let file = wine_prefix.join("launcher-registry.reg");
+
+    std::fs::write(&file, reg_file)?;
+
+    let output = wine_command(wine, prefix, wine.get_executable())
+        .arg("regedit")
+        .arg("/S")
+        .arg(&file)
+        .output();
+
+    std::fs::remove_file(&file)?;
+
+    let output = output?;
+
+    if !output.status.success() {
+        anyhow::bail!("Failed to update prefix registry: {}", String::from_utf8_lossy(&output.stderr).trim());
+    }
+
+    std::fs::write(hash_file, hash)?;
+
+    Ok(())
+}
+
+/// Get list of the verbs applied to the prefix by winetricks
+pub fn get_applied_verbs(wine: &Wine, prefix: impl AsRef<Path>) -> Vec<String> {
+    let log = get_wine_prefix(wine, prefix).join(WINETRICKS_LOG);
+
+    let Ok(log) = std::fs::read_to_string(log) else {
+        return Vec::new();
+    };
+
+    let mut verbs = Vec::new();
+
+    for verb in log.lines().map(str::trim) {
+        if !verb.is_empty() && !verbs.iter().any(|applied| applied == verb) {
+            verbs.push(verb.to_string());
+        }
+    }
+
+    verbs
+}
+
+/// Get list of the fonts installed to the prefix
+pub fn get_fonts(wine: &Wine, prefix: impl AsRef<Path>) -> Vec<String> {
+    let fonts = get_wine_prefix(wine, prefix).join("drive_c/windows/Fonts");
+
+    let Ok(entries) = fonts.read_dir() else {
+        return Vec::new();
+    };
+
+    let mut fonts = entries.flatten()
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .filter(|name| {
+            let name = name.to_ascii_lowercase();
+
+            name.ends_with(".ttf") || name.ends_with(".ttc") || name.ends_with(".otf")
+        })
+        .collect::<Vec<_>>();
+
+    fonts.sort();
+
+    fonts
+}
+
+/// Get DLL overrides from the prefix registry
+///
+/// Returns list of DLL names and their override values, e.g. `("d3d11", "native")`
+pub fn get_dll_overrides(wine: &Wine, prefix: impl AsRef<Path>) -> Vec<(String, String)> {
+    let registry = get_wine_prefix(wine, prefix).join("user.reg");
+
+    let Ok(registry) = std::fs::read_to_string(registry) else {
+        return Vec::new();
+    };
+
+    let mut overrides = Vec::new();
+    let mut in_section = false;
+
+    for line in registry.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_section = line.starts_with("[Software\\\\Wine\\\\DllOverrides]");
+
+            continue;
+        }
+
+        if !in_section {
+            continue;
+        }
+
+        // "d3d11"="native"
+        if let Some((name, value)) = line.split_once('=') {
+            let name = name.trim_matches('"').trim_start_matches('*');
+            let value = value.trim_matches('"');
+
+            overrides.push((name.to_string(), value.to_string()));
+        }
+    }
+
+    overrides
+}
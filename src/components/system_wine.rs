@@ -0,0 +1,125 @@
+use std::path::{Path, PathBuf};
+
+/// Name of the proton launching script
+pub const PROTON_SCRIPT: &str = "proton";
+
+/// Get existing Steam installation folders
+///
+/// - `$HOME/.steam/root`
+/// - `$HOME/.local/share/Steam`
+/// - `$HOME/.var/app/com.valvesoftware.Steam/data/Steam` (flatpak)
+pub fn get_steam_folders() -> Vec<PathBuf> {
+    let Ok(home) = std::env::var("HOME").map(PathBuf::from) else {
+        return Vec::new();
+    };
+
+    let mut folders: Vec<PathBuf> = Vec::new();
+
+    let candidates = [
+        home.join(".steam/root"),
+        home.join(".local/share/Steam"),
+        home.join(".var/app/com.valvesoftware.Steam/data/Steam")
+    ];
+
+    for folder in candidates {
+        // `.steam/root` is usually a symlink to one of other folders
+        if let Ok(folder) = folder.canonicalize() {
+            if !folders.contains(&folder) {
+                folders.push(folder);
+            }
+        }
+    }
+
+    folders
+}
+
+/// Find executable with the given name in the `PATH` folders
+pub fn find_executable(name: impl AsRef<str>) -> Option<PathBuf> {
+    let paths = std::env::var("PATH").ok()?;
+
+    paths.split(':')
+        .map(|folder| PathBuf::from(folder).join(name.as_ref()))
+        .find(|executable| executable.is_file())
+}
+
+#[inline]
+/// Find wine executable in the `PATH` folders
+pub fn find_wine_executable() -> Option<PathBuf> {
+    find_executable("wine64").or_else(|| find_executable("wine"))
+}
+
+#[inline]
+/// Find wine executable in the given build folder
+///
+/// - `bin/wine64` or `bin/wine`
+/// - `files/bin/wine64` or `files/bin/wine`
+pub fn find_build_executable(folder: impl AsRef<Path>) -> Option<PathBuf> {
+    ["bin", "files/bin"].into_iter()
+        .flat_map(|bin| ["wine64", "wine"].map(|name| folder.as_ref().join(bin).join(name)))
+        .find(|executable| executable.is_file())
+}
+
+/// Find Proton builds installed by Steam or manually
+/// to the Steam's compatibility tools folder
+///
+/// Returns list of builds names and folders
+pub fn find_proton_builds() -> Vec<(String, PathBuf)> {
+    let mut builds = Vec::new();
+
+    for steam in get_steam_folders() {
+        let folders = [
+            steam.join("compatibilitytools.d"),
+            steam.join("steamapps/common")
+        ];
+
+        for folder in folders {
+            let Ok(entries) = folder.read_dir() else {
+                continue;
+            };
+
+            for entry in entries.flatten() {
+                let path = entry.path();
+
+                if path.join(PROTON_SCRIPT).is_file() {
+                    builds.push((entry.file_name().to_string_lossy().to_string(), path));
+                }
+            }
+        }
+    }
+
+    builds.sort_by(|a, b| b.0.cmp(&a.0));
+    builds.dedup_by(|a, b| a.0 == b.0);
+
+    builds
+}
+
+/// Find wine builds installed manually to the Steam's compatibility
+/// tools folder which don't have the Proton launching script
+///
+/// Returns list of builds names and wine executables
+pub fn find_wine_builds() -> Vec<(String, PathBuf)> {
+    let mut builds = Vec::new();
+
+    for steam in get_steam_folders() {
+        let Ok(entries) = steam.join("compatibilitytools.d").read_dir() else {
+            continue;
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+
+            if path.join(PROTON_SCRIPT).is_file() {
+                continue;
+            }
+
+            if let Some(executable) = find_build_executable(&path) {
+                builds.push((entry.file_name().to_string_lossy().to_string(), executable));
+            }
+        }
+    }
+
+    builds.sort_by(|a, b| b.0.cmp(&a.0));
+    builds.dedup_by(|a, b| a.0 == b.0);
+
+    builds
+}
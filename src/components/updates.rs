@@ -0,0 +1,136 @@
+use std::path::PathBuf;
+
+use crate::{
+    config,
+    COMPONENTS_FOLDER
+};
+
+use super::wine::Wine;
+use super::dxvk::Dxvk;
+use super::vkd3d::Vkd3d;
+use super::nvapi::Nvapi;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+/// Newer versions of the selected components
+pub struct ComponentsUpdates {
+    pub wine: Option<Wine>,
+    pub dxvk: Option<Dxvk>,
+    pub vkd3d: Option<Vkd3d>,
+    pub nvapi: Option<Nvapi>
+}
+
+impl ComponentsUpdates {
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.wine.is_none() && self.dxvk.is_none() && self.vkd3d.is_none() && self.nvapi.is_none()
+    }
+
+    /// Get titles of the updated components versions
+    pub fn titles(&self) -> Vec<String> {
+        [
+            self.wine.as_ref().map(|wine| wine.title.clone()),
+            self.dxvk.as_ref().map(|dxvk| dxvk.name.clone()),
+            self.vkd3d.as_ref().map(|vkd3d| vkd3d.name.clone()),
+            self.nvapi.as_ref().map(|nvapi| nvapi.name.clone())
+        ].into_iter().flatten().collect()
+    }
+}
+
+/// Get newest version from the list if the selected one is listed after it
+///
+/// Versions which are not listed (e.g. system wine builds)
+/// can't be compared so they're never updated
+fn get_newer<T: PartialEq + Clone>(versions: &[T], selected: &T) -> Option<T> {
+    match versions.iter().position(|version| version == selected) {
+        Some(0) | None => None,
+        Some(_) => versions.first().cloned()
+    }
+}
+
+/// Check newer versions of the selected components
+///
+/// Components with `latest` version are ignored
+/// because they're updated automatically
+pub fn get_updates() -> anyhow::Result<ComponentsUpdates> {
+    let components = config::get().components;
+
+    let mut updates = ComponentsUpdates::default();
+
+    if components.wine.version != "latest" {
+        updates.wine = get_newer(&Wine::versions()?, &Wine::from_config()?);
+    }
+
+    if components.dxvk.version != "latest" {
+        updates.dxvk = get_newer(&Dxvk::versions()?, &Dxvk::from_config()?);
+    }
+
+    if components.vkd3d.enabled && components.vkd3d.version != "latest" {
+        updates.vkd3d = get_newer(&Vkd3d::versions()?, &Vkd3d::from_config()?);
+    }
+
+    if components.nvapi.enabled && components.nvapi.version != "latest" {
+        updates.nvapi = get_newer(&Nvapi::versions()?, &Nvapi::from_config()?);
+    }
+
+    Ok(updates)
+}
+
+/// Get downloaded components folders which are used
+/// neither globally nor by games settings overrides
+///
+/// Fails if some of the used versions can't be resolved,
+/// e.g. when the components channel is unavailable,
+/// so needed components are never deleted. Nothing is returned
+/// while some used versions are not downloaded yet, so the previous
+/// versions are kept until the new ones are available
+pub fn get_unused_folders() -> anyhow::Result<Vec<PathBuf>> {
+    let config = config::get();
+
+    let mut used = vec![
+        Wine::from_config()?.get_folder(),
+        Dxvk::from_config()?.get_folder()
+    ];
+
+    // Disabled components are not needed anymore
+    if config.components.vkd3d.enabled {
+        used.push(Vkd3d::from_config()?.get_folder());
+    }
+
+    if config.components.nvapi.enabled {
+        used.push(Nvapi::from_config()?.get_folder());
+    }
+
+    for overrides in config.games.get_all_overrides() {
+        if let Some(wine) = overrides.wine {
+            used.push(Wine::from_version(wine.build, wine.version)?.get_folder());
+        }
+
+        if let Some(dxvk) = overrides.dxvk {
+            used.push(Dxvk::from_version(dxvk.build, dxvk.version)?.get_folder());
+        }
+    }
+
+    if used.iter().any(|folder| !folder.exists()) {
+        return Ok(Vec::new());
+    }
+
+    let mut unused = Vec::new();
+
+    for component in ["wine", "dxvk", "vkd3d", "nvapi"] {
+        let path = COMPONENTS_FOLDER.join(component);
+
+        if !path.exists() {
+            continue;
+        }
+
+        for entry in path.read_dir()?.flatten() {
+            let folder = entry.path();
+
+            if entry.file_type()?.is_dir() && !used.contains(&folder) {
+                unused.push(folder);
+            }
+        }
+    }
+
+    Ok(unused)
+}
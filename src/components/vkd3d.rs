@@ -0,0 +1,31 @@
+use crate::config;
+
+use super::channel_component::{
+    ChannelComponent,
+    ChannelComponentKind,
+    PrefixDllsKind,
+    DownloadComponentQueuedTask
+};
+
+use super::prefix_dlls::PrefixDlls;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Vkd3dKind;
+
+impl ChannelComponentKind for Vkd3dKind {
+    const NAME: &'static str = "vkd3d";
+
+    #[inline]
+    fn get_config() -> (String, String) {
+        let vkd3d = config::get().components.vkd3d;
+
+        (vkd3d.build, vkd3d.version)
+    }
+}
+
+impl PrefixDllsKind for Vkd3dKind {
+    const DLLS: PrefixDlls = PrefixDlls::Vkd3d;
+}
+
+pub type Vkd3d = ChannelComponent<Vkd3dKind>;
+pub type DownloadVkd3dQueuedTask = DownloadComponentQueuedTask<Vkd3dKind>;
@@ -1,7 +1,5 @@
 use std::cell::Cell;
-use std::path::PathBuf;
-
-use serde_json::Value as Json;
+use std::path::{Path, PathBuf};
 
 use wincompatlib::wine::ext::WineWithExt;
 
@@ -11,11 +9,8 @@ use wincompatlib::wine::{
     WineLoader as WincompatlibWineLoader
 };
 
-use anime_game_core::network::minreq;
 use anime_game_core::archive;
 
-use anime_game_core::network::downloader::DownloaderExt;
-use anime_game_core::network::downloader::basic::Downloader;
 
 use anime_game_core::updater::UpdaterExt;
 
@@ -27,78 +22,283 @@ use crate::{
     COMPONENTS_FOLDER
 };
 
+use crate::network::download;
+
+use crate::games::integrations::standards::integrity::FileHash;
+
 use crate::components::{
     Updater,
     Status
 };
 
 use super::DownloadComponentResolvedTask;
+use super::channel::{self, ChannelVersions};
+use super::system_wine;
+
+/// Prefix of the Steam-installed Proton builds names
+pub const PROTON_NAME_PREFIX: &str = "steam:";
+
+#[inline]
+/// Check if the wine version name belongs to a Proton build
+///
+/// Proton creates its prefix by itself and uses its own
+/// DXVK, VKD3D-Proton and DXVK-NVAPI builds
+pub fn is_proton(name: impl AsRef<str>) -> bool {
+    name.as_ref().starts_with(PROTON_NAME_PREFIX)
+}
+
+/// Prefix of the wine builds names installed to the Steam's compatibility tools folder
+pub const COMPAT_WINE_NAME_PREFIX: &str = "compat:";
+
+/// Name of the wine installed in the system
+pub const SYSTEM_WINE_NAME: &str = "system";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WineSource {
+    /// Wine build downloaded by the launcher
+    Component,
+
+    /// Wine installed in the system, e.g. `/usr/bin/wine64`,
+    /// or to the Steam's compatibility tools folder
+    System {
+        executable: PathBuf
+    },
+
+    /// Proton build installed by Steam or to its compatibility tools folder
+    Proton {
+        folder: PathBuf
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Wine {
     pub name: String,
     pub title: String,
-    pub uri: String
+    pub uri: String,
+    pub hash: Option<FileHash>,
+    pub source: WineSource
 }
 
 impl Wine {
+    #[inline]
     /// Get selected wine build versions list
     pub fn versions() -> anyhow::Result<Vec<Self>> {
-        let components = config::get().components;
-
-        let wine_versions = minreq::get(format!("{}/wine/{}.json", &components.channel, &components.wine.build))
-            .send()?.json::<Vec<Json>>()?;
+        Self::build_versions(config::get().components.wine.build)
+    }
 
-        let mut versions = Vec::new();
+    #[inline]
+    /// Get versions list of the given wine build
+    pub fn build_versions(build: impl AsRef<str>) -> anyhow::Result<Vec<Self>> {
+        Ok(Self::build_channel_versions(build)?.versions)
+    }
 
-        for wine in wine_versions {
-            let name = wine.get("name").and_then(Json::as_str);
-            let title = wine.get("title").and_then(Json::as_str);
-            let uri = wine.get("uri").and_then(Json::as_str);
-
-            if let (Some(name), Some(title), Some(uri)) = (name, title, uri) {
-                versions.push(Self {
-                    name: name.to_owned(),
-                    title: title.to_owned(),
-                    uri: uri.to_owned()
-                });
-            }
-        }
+    #[inline]
+    /// Get selected wine build versions list
+    /// with the skipped malformed entries
+    pub fn channel_versions() -> anyhow::Result<ChannelVersions<Self>> {
+        Self::build_channel_versions(config::get().components.wine.build)
+    }
 
-        Ok(versions)
+    /// Get versions list of the given wine build
+    /// with the skipped malformed entries
+    pub fn build_channel_versions(build: impl AsRef<str>) -> anyhow::Result<ChannelVersions<Self>> {
+        channel::get_versions("wine", build, |entry| Ok(Self {
+            name: channel::get_field(entry, "name")?,
+            title: channel::get_field(entry, "title")?,
+            uri: channel::get_field(entry, "uri")?,
+            hash: channel::get_hash(entry)?,
+            source: WineSource::Component
+        }))
     }
 
+    #[inline]
     /// Resolve component version from the config file
     pub fn from_config() -> anyhow::Result<Self> {
         let wine_info = config::get().components.wine;
 
-        for version in Self::versions()? {
-            if version.name.contains(&wine_info.version) || wine_info.version == "latest" {
-                return Ok(version);
+        Self::from_version(wine_info.build, wine_info.version)
+    }
+
+    /// Resolve component version of the given wine build
+    pub fn from_version(build: impl AsRef<str>, version: impl AsRef<str>) -> anyhow::Result<Self> {
+        let version = version.as_ref();
+
+        if let Some(wine) = Self::from_system(version) {
+            return Ok(wine);
+        }
+
+        let versions = Self::build_versions(build);
+
+        for wine in versions.iter().flatten() {
+            if wine.name.contains(version) || version == "latest" {
+                return Ok(wine.clone());
             }
         }
 
+        // Use downloaded version which is not listed in the build
+        if version != "latest" && COMPONENTS_FOLDER.join("wine").join(version).exists() {
+            return Ok(Self::from_downloaded(version));
+        }
+
+        versions?;
+
         anyhow::bail!("No appropriate wine version found")
     }
 
     #[inline]
+    /// Describe downloaded version by its folder name
+    ///
+    /// Such version can't be downloaded again because its uri is unknown
+    pub fn from_downloaded(name: impl AsRef<str>) -> Self {
+        Self {
+            name: name.as_ref().to_string(),
+            title: name.as_ref().to_string(),
+            uri: String::new(),
+            hash: None,
+            source: WineSource::Component
+        }
+    }
+
+    /// Get list of wine and Proton builds installed outside of the launcher
+    pub fn system_versions() -> Vec<Self> {
+        let mut versions = Vec::new();
+
+        if let Some(executable) = system_wine::find_wine_executable() {
+            versions.push(Self {
+                name: String::from(SYSTEM_WINE_NAME),
+                title: format!("System ({})", executable.to_string_lossy()),
+                uri: String::new(),
+                hash: None,
+                source: WineSource::System { executable }
+            });
+        }
+
+        for (name, folder) in system_wine::find_proton_builds() {
+            versions.push(Self {
+                name: format!("{PROTON_NAME_PREFIX}{name}"),
+                title: format!("Steam ({name})"),
+                uri: String::new(),
+                hash: None,
+                source: WineSource::Proton { folder }
+            });
+        }
+
+        for (name, executable) in system_wine::find_wine_builds() {
+            versions.push(Self {
+                name: format!("{COMPAT_WINE_NAME_PREFIX}{name}"),
+                title: format!("Compatibility tools ({name})"),
+                uri: String::new(),
+                hash: None,
+                source: WineSource::System { executable }
+            });
+        }
+
+        versions
+    }
+
+    #[inline]
+    /// Find wine or Proton build installed outside of the launcher
+    pub fn from_system(name: impl AsRef<str>) -> Option<Self> {
+        Self::system_versions()
+            .into_iter()
+            .find(|version| version.name == name.as_ref())
+    }
+
+    /// Get list of downloaded wine versions
+    pub fn downloaded() -> anyhow::Result<Vec<Self>> {
+        let path = COMPONENTS_FOLDER.join("wine");
+
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut versions = Vec::new();
+
+        for entry in path.read_dir()?.flatten() {
+            if entry.file_type()?.is_dir() {
+                versions.push(Self::from_downloaded(entry.file_name().to_string_lossy()));
+            }
+        }
+
+        versions.sort_by(|a, b| b.name.cmp(&a.name));
+
+        Ok(versions)
+    }
+
+    /// Append downloaded versions which are missing in the given list
+    pub fn with_downloaded(versions: impl IntoIterator<Item = Self>) -> Vec<Self> {
+        let mut versions = versions.into_iter().collect::<Vec<_>>();
+
+        for downloaded in Self::downloaded().unwrap_or_default() {
+            if !versions.iter().any(|version| version.name == downloaded.name) {
+                versions.push(downloaded);
+            }
+        }
+
+        versions
+    }
+
     /// Get wine component folder path
     pub fn get_folder(&self) -> PathBuf {
-        COMPONENTS_FOLDER
-            .join("wine")
-            .join(&self.name)
+        match &self.source {
+            WineSource::Component => COMPONENTS_FOLDER
+                .join("wine")
+                .join(&self.name),
+
+            // /usr/bin/wine64 -> /usr
+            // compatibilitytools.d/build/files/bin/wine -> compatibilitytools.d/build/files
+            WineSource::System { executable } => executable.parent()
+                .and_then(Path::parent)
+                .map(Path::to_path_buf)
+                .unwrap_or_default(),
+
+            // Older Proton versions store wine files in the "dist" folder
+            WineSource::Proton { folder } => {
+                let files = folder.join("files");
+
+                if files.exists() {
+                    files
+                } else {
+                    folder.join("dist")
+                }
+            }
+        }
     }
 
-    #[inline]
     /// Get path to the wine executable
     pub fn get_executable(&self) -> PathBuf {
-        self.get_folder().join("bin/wine64")
+        match &self.source {
+            WineSource::System { executable } => executable.clone(),
+
+            _ => self.get_folder().join("bin/wine64")
+        }
+    }
+
+    /// Get path to the Proton launching script
+    pub fn get_proton_script(&self) -> Option<PathBuf> {
+        match &self.source {
+            WineSource::Proton { folder } => Some(folder.join(system_wine::PROTON_SCRIPT)),
+
+            _ => None
+        }
     }
 
     #[inline]
     /// Check if the component is downloaded
+    ///
+    /// System and Proton builds are always considered downloaded
     pub fn is_downloaded(&self) -> bool {
-        self.get_folder().exists()
+        match &self.source {
+            WineSource::Component => self.get_folder().exists(),
+
+            _ => true
+        }
+    }
+
+    #[inline]
+    /// Check if the build is managed by the launcher
+    pub fn is_component(&self) -> bool {
+        self.source == WineSource::Component
     }
 
     /// Download component
@@ -106,6 +306,7 @@ impl Wine {
         let (sender, receiver) = flume::unbounded();
 
         let download_uri = self.uri.clone();
+        let download_hash = self.hash.clone();
 
         Ok(Updater {
             status: Cell::new(Status::Downloading),
@@ -116,26 +317,18 @@ impl Wine {
             updater: receiver,
 
             worker: Some(std::thread::spawn(move || -> anyhow::Result<()> {
-                let downloader = Downloader::new(download_uri);
-
                 let path = COMPONENTS_FOLDER.join("wine");
-                let archive = path.join(downloader.file_name());
+                let archive = path.join(download::get_file_name(&download_uri));
 
                 // Create wine dir if needed
 
                 std::fs::create_dir_all(&path)?;
 
-                // Download update archive
-
-                let mut updater = downloader.download(&archive)?;
+                // Download update archive or take it from the cache
 
-                while !updater.is_finished() {
-                    sender.send((
-                        Status::Downloading,
-                        updater.current(),
-                        updater.total()
-                    ))?;
-                }
+                channel::download_archive(&download_uri, &archive, download_hash.as_ref(), |status, current, total| {
+                    Ok(sender.send((status, current, total))?)
+                })?;
 
                 // Extract archive
 
@@ -5,12 +5,18 @@ use crate::i18n;
 
 pub mod wine;
 pub mod dxvk;
+pub mod vkd3d;
+pub mod nvapi;
+pub mod updates;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Components {
     pub channel: String,
     pub wine: wine::Wine,
-    pub dxvk: dxvk::Dxvk
+    pub dxvk: dxvk::Dxvk,
+    pub vkd3d: vkd3d::Vkd3d,
+    pub nvapi: nvapi::Nvapi,
+    pub updates: updates::Updates
 }
 
 impl Default for Components {
@@ -24,7 +30,10 @@ impl Default for Components {
             },
 
             wine: wine::Wine::default(),
-            dxvk: dxvk::Dxvk::default()
+            dxvk: dxvk::Dxvk::default(),
+            vkd3d: vkd3d::Vkd3d::default(),
+            nvapi: nvapi::Nvapi::default(),
+            updates: updates::Updates::default()
         }
     }
 }
@@ -46,7 +55,19 @@ impl From<&Json> for Components {
 
             dxvk: value.get("dxvk")
                 .map(dxvk::Dxvk::from)
-                .unwrap_or(default.dxvk)
+                .unwrap_or(default.dxvk),
+
+            vkd3d: value.get("vkd3d")
+                .map(vkd3d::Vkd3d::from)
+                .unwrap_or(default.vkd3d),
+
+            nvapi: value.get("nvapi")
+                .map(nvapi::Nvapi::from)
+                .unwrap_or(default.nvapi),
+
+            updates: value.get("updates")
+                .map(updates::Updates::from)
+                .unwrap_or(default.updates)
         }
     }
 }
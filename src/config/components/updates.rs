@@ -0,0 +1,67 @@
+use serde::{Serialize, Deserialize};
+use serde_json::Value as Json;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum UpdatePolicy {
+    /// Download newer versions automatically
+    Always,
+
+    /// Notify user and ask to download newer versions
+    Ask,
+
+    /// Don't check for newer versions
+    Never
+}
+
+impl Default for UpdatePolicy {
+    #[inline]
+    fn default() -> Self {
+        Self::Ask
+    }
+}
+
+impl From<&Json> for UpdatePolicy {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        serde_json::from_value(value.clone()).unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Updates {
+    /// What to do when newer versions of the selected components are available
+    ///
+    /// Components with `latest` version are always updated
+    pub policy: UpdatePolicy,
+
+    /// Delete downloaded components which are not used
+    /// neither globally nor by games settings
+    pub delete_unused: bool
+}
+
+impl Default for Updates {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            policy: UpdatePolicy::default(),
+            delete_unused: true
+        }
+    }
+}
+
+impl From<&Json> for Updates {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        let default = Self::default();
+
+        Self {
+            policy: value.get("policy")
+                .map(UpdatePolicy::from)
+                .unwrap_or(default.policy),
+
+            delete_unused: value.get("delete_unused")
+                .and_then(Json::as_bool)
+                .unwrap_or(default.delete_unused)
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use serde::{Serialize, Deserialize};
+use serde_json::Value as Json;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Vkd3d {
+    pub enabled: bool,
+    pub build: String,
+    pub version: String
+}
+
+impl Default for Vkd3d {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            build: String::from("vkd3d-proton"),
+            version: String::from("latest")
+        }
+    }
+}
+
+impl From<&Json> for Vkd3d {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        let default = Self::default();
+
+        Self {
+            enabled: value.get("enabled")
+                .and_then(Json::as_bool)
+                .unwrap_or(default.enabled),
+
+            build: value.get("build")
+                .and_then(Json::as_str)
+                .map(String::from)
+                .unwrap_or(default.build),
+
+            version: value.get("version")
+                .and_then(Json::as_str)
+                .map(String::from)
+                .unwrap_or(default.version)
+        }
+    }
+}
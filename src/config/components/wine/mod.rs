@@ -2,6 +2,7 @@ use serde::{Serialize, Deserialize};
 use serde_json::Value as Json;
 
 pub mod prefix;
+pub mod snapshots;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Wine {
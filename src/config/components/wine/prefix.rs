@@ -5,10 +5,13 @@ use serde_json::Value as Json;
 
 use crate::LAUNCHER_FOLDER;
 
+use super::snapshots::Snapshots;
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Prefix {
     pub path: PathBuf,
-    pub install_corefonts: bool
+    pub install_corefonts: bool,
+    pub snapshots: Snapshots
 }
 
 impl Default for Prefix {
@@ -19,7 +22,8 @@ impl Default for Prefix {
                 .join("components")
                 .join("prefix"),
 
-            install_corefonts: true
+            install_corefonts: true,
+            snapshots: Snapshots::default()
         }
     }
 }
@@ -37,7 +41,11 @@ impl From<&Json> for Prefix {
 
             install_corefonts: value.get("install_corefonts")
                 .and_then(Json::as_bool)
-                .unwrap_or(default.install_corefonts)
+                .unwrap_or(default.install_corefonts),
+
+            snapshots: value.get("snapshots")
+                .map(Snapshots::from)
+                .unwrap_or(default.snapshots)
         }
     }
 }
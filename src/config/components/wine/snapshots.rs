@@ -0,0 +1,83 @@
+use std::path::PathBuf;
+
+use serde::{Serialize, Deserialize};
+use serde_json::Value as Json;
+
+use crate::LAUNCHER_FOLDER;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SnapshotMode {
+    /// Pack prefix files into a tar archive
+    Tar,
+
+    /// Copy prefix files, using reflinks on supported filesystems
+    Copy
+}
+
+impl Default for SnapshotMode {
+    #[inline]
+    fn default() -> Self {
+        // Copies take full prefix size on filesystems without reflinks support
+        Self::Tar
+    }
+}
+
+impl From<&Json> for SnapshotMode {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        serde_json::from_value(value.clone()).unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Snapshots {
+    /// Take prefix snapshots automatically before
+    /// applying DXVK and updating the prefix
+    pub enabled: bool,
+
+    /// Path to the snapshots folder
+    pub path: PathBuf,
+
+    pub mode: SnapshotMode,
+
+    /// Amount of snapshots kept for each prefix
+    pub keep: u64
+}
+
+impl Default for Snapshots {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            path: LAUNCHER_FOLDER.join("snapshots"),
+            mode: SnapshotMode::default(),
+            keep: 3
+        }
+    }
+}
+
+impl From<&Json> for Snapshots {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        let default = Self::default();
+
+        Self {
+            enabled: value.get("enabled")
+                .and_then(Json::as_bool)
+                .unwrap_or(default.enabled),
+
+            path: value.get("path")
+                .and_then(Json::as_str)
+                .map(PathBuf::from)
+                .unwrap_or(default.path),
+
+            mode: value.get("mode")
+                .map(SnapshotMode::from)
+                .unwrap_or(default.mode),
+
+            keep: value.get("keep")
+                .and_then(Json::as_u64)
+                .unwrap_or(default.keep)
+        }
+    }
+}
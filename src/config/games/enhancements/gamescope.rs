@@ -0,0 +1,236 @@
+use serde::{Serialize, Deserialize};
+use serde_json::Value as Json;
+
+use super::hud::HUD;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GamescopeUpscaler {
+    /// `--filter linear`
+    Linear,
+
+    /// `--filter nearest`
+    Nearest,
+
+    /// `--filter fsr`
+    FSR,
+
+    /// `--filter nis`
+    NIS,
+
+    /// `--filter pixel`
+    Pixel
+}
+
+impl Default for GamescopeUpscaler {
+    #[inline]
+    fn default() -> Self {
+        Self::Linear
+    }
+}
+
+impl From<&Json> for GamescopeUpscaler {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        serde_json::from_value(value.clone()).unwrap_or_default()
+    }
+}
+
+impl GamescopeUpscaler {
+    #[inline]
+    pub fn list() -> &'static [Self] {
+        &[Self::Linear, Self::Nearest, Self::FSR, Self::NIS, Self::Pixel]
+    }
+
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Linear  => "linear",
+            Self::Nearest => "nearest",
+            Self::FSR     => "fsr",
+            Self::NIS     => "nis",
+            Self::Pixel   => "pixel"
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GamescopeWindowMode {
+    Windowed,
+
+    /// `--borderless`
+    Borderless,
+
+    /// `--fullscreen`
+    Fullscreen
+}
+
+impl Default for GamescopeWindowMode {
+    #[inline]
+    fn default() -> Self {
+        Self::Fullscreen
+    }
+}
+
+impl From<&Json> for GamescopeWindowMode {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        serde_json::from_value(value.clone()).unwrap_or_default()
+    }
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// Gamescope resolution. Zero values are not passed to gamescope
+pub struct GamescopeResolution {
+    pub width: u64,
+    pub height: u64
+}
+
+impl From<&Json> for GamescopeResolution {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        serde_json::from_value(value.clone()).unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Gamescope {
+    pub enabled: bool,
+
+    /// Gamescope window resolution, `--output-width` and `--output-height`
+    pub output: GamescopeResolution,
+
+    /// Game rendering resolution, `--nested-width` and `--nested-height`
+    pub internal: GamescopeResolution,
+
+    /// Frame rate limit, `--nested-refresh`. Zero means no limit
+    pub refresh_rate: u64,
+
+    pub upscaler: GamescopeUpscaler,
+
+    /// FSR and NIS upscalers sharpness, from 0 to 20 where 0 is maximum sharpness
+    ///
+    /// Default: 2
+    pub sharpness: u64,
+
+    pub window_mode: GamescopeWindowMode,
+
+    /// `--hdr-enabled`
+    pub hdr: bool,
+
+    /// `--force-grab-cursor`
+    pub grab_cursor: bool
+}
+
+impl Default for Gamescope {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            output: GamescopeResolution::default(),
+            internal: GamescopeResolution::default(),
+            refresh_rate: 0,
+            upscaler: GamescopeUpscaler::default(),
+            sharpness: 2,
+            window_mode: GamescopeWindowMode::default(),
+            hdr: false,
+            grab_cursor: false
+        }
+    }
+}
+
+impl From<&Json> for Gamescope {
+    fn from(value: &Json) -> Self {
+        let default = Self::default();
+
+        Self {
+            enabled: value.get("enabled")
+                .and_then(Json::as_bool)
+                .unwrap_or(default.enabled),
+
+            output: value.get("output")
+                .map(GamescopeResolution::from)
+                .unwrap_or(default.output),
+
+            internal: value.get("internal")
+                .map(GamescopeResolution::from)
+                .unwrap_or(default.internal),
+
+            refresh_rate: value.get("refresh_rate")
+                .and_then(Json::as_u64)
+                .unwrap_or(default.refresh_rate),
+
+            upscaler: value.get("upscaler")
+                .map(GamescopeUpscaler::from)
+                .unwrap_or(default.upscaler),
+
+            sharpness: value.get("sharpness")
+                .and_then(Json::as_u64)
+                .unwrap_or(default.sharpness),
+
+            window_mode: value.get("window_mode")
+                .map(GamescopeWindowMode::from)
+                .unwrap_or(default.window_mode),
+
+            hdr: value.get("hdr")
+                .and_then(Json::as_bool)
+                .unwrap_or(default.hdr),
+
+            grab_cursor: value.get("grab_cursor")
+                .and_then(Json::as_bool)
+                .unwrap_or(default.grab_cursor)
+        }
+    }
+}
+
+impl Gamescope {
+    /// Get gamescope command which should wrap the game command
+    ///
+    /// MangoHUD is shown by gamescope itself using `--mangoapp`
+    /// because the usual overlay doesn't work inside of it.
+    /// Returns `None` if gamescope is disabled
+    pub fn get_command(&self, hud: HUD) -> Option<String> {
+        if !self.enabled {
+            return None;
+        }
+
+        let mut command = String::from("gamescope");
+
+        if self.output.width > 0 && self.output.height > 0 {
+            command = format!("{command} --output-width {} --output-height {}", self.output.width, self.output.height);
+        }
+
+        if self.internal.width > 0 && self.internal.height > 0 {
+            command = format!("{command} --nested-width {} --nested-height {}", self.internal.width, self.internal.height);
+        }
+
+        if self.refresh_rate > 0 {
+            command = format!("{command} --nested-refresh {}", self.refresh_rate);
+        }
+
+        command = format!("{command} --filter {}", self.upscaler.name());
+
+        if matches!(self.upscaler, GamescopeUpscaler::FSR | GamescopeUpscaler::NIS) {
+            command = format!("{command} --sharpness {}", self.sharpness);
+        }
+
+        match self.window_mode {
+            GamescopeWindowMode::Windowed => (),
+            GamescopeWindowMode::Borderless => command = format!("{command} --borderless"),
+            GamescopeWindowMode::Fullscreen => command = format!("{command} --fullscreen")
+        }
+
+        if self.hdr {
+            command = format!("{command} --hdr-enabled");
+        }
+
+        if self.grab_cursor {
+            command = format!("{command} --force-grab-cursor");
+        }
+
+        if hud == HUD::MangoHUD {
+            command = format!("{command} --mangoapp");
+        }
+
+        Some(format!("{command} --"))
+    }
+}
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 use serde::{Serialize, Deserialize};
 use serde_json::Value as Json;
@@ -26,25 +27,218 @@ impl From<&Json> for HUD {
 
 impl HUD {
     /// Get environment variables corresponding to used wine hud
-    pub fn get_env_vars(&self, gamescope_enabled: bool) -> HashMap<&str, &str> {
+    pub fn get_env_vars(&self, options: &HudOptions, gamescope_enabled: bool) -> HashMap<&str, String> {
         match self {
             Self::None => HashMap::new(),
 
-            Self::DXVK => HashMap::from([
-                ("DXVK_HUD", "fps,frametimes,version,gpuload")
-            ]),
+            Self::DXVK => {
+                // DXVK shows its default HUD elements with "1"
+                let elements = if options.dxvk.is_empty() {
+                    String::from("1")
+                } else {
+                    options.dxvk.join(",")
+                };
+
+                HashMap::from([
+                    ("DXVK_HUD", elements)
+                ])
+            }
 
             Self::MangoHUD => {
+                // Config variables are used by gamescope's mangoapp as well
+                let mut env = options.mangohud.get_env_vars();
+
                 // Don't show mangohud if gamescope is enabled
                 // otherwise it'll be doubled
-                if gamescope_enabled {
-                    HashMap::new()
-                } else {
-                    HashMap::from([
-                        ("MANGOHUD", "1")
-                    ])
+                if !gamescope_enabled {
+                    env.insert("MANGOHUD", String::from("1"));
                 }
+
+                env
             }
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Predefined HUD options
+pub enum HudPreset {
+    Minimal,
+    Default,
+    Full
+}
+
+impl HudPreset {
+    #[inline]
+    pub fn list() -> &'static [Self] {
+        &[Self::Minimal, Self::Default, Self::Full]
+    }
+
+    #[inline]
+    /// Get DXVK HUD elements of the preset
+    pub fn dxvk_elements(&self) -> Vec<String> {
+        let elements: &[&str] = match self {
+            Self::Minimal => &["fps"],
+            Self::Default => &["fps", "frametimes", "version", "gpuload"],
+            Self::Full    => &["full"]
+        };
+
+        elements.iter().map(|element| element.to_string()).collect()
+    }
+
+    #[inline]
+    /// Get MangoHUD inline options of the preset
+    ///
+    /// Default preset has no options so `MANGOHUD_CONFIG` is not set
+    /// and MangoHUD uses user's `MangoHud.conf`
+    pub fn mangohud_options(&self) -> &'static str {
+        match self {
+            Self::Minimal => "fps_only",
+            Self::Default => "",
+            Self::Full    => "full"
+        }
+    }
+
+    /// Find preset with the given DXVK HUD elements
+    pub fn from_dxvk_elements(elements: &[String]) -> Option<Self> {
+        Self::list().iter()
+            .find(|preset| preset.dxvk_elements() == elements)
+            .copied()
+    }
+
+    /// Find preset with the given MangoHUD inline options
+    pub fn from_mangohud_options(options: impl AsRef<str>) -> Option<Self> {
+        Self::list().iter()
+            .find(|preset| preset.mangohud_options() == options.as_ref())
+            .copied()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MangoHudConfigSource {
+    /// `MANGOHUD_CONFIG` variable
+    Inline,
+
+    /// `MANGOHUD_CONFIGFILE` variable
+    File
+}
+
+impl Default for MangoHudConfigSource {
+    #[inline]
+    fn default() -> Self {
+        Self::Inline
+    }
+}
+
+impl From<&Json> for MangoHudConfigSource {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        serde_json::from_value(value.clone()).unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MangoHud {
+    pub source: MangoHudConfigSource,
+
+    /// Comma separated MangoHUD options, e.g. `fps,frametime,position=top-right`
+    pub options: String,
+
+    /// Path to the MangoHUD config file
+    pub file: PathBuf
+}
+
+impl Default for MangoHud {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            source: MangoHudConfigSource::default(),
+            options: HudPreset::Default.mangohud_options().to_string(),
+            file: PathBuf::new()
+        }
+    }
+}
+
+/// MangoHUD options of the default preset stored by the older launcher versions
+const LEGACY_DEFAULT_MANGOHUD_OPTIONS: &str = "fps,frametime,cpu_stats,gpu_stats,ram,vram";
+
+impl From<&Json> for MangoHud {
+    fn from(value: &Json) -> Self {
+        let default = Self::default();
+
+        Self {
+            source: value.get("source")
+                .map(MangoHudConfigSource::from)
+                .unwrap_or(default.source),
+
+            options: value.get("options")
+                .and_then(Json::as_str)
+                .filter(|options| *options != LEGACY_DEFAULT_MANGOHUD_OPTIONS)
+                .map(String::from)
+                .unwrap_or(default.options),
+
+            file: value.get("file")
+                .and_then(Json::as_str)
+                .map(PathBuf::from)
+                .unwrap_or(default.file)
+        }
+    }
+}
+
+impl MangoHud {
+    /// Get environment variables of the selected config source
+    ///
+    /// Nothing is set for empty values so MangoHUD uses its own config
+    pub fn get_env_vars(&self) -> HashMap<&str, String> {
+        match self.source {
+            MangoHudConfigSource::Inline if !self.options.is_empty() => HashMap::from([
+                ("MANGOHUD_CONFIG", self.options.clone())
+            ]),
+
+            MangoHudConfigSource::File if !self.file.as_os_str().is_empty() => HashMap::from([
+                ("MANGOHUD_CONFIGFILE", self.file.to_string_lossy().to_string())
+            ]),
+
+            _ => HashMap::new()
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HudOptions {
+    /// DXVK HUD elements, e.g. `fps`, `frametimes` or `gpuload`
+    pub dxvk: Vec<String>,
+
+    pub mangohud: MangoHud
+}
+
+impl Default for HudOptions {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            dxvk: HudPreset::Default.dxvk_elements(),
+            mangohud: MangoHud::default()
+        }
+    }
+}
+
+impl From<&Json> for HudOptions {
+    fn from(value: &Json) -> Self {
+        let default = Self::default();
+
+        Self {
+            dxvk: value.get("dxvk")
+                .and_then(Json::as_array)
+                .map(|elements| elements.iter()
+                    .filter_map(Json::as_str)
+                    .map(String::from)
+                    .collect()
+                )
+                .unwrap_or(default.dxvk),
+
+            mangohud: value.get("mangohud")
+                .map(MangoHud::from)
+                .unwrap_or(default.mangohud)
+        }
+    }
+}
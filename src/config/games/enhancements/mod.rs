@@ -3,14 +3,28 @@ use serde_json::Value as Json;
 
 pub mod hud;
 pub mod fsr;
+pub mod gamescope;
 
 pub mod prelude {
-    pub use super::hud::HUD;
+    pub use super::hud::{
+        HUD,
+        HudPreset,
+        HudOptions,
+        MangoHud,
+        MangoHudConfigSource
+    };
     pub use super::fsr::{
         FSR,
         FsrQuality
     };
 
+    pub use super::gamescope::{
+        Gamescope,
+        GamescopeResolution,
+        GamescopeUpscaler,
+        GamescopeWindowMode
+    };
+
     pub use super::Enhancements;
 }
 
@@ -19,7 +33,9 @@ use prelude::*;
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Enhancements {
     pub hud: HUD,
+    pub hud_options: HudOptions,
     pub fsr: FSR,
+    pub gamescope: Gamescope,
     pub gamemode: bool
 }
 
@@ -28,7 +44,9 @@ impl Default for Enhancements {
     fn default() -> Self {
         Self {
             hud: HUD::default(),
+            hud_options: HudOptions::default(),
             fsr: FSR::default(),
+            gamescope: Gamescope::default(),
             gamemode: false
         }
     }
@@ -44,10 +62,18 @@ impl From<&Json> for Enhancements {
                 .map(HUD::from)
                 .unwrap_or(default.hud),
 
+            hud_options: value.get("hud_options")
+                .map(HudOptions::from)
+                .unwrap_or(default.hud_options),
+
             fsr: value.get("fsr")
                 .map(FSR::from)
                 .unwrap_or(default.fsr),
 
+            gamescope: value.get("gamescope")
+                .map(Gamescope::from)
+                .unwrap_or(default.gamescope),
+
             gamemode: value.get("gamemode")
                 .and_then(Json::as_bool)
                 .unwrap_or(default.gamemode)
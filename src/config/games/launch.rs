@@ -0,0 +1,184 @@
+use serde::{Serialize, Deserialize};
+use serde_json::Value as Json;
+
+/// Template used when no custom one is set
+pub const DEFAULT_TEMPLATE: &str = "%command%";
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// Command which wraps the game launching command
+pub enum LaunchWrapper {
+    /// `gamemoderun`
+    Gamemode,
+
+    /// `prime-run`
+    PrimeRun,
+
+    /// `obs-gamecapture`
+    ObsGamecapture,
+
+    /// `strace -f`
+    Strace,
+
+    /// `taskset -c <cpus>`
+    Taskset(String),
+
+    Custom(String)
+}
+
+impl LaunchWrapper {
+    /// Parse wrapper from its command
+    ///
+    /// Unknown commands are kept as custom wrappers
+    pub fn from_command(command: impl AsRef<str>) -> Self {
+        let command = command.as_ref().trim();
+
+        match command {
+            "gamemoderun"     => Self::Gamemode,
+            "prime-run"       => Self::PrimeRun,
+            "obs-gamecapture" => Self::ObsGamecapture,
+
+            "strace" | "strace -f" => Self::Strace,
+
+            _ => match command.strip_prefix("taskset -c ") {
+                Some(cpus) => Self::Taskset(cpus.trim().to_string()),
+                None => Self::Custom(command.to_string())
+            }
+        }
+    }
+
+    /// Get wrapper command
+    pub fn get_command(&self) -> String {
+        match self {
+            Self::Gamemode       => String::from("gamemoderun"),
+            Self::PrimeRun       => String::from("prime-run"),
+            Self::ObsGamecapture => String::from("obs-gamecapture"),
+            Self::Strace         => String::from("strace -f"),
+
+            Self::Taskset(cpus) => format!("taskset -c {cpus}"),
+            Self::Custom(command) => command.clone()
+        }
+    }
+}
+
+/// Check that the template runs the game
+///
+/// Empty template is valid because the default one is used instead.
+/// Templates without `%command%` must contain both `%wine%` and `%args%`
+pub fn is_valid_template(template: impl AsRef<str>) -> bool {
+    let template = template.as_ref().trim();
+
+    template.is_empty() || template.contains("%command%") || (template.contains("%wine%") && template.contains("%args%"))
+}
+
+/// Read wrappers from the json array
+///
+/// Wrappers which can't be parsed are skipped
+pub fn wrappers_from_json(value: &Json) -> Vec<LaunchWrapper> {
+    value.as_array()
+        .map(|wrappers| wrappers.iter()
+            .filter_map(|wrapper| serde_json::from_value(wrapper.clone()).ok())
+            .collect::<Vec<_>>()
+        )
+        .unwrap_or_default()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Launch {
+    /// Launch command template
+    ///
+    /// Supported placeholders:
+    ///
+    /// - `%command%` - whole launch command with wrappers
+    /// - `%wine%` - wine executable, or proton script with its `run` verb
+    /// - `%args%` - game executable with its launch options
+    pub template: String,
+
+    /// Wrappers commands in the order they wrap the game command
+    pub wrappers: Vec<LaunchWrapper>
+}
+
+impl Default for Launch {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            template: String::from(DEFAULT_TEMPLATE),
+            wrappers: Vec::new()
+        }
+    }
+}
+
+impl From<&Json> for Launch {
+    fn from(value: &Json) -> Self {
+        let default = Self::default();
+
+        Self {
+            template: value.get("template")
+                .and_then(Json::as_str)
+                .map(String::from)
+                .unwrap_or(default.template),
+
+            wrappers: value.get("wrappers")
+                .map(wrappers_from_json)
+                .unwrap_or(default.wrappers)
+        }
+    }
+}
+
+impl Launch {
+    /// Build launch command from the template
+    ///
+    /// `wrappers` are prepended to the wine command in `%command%`,
+    /// so templates without it can't be used when any wrapper is set
+    pub fn build_command(&self, wrappers: &str, wine: &str, args: &str) -> anyhow::Result<String> {
+        let template = match self.template.trim() {
+            "" => DEFAULT_TEMPLATE,
+            template => template
+        };
+
+        if !is_valid_template(template) {
+            anyhow::bail!("Launch template must contain %command%, or both %wine% and %args%: {template}");
+        }
+
+        let wrappers = wrappers.trim();
+
+        if !wrappers.is_empty() && !template.contains("%command%") {
+            anyhow::bail!("Launch template must contain %command% to use wrappers ({wrappers}): {template}");
+        }
+
+        let command = if wrappers.is_empty() {
+            format!("{wine} {args}")
+        } else {
+            format!("{wrappers} {wine} {args}")
+        };
+
+        // Substitute placeholders in a single pass so values
+        // containing placeholders' names are not expanded again
+        let mut result = String::with_capacity(template.len() + command.len());
+        let mut rest = template;
+
+        while let Some(start) = rest.find('%') {
+            result.push_str(&rest[..start]);
+            rest = &rest[start..];
+
+            let value = [("%command%", command.as_str()), ("%wine%", wine), ("%args%", args)]
+                .into_iter()
+                .find(|(placeholder, _)| rest.starts_with(placeholder));
+
+            match value {
+                Some((placeholder, value)) => {
+                    result.push_str(value);
+                    rest = &rest[placeholder.len()..];
+                }
+
+                None => {
+                    result.push('%');
+                    rest = &rest[1..];
+                }
+            }
+        }
+
+        result.push_str(rest);
+
+        Ok(result)
+    }
+}
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 use serde::{Serialize, Deserialize};
 use serde_json::Value as Json;
@@ -8,6 +9,7 @@ use crate::games::integrations::Game;
 
 pub mod wine;
 pub mod enhancements;
+pub mod launch;
 pub mod integrations;
 pub mod settings;
 
@@ -17,6 +19,7 @@ pub mod prelude {
     pub use super::settings::prelude::*;
 
     pub use super::integrations::Integrations;
+    pub use super::launch::{Launch, LaunchWrapper};
 
     pub use super::Games;
 }
@@ -27,6 +30,7 @@ use prelude::*;
 pub struct Games {
     pub wine: Wine,
     pub enhancements: Enhancements,
+    pub launch: Launch,
     pub environment: HashMap<String, String>,
     pub integrations: Integrations,
 
@@ -39,6 +43,7 @@ impl Default for Games {
         Self {
             wine: Wine::default(),
             enhancements: Enhancements::default(),
+            launch: Launch::default(),
             environment: HashMap::new(),
             integrations: Integrations::default(),
             settings: Json::Object(serde_json::Map::default())
@@ -60,6 +65,10 @@ impl From<&Json> for Games {
                 .map(Enhancements::from)
                 .unwrap_or(default.enhancements),
 
+            launch: value.get("launch")
+                .map(Launch::from)
+                .unwrap_or(default.launch),
+
             environment: value.get("environment")
                 .and_then(Json::as_object)
                 .map(|object| object.into_iter()
@@ -97,4 +106,53 @@ impl Games {
 
         Ok(settings)
     }
+
+    /// Get settings overrides of all the games editions
+    ///
+    /// Unlike `get_game_settings` this method doesn't need game drivers
+    /// and doesn't update the config file
+    pub fn get_all_overrides(&self) -> Vec<GameEditionOverrides> {
+        let Some(games) = self.settings.as_object() else {
+            return Vec::new();
+        };
+
+        games.values()
+            .filter_map(|settings| settings.get("overrides").and_then(Json::as_object))
+            .flat_map(|overrides| overrides.values().map(GameEditionOverrides::from))
+            .collect()
+    }
+
+    /// Get own wine prefixes of all the games editions
+    /// with their settings overrides
+    ///
+    /// Editions which use the shared prefix are not listed.
+    /// Like `get_all_overrides` this method doesn't need game drivers
+    pub fn get_editions_prefixes(&self) -> Vec<(PathBuf, GameEditionOverrides)> {
+        let Some(games) = self.settings.as_object() else {
+            return Vec::new();
+        };
+
+        let mut prefixes = Vec::new();
+
+        for settings in games.values() {
+            let Some(paths) = settings.get("paths").and_then(Json::as_object) else {
+                continue;
+            };
+
+            for (edition, paths) in paths {
+                let Some(prefix) = paths.get("prefix").and_then(Json::as_str) else {
+                    continue;
+                };
+
+                let overrides = settings.get("overrides")
+                    .and_then(|overrides| overrides.get(edition))
+                    .map(GameEditionOverrides::from)
+                    .unwrap_or_default();
+
+                prefixes.push((PathBuf::from(prefix), overrides));
+            }
+        }
+
+        prefixes
+    }
 }
@@ -0,0 +1,164 @@
+use std::collections::{HashMap, BTreeMap};
+
+use serde::{Serialize, Deserialize};
+use serde_json::Value as Json;
+
+use crate::config::Config;
+
+use crate::config::games::wine::prelude::*;
+use crate::config::games::wine::{dll_overrides, registry};
+use crate::config::games::enhancements::prelude::*;
+use crate::config::games::launch::{self, LaunchWrapper};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ComponentVersion {
+    pub build: String,
+    pub version: String
+}
+
+impl From<&Json> for ComponentVersion {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        let default = Self::default();
+
+        Self {
+            build: value.get("build")
+                .and_then(Json::as_str)
+                .map(String::from)
+                .unwrap_or(default.build),
+
+            version: value.get("version")
+                .and_then(Json::as_str)
+                .map(String::from)
+                .unwrap_or(default.version)
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+/// Game edition settings which replace global ones when set
+pub struct GameEditionOverrides {
+    pub wine: Option<ComponentVersion>,
+    pub dxvk: Option<ComponentVersion>,
+    pub sync: Option<WineSync>,
+    pub virtual_desktop: Option<VirtualDesktop>,
+    pub hud: Option<HUD>,
+    pub fsr: Option<FSR>,
+    pub gamescope: Option<Gamescope>,
+    pub gamemode: Option<bool>,
+
+    /// Launch command template used instead of the global one
+    pub launch_template: Option<String>,
+
+    /// Wrappers added after the global ones
+    pub wrappers: Vec<LaunchWrapper>,
+
+    /// Variables added to the global environment
+    pub environment: HashMap<String, String>,
+
+    /// DLL overrides added to the global ones
+    pub dll_overrides: BTreeMap<String, DllOverride>,
+
+    /// Registry values set after the global ones
+    pub registry: Vec<RegistryValue>
+}
+
+impl From<&Json> for GameEditionOverrides {
+    fn from(value: &Json) -> Self {
+        #[inline]
+        fn get<'a>(value: &'a Json, key: &str) -> Option<&'a Json> {
+            value.get(key).filter(|value| !value.is_null())
+        }
+
+        Self {
+            wine: get(value, "wine").map(ComponentVersion::from),
+            dxvk: get(value, "dxvk").map(ComponentVersion::from),
+            sync: get(value, "sync").map(WineSync::from),
+            virtual_desktop: get(value, "virtual_desktop").map(VirtualDesktop::from),
+            hud: get(value, "hud").map(HUD::from),
+            fsr: get(value, "fsr").map(FSR::from),
+            gamescope: get(value, "gamescope").map(Gamescope::from),
+            gamemode: get(value, "gamemode").and_then(Json::as_bool),
+
+            launch_template: get(value, "launch_template")
+                .and_then(Json::as_str)
+                .map(String::from),
+
+            wrappers: value.get("wrappers")
+                .map(launch::wrappers_from_json)
+                .unwrap_or_default(),
+
+            environment: value.get("environment")
+                .and_then(Json::as_object)
+                .map(|object| object.into_iter()
+                    .filter_map(|(key, value)| {
+                        value.as_str().map(|value| (key.to_string(), value.to_string()))
+                    })
+                    .collect::<HashMap<_, _>>()
+                )
+                .unwrap_or_default(),
+
+            dll_overrides: value.get("dll_overrides")
+                .map(dll_overrides::from_json)
+                .unwrap_or_default(),
+
+            registry: value.get("registry")
+                .map(registry::from_json)
+                .unwrap_or_default()
+        }
+    }
+}
+
+impl GameEditionOverrides {
+    /// Merge overridden values on top of the global config
+    pub fn apply(&self, config: &mut Config) {
+        if let Some(wine) = &self.wine {
+            config.components.wine.build = wine.build.clone();
+            config.components.wine.version = wine.version.clone();
+        }
+
+        if let Some(dxvk) = &self.dxvk {
+            config.components.dxvk.build = dxvk.build.clone();
+            config.components.dxvk.version = dxvk.version.clone();
+        }
+
+        if let Some(sync) = self.sync {
+            config.games.wine.sync = sync;
+        }
+
+        if let Some(virtual_desktop) = self.virtual_desktop {
+            config.games.wine.virtual_desktop = virtual_desktop;
+        }
+
+        if let Some(hud) = self.hud {
+            config.games.enhancements.hud = hud;
+        }
+
+        if let Some(fsr) = self.fsr {
+            config.games.enhancements.fsr = fsr;
+        }
+
+        if let Some(gamescope) = self.gamescope {
+            config.games.enhancements.gamescope = gamescope;
+        }
+
+        if let Some(gamemode) = self.gamemode {
+            config.games.enhancements.gamemode = gamemode;
+        }
+
+        if let Some(template) = &self.launch_template {
+            config.games.launch.template = template.clone();
+        }
+
+        for wrapper in &self.wrappers {
+            if !config.games.launch.wrappers.contains(wrapper) {
+                config.games.launch.wrappers.push(wrapper.clone());
+            }
+        }
+
+        config.games.environment.extend(self.environment.clone());
+
+        config.games.wine.dll_overrides.extend(self.dll_overrides.clone());
+        config.games.wine.registry.extend(self.registry.iter().cloned());
+    }
+}
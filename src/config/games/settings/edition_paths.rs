@@ -4,12 +4,16 @@ use serde::{Serialize, Deserialize};
 use serde_json::Value as Json;
 
 use crate::LAUNCHER_FOLDER;
+use crate::config::Config;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GameEditionPaths {
     pub game: PathBuf,
     pub addons: PathBuf,
-    pub deployment: PathBuf
+    pub deployment: PathBuf,
+
+    /// Wine prefix of the edition. Shared prefix is used if not set
+    pub prefix: Option<PathBuf>
 }
 
 impl GameEditionPaths {
@@ -31,10 +35,22 @@ impl GameEditionPaths {
                 .join("games")
                 .join(game_name.as_ref())
                 .join(edition_name.as_ref())
-                .join("deployment")
+                .join("deployment"),
+
+            prefix: None
         })
     }
 
+    #[inline]
+    /// Get default path of the edition's own wine prefix
+    pub fn default_prefix(game_name: impl AsRef<str>, edition_name: impl AsRef<str>) -> PathBuf {
+        LAUNCHER_FOLDER
+            .join("games")
+            .join(game_name.as_ref())
+            .join(edition_name.as_ref())
+            .join("prefix")
+    }
+
     pub fn from_json(game_name: impl AsRef<str>, edition_name: impl AsRef<str>, value: &Json) -> anyhow::Result<Self> {
         let default = Self::default(game_name, edition_name)?;
 
@@ -52,7 +68,17 @@ impl GameEditionPaths {
             deployment: value.get("deployment")
                 .and_then(Json::as_str)
                 .map(PathBuf::from)
-                .unwrap_or(default.deployment)
+                .unwrap_or(default.deployment),
+
+            prefix: value.get("prefix")
+                .and_then(Json::as_str)
+                .map(PathBuf::from)
         })
     }
+
+    /// Get wine prefix path used by the edition
+    pub fn get_prefix(&self, config: &Config) -> PathBuf {
+        self.prefix.clone()
+            .unwrap_or_else(|| config.components.wine.prefix.path.clone())
+    }
 }
@@ -5,10 +5,16 @@ use serde_json::Value as Json;
 
 pub mod edition_addons;
 pub mod edition_paths;
+pub mod edition_overrides;
 
 pub mod prelude {
     pub use super::edition_addons::GameEditionAddon;
     pub use super::edition_paths::GameEditionPaths;
+    pub use super::edition_overrides::{
+        GameEditionOverrides,
+        ComponentVersion
+    };
+
     pub use super::GameSettings;
 }
 
@@ -17,7 +23,8 @@ use prelude::*;
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GameSettings {
     pub paths: HashMap<String, GameEditionPaths>,
-    pub addons: HashMap<String, Vec<GameEditionAddon>>
+    pub addons: HashMap<String, Vec<GameEditionAddon>>,
+    pub overrides: HashMap<String, GameEditionOverrides>
 }
 
 impl GameSettings {
@@ -34,9 +41,15 @@ impl GameSettings {
                 }).collect(),
 
             addons: edition_names
+                .clone()
                 .into_iter()
                 .map(|edition| (edition.as_ref().to_string(), vec![]))
                 .collect::<HashMap<_, _>>(),
+
+            overrides: edition_names
+                .into_iter()
+                .map(|edition| (edition.as_ref().to_string(), GameEditionOverrides::default()))
+                .collect::<HashMap<_, _>>()
         })
     }
 
@@ -63,9 +76,16 @@ impl GameSettings {
             }
         }
 
+        if let Some(values) = value.get("overrides").and_then(Json::as_object) {
+            for (edition, overrides) in values {
+                default.overrides.insert(edition.clone(), GameEditionOverrides::from(overrides));
+            }
+        }
+
         Ok(Self {
             paths: default.paths,
-            addons: default.addons
+            addons: default.addons,
+            overrides: default.overrides
         })
     }
 }
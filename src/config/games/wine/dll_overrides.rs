@@ -0,0 +1,93 @@
+use std::collections::BTreeMap;
+
+use serde::{Serialize, Deserialize};
+use serde_json::Value as Json;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DllOverride {
+    Native,
+    Builtin,
+    NativeBuiltin,
+    BuiltinNative,
+    Disabled
+}
+
+impl Default for DllOverride {
+    #[inline]
+    fn default() -> Self {
+        Self::NativeBuiltin
+    }
+}
+
+impl From<&Json> for DllOverride {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        serde_json::from_value(value.clone()).unwrap_or_default()
+    }
+}
+
+impl DllOverride {
+    #[inline]
+    pub fn list() -> &'static [Self] {
+        &[
+            Self::Native,
+            Self::Builtin,
+            Self::NativeBuiltin,
+            Self::BuiltinNative,
+            Self::Disabled
+        ]
+    }
+
+    /// Parse override in the `WINEDLLOVERRIDES` format, e.g. `n,b`
+    pub fn from_wine_str(value: impl AsRef<str>) -> Option<Self> {
+        match value.as_ref().trim() {
+            "n"   => Some(Self::Native),
+            "b"   => Some(Self::Builtin),
+            "n,b" => Some(Self::NativeBuiltin),
+            "b,n" => Some(Self::BuiltinNative),
+            ""    => Some(Self::Disabled),
+
+            _ => None
+        }
+    }
+
+    #[inline]
+    /// Get override in the `WINEDLLOVERRIDES` format
+    pub fn to_wine_str(&self) -> &'static str {
+        match self {
+            Self::Native        => "n",
+            Self::Builtin       => "b",
+            Self::NativeBuiltin => "n,b",
+            Self::BuiltinNative => "b,n",
+            Self::Disabled      => ""
+        }
+    }
+}
+
+/// Get `WINEDLLOVERRIDES` entries of the given overrides, e.g. `d3d11=n,b`
+///
+/// Entries are sorted by the DLL names so the variable doesn't change between launches
+pub fn get_wine_overrides(overrides: &BTreeMap<String, DllOverride>) -> Vec<String> {
+    overrides.iter()
+        .map(|(dll, value)| format!("{dll}={}", value.to_wine_str()))
+        .collect()
+}
+
+/// Read DLL overrides from the json object
+///
+/// Values can be either override names or `WINEDLLOVERRIDES` strings
+pub fn from_json(value: &Json) -> BTreeMap<String, DllOverride> {
+    value.as_object()
+        .map(|object| object.into_iter()
+            .filter_map(|(dll, value)| {
+                let value = value.as_str()?;
+
+                let value = DllOverride::from_wine_str(value)
+                    .or_else(|| serde_json::from_value(Json::from(value)).ok())?;
+
+                Some((dll.to_string(), value))
+            })
+            .collect::<BTreeMap<_, _>>()
+        )
+        .unwrap_or_default()
+}
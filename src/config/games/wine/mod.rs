@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use serde::{Serialize, Deserialize};
 use serde_json::Value as Json;
 
@@ -5,12 +7,16 @@ pub mod wine_sync;
 pub mod wine_lang;
 pub mod virtual_desktop;
 pub mod shared_libraries;
+pub mod dll_overrides;
+pub mod registry;
 
 pub mod prelude {
     pub use super::wine_sync::WineSync;
     pub use super::wine_lang::WineLang;
     pub use super::virtual_desktop::VirtualDesktop;
     pub use super::shared_libraries::SharedLibraries;
+    pub use super::dll_overrides::DllOverride;
+    pub use super::registry::{RegistryValue, RegistryValueType};
     pub use super::Wine;
 }
 
@@ -22,7 +28,13 @@ pub struct Wine {
     pub language: WineLang,
     pub borderless: bool,
     pub virtual_desktop: VirtualDesktop,
-    pub shared_libraries: SharedLibraries
+    pub shared_libraries: SharedLibraries,
+
+    /// DLL overrides added to the `WINEDLLOVERRIDES` variable
+    pub dll_overrides: BTreeMap<String, DllOverride>,
+
+    /// Values set in the prefix registry before the game launch
+    pub registry: Vec<RegistryValue>
 }
 
 impl Default for Wine {
@@ -33,7 +45,9 @@ impl Default for Wine {
             language: WineLang::default(),
             borderless: false,
             virtual_desktop: VirtualDesktop::default(),
-            shared_libraries: SharedLibraries::default()
+            shared_libraries: SharedLibraries::default(),
+            dll_overrides: BTreeMap::new(),
+            registry: Vec::new()
         }
     }
 }
@@ -62,7 +76,15 @@ impl From<&Json> for Wine {
 
             shared_libraries: value.get("shared_libraries")
                 .map(SharedLibraries::from)
-                .unwrap_or(default.shared_libraries)
+                .unwrap_or(default.shared_libraries),
+
+            dll_overrides: value.get("dll_overrides")
+                .map(dll_overrides::from_json)
+                .unwrap_or(default.dll_overrides),
+
+            registry: value.get("registry")
+                .map(registry::from_json)
+                .unwrap_or(default.registry)
         }
     }
 }
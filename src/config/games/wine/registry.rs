@@ -0,0 +1,139 @@
+use serde::{Serialize, Deserialize};
+use serde_json::Value as Json;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RegistryValueType {
+    /// `REG_SZ` value
+    String,
+
+    /// `REG_DWORD` value
+    Dword
+}
+
+impl Default for RegistryValueType {
+    #[inline]
+    fn default() -> Self {
+        Self::String
+    }
+}
+
+impl From<&Json> for RegistryValueType {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        serde_json::from_value(value.clone()).unwrap_or_default()
+    }
+}
+
+impl RegistryValueType {
+    #[inline]
+    pub fn list() -> &'static [Self] {
+        &[Self::String, Self::Dword]
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// Value set in the wine prefix registry before the game launch
+pub struct RegistryValue {
+    /// Full registry key path, e.g. `HKEY_CURRENT_USER\Software\Wine\Direct3D`
+    pub key: String,
+
+    pub name: String,
+
+    #[serde(rename = "type")]
+    pub kind: RegistryValueType,
+
+    /// Value data. Dword values can be either decimal or `0x` prefixed hex numbers
+    pub value: String
+}
+
+impl From<&Json> for RegistryValue {
+    fn from(value: &Json) -> Self {
+        let default = Self::default();
+
+        Self {
+            key: value.get("key")
+                .and_then(Json::as_str)
+                .map(String::from)
+                .unwrap_or(default.key),
+
+            name: value.get("name")
+                .and_then(Json::as_str)
+                .map(String::from)
+                .unwrap_or(default.name),
+
+            kind: value.get("type")
+                .map(RegistryValueType::from)
+                .unwrap_or(default.kind),
+
+            value: value.get("value")
+                .and_then(Json::as_str)
+                .map(String::from)
+                .unwrap_or(default.value)
+        }
+    }
+}
+
+impl RegistryValue {
+    /// Get value line in the `.reg` file format, e.g. `"name"=dword:00000001`
+    pub fn to_reg_line(&self) -> anyhow::Result<String> {
+        let name = escape(&self.name);
+
+        match self.kind {
+            RegistryValueType::String => Ok(format!("\"{name}\"=\"{}\"", escape(&self.value))),
+
+            RegistryValueType::Dword => {
+                let value = self.value.trim();
+
+                let value = match value.strip_prefix("0x") {
+                    Some(hex) => u32::from_str_radix(hex, 16),
+                    None => value.parse::<u32>()
+                };
+
+                match value {
+                    Ok(value) => Ok(format!("\"{name}\"=dword:{value:08x}")),
+                    Err(err) => anyhow::bail!("Wrong dword value of the registry value {}: {err}", self.name)
+                }
+            }
+        }
+    }
+}
+
+#[inline]
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Read registry values from the json array
+pub fn from_json(value: &Json) -> Vec<RegistryValue> {
+    value.as_array()
+        .map(|values| values.iter()
+            .map(RegistryValue::from)
+            .collect::<Vec<_>>()
+        )
+        .unwrap_or_default()
+}
+
+/// Build `.reg` file which sets the given values
+///
+/// Values of the same key are grouped together in their original order
+pub fn to_reg_file(values: &[RegistryValue]) -> anyhow::Result<String> {
+    let mut keys: Vec<(&str, Vec<String>)> = Vec::new();
+
+    for value in values {
+        let line = value.to_reg_line()?;
+
+        match keys.iter_mut().find(|(key, _)| *key == value.key) {
+            Some((_, lines)) => lines.push(line),
+            None => keys.push((&value.key, vec![line]))
+        }
+    }
+
+    let mut file = String::from("Windows Registry Editor Version 5.00\n");
+
+    for (key, lines) in keys {
+        file += &format!("\n[{key}]\n{}\n", lines.join("\n"));
+    }
+
+    Ok(file)
+}
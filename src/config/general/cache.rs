@@ -0,0 +1,51 @@
+use std::path::PathBuf;
+
+use serde::{Serialize, Deserialize};
+use serde_json::Value as Json;
+
+use crate::LAUNCHER_FOLDER;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cache {
+    /// Keep downloaded archives to reuse them later
+    pub enabled: bool,
+
+    /// Path to the cache folder
+    pub path: PathBuf,
+
+    /// Maximal size of the cache in MiB
+    pub max_size: u64
+}
+
+impl Default for Cache {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            path: LAUNCHER_FOLDER.join("cache"),
+            max_size: 20480
+        }
+    }
+}
+
+impl From<&Json> for Cache {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        let default = Self::default();
+
+        Self {
+            enabled: value.get("enabled")
+                .and_then(Json::as_bool)
+                .unwrap_or(default.enabled),
+
+            path: value.get("path")
+                .and_then(Json::as_str)
+                .map(PathBuf::from)
+                .unwrap_or(default.path),
+
+            max_size: value.get("max_size")
+                .and_then(Json::as_u64)
+                .unwrap_or(default.max_size)
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use serde::{Serialize, Deserialize};
+use serde_json::Value as Json;
+
+use chrono::NaiveTime;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Downloads {
+    /// Amount of retries after the first failed download attempt
+    pub retries: u64,
+
+    /// Delay before the first retry, in milliseconds.
+    /// Doubled after each failed attempt
+    pub retry_delay: u64,
+
+    /// Stop the download attempt if no data was received
+    /// during this amount of seconds
+    pub stall_timeout: u64,
+
+    /// Maximal download speed of all the downloads together, in KiB/s.
+    /// 0 means no limit
+    pub speed_limit: u64,
+
+    /// Time window in which downloads are allowed
+    pub schedule: DownloadsSchedule,
+
+    /// Pause downloads while any game is running
+    pub pause_while_playing: bool
+}
+
+impl Default for Downloads {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            retries: 5,
+            retry_delay: 1000,
+            stall_timeout: 30,
+            speed_limit: 0,
+            schedule: DownloadsSchedule::default(),
+            pause_while_playing: false
+        }
+    }
+}
+
+impl From<&Json> for Downloads {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        let default = Self::default();
+
+        Self {
+            retries: value.get("retries")
+                .and_then(Json::as_u64)
+                .unwrap_or(default.retries),
+
+            retry_delay: value.get("retry_delay")
+                .and_then(Json::as_u64)
+                .unwrap_or(default.retry_delay),
+
+            stall_timeout: value.get("stall_timeout")
+                .and_then(Json::as_u64)
+                .unwrap_or(default.stall_timeout),
+
+            speed_limit: value.get("speed_limit")
+                .and_then(Json::as_u64)
+                .unwrap_or(default.speed_limit),
+
+            schedule: value.get("schedule")
+                .map(DownloadsSchedule::from)
+                .unwrap_or(default.schedule),
+
+            pause_while_playing: value.get("pause_while_playing")
+                .and_then(Json::as_bool)
+                .unwrap_or(default.pause_while_playing)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DownloadsSchedule {
+    pub enabled: bool,
+
+    /// Local time from which downloads are allowed, in `HH:MM` format
+    pub from: String,
+
+    /// Local time until which downloads are allowed, in `HH:MM` format.
+    /// Can be lower than `from` to allow downloads at night
+    pub to: String
+}
+
+impl Default for DownloadsSchedule {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            from: String::from("01:00"),
+            to: String::from("07:00")
+        }
+    }
+}
+
+impl From<&Json> for DownloadsSchedule {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        let default = Self::default();
+
+        Self {
+            enabled: value.get("enabled")
+                .and_then(Json::as_bool)
+                .unwrap_or(default.enabled),
+
+            from: value.get("from")
+                .and_then(Json::as_str)
+                .map(String::from)
+                .unwrap_or(default.from),
+
+            to: value.get("to")
+                .and_then(Json::as_str)
+                .map(String::from)
+                .unwrap_or(default.to)
+        }
+    }
+}
+
+impl DownloadsSchedule {
+    /// Get allowed downloading time window
+    /// 
+    /// Return `None` if schedule is disabled or has wrong format
+    pub fn get_window(&self) -> Option<(NaiveTime, NaiveTime)> {
+        if !self.enabled {
+            return None;
+        }
+
+        let from = NaiveTime::parse_from_str(&self.from, "%H:%M").ok()?;
+        let to = NaiveTime::parse_from_str(&self.to, "%H:%M").ok()?;
+
+        Some((from, to))
+    }
+}
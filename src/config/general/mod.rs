@@ -5,10 +5,14 @@ use crate::i18n;
 
 pub mod transitions;
 pub mod threads;
+pub mod downloads;
+pub mod cache;
 
 pub mod prelude {
     pub use super::transitions::Transitions;
     pub use super::threads::Threads;
+    pub use super::downloads::{Downloads, DownloadsSchedule};
+    pub use super::cache::Cache;
     pub use super::General;
 }
 
@@ -18,6 +22,8 @@ use prelude::*;
 pub struct General {
     pub transitions: Transitions,
     pub threads: Threads,
+    pub downloads: Downloads,
+    pub cache: Cache,
     pub language: String,
     pub verify_games: bool
 }
@@ -28,6 +34,8 @@ impl Default for General {
         Self {
             transitions: Transitions::default(),
             threads: Threads::default(),
+            downloads: Downloads::default(),
+            cache: Cache::default(),
             language: i18n::format_language(&i18n::get_default_language()),
             verify_games: true
         }
@@ -48,6 +56,14 @@ impl From<&Json> for General {
                 .map(Threads::from)
                 .unwrap_or(default.threads),
 
+            downloads: value.get("downloads")
+                .map(Downloads::from)
+                .unwrap_or(default.downloads),
+
+            cache: value.get("cache")
+                .map(Cache::from)
+                .unwrap_or(default.cache),
+
             language: value.get("language")
                 .and_then(Json::as_str)
                 .map(String::from)
@@ -1,6 +1,9 @@
 use mlua::prelude::*;
 
+use crate::network::download::get_file_name;
+
 use super::IntegrationStandard;
+use super::integrity::FileHash;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Diff {
@@ -8,7 +11,8 @@ pub struct Diff {
     pub latest_version: String,
     pub edition: String,
     pub status: DiffStatus,
-    pub diff: Option<DiffInfo>
+    pub diff: Option<DiffInfo>,
+    pub pre_download: Option<PreDownload>
 }
 
 impl Diff {
@@ -26,6 +30,13 @@ impl Diff {
                         } else {
                             None
                         }
+                    },
+                    pre_download: {
+                        if table.contains_key::<_>("pre_download")? {
+                            Some(PreDownload::from_table(table.get::<_, LuaTable>("pre_download")?, standard)?)
+                        } else {
+                            None
+                        }
                     }
                 })
             }
@@ -46,6 +57,43 @@ impl Diff {
                     table.set("diff", diff.to_table(lua, standard)?)?;
                 }
 
+                if let Some(pre_download) = &self.pre_download {
+                    table.set("pre_download", pre_download.to_table(lua, standard)?)?;
+                }
+
+                Ok(table)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Diff of the upcoming version which can be downloaded before its release
+pub struct PreDownload {
+    pub version: String,
+    pub diff: DiffInfo
+}
+
+impl PreDownload {
+    pub fn from_table(table: LuaTable, standard: IntegrationStandard) -> anyhow::Result<Self> {
+        match standard {
+            IntegrationStandard::V1 => {
+                Ok(Self {
+                    version: table.get::<_, String>("version")?,
+                    diff: DiffInfo::from_table(table.get::<_, LuaTable>("diff")?, standard)?
+                })
+            }
+        }
+    }
+
+    pub fn to_table<'a>(&self, lua: &'a Lua, standard: IntegrationStandard) -> anyhow::Result<LuaTable<'a>> {
+        match standard {
+            IntegrationStandard::V1 => {
+                let table = lua.create_table()?;
+
+                table.set("version", self.version.as_str())?;
+                table.set("diff", self.diff.to_table(lua, standard)?)?;
+
                 Ok(table)
             }
         }
@@ -91,11 +139,24 @@ impl DiffStatus {
 pub enum DiffInfo {
     Archive {
         size: u64,
-        uri: String
+        unpacked_size: Option<u64>,
+        extract: ExtractMode,
+        uri: String,
+        mirrors: Vec<String>,
+        hash: Option<FileHash>
     },
     Segments {
         size: u64,
-        segments: Vec<String>
+        unpacked_size: Option<u64>,
+        extract: ExtractMode,
+        segments: Vec<DiffSegment>
+    },
+    Patch {
+        size: u64,
+        unpacked_size: Option<u64>,
+        uri: String,
+        mirrors: Vec<String>,
+        hash: Option<FileHash>
     },
     Files {
         size: u64,
@@ -108,19 +169,40 @@ impl DiffInfo {
         match standard {
             IntegrationStandard::V1 => {
                 let size = table.get::<_, u64>("size")?;
+                let unpacked_size = table.get::<_, Option<u64>>("unpacked_size")?;
+
+                let extract = match table.get::<_, Option<String>>("extract")? {
+                    Some(mode) => ExtractMode::from_str(mode, standard)?,
+                    None => ExtractMode::default()
+                };
 
                 match table.get::<_, String>("type")?.as_str() {
                     "archive" => Ok(Self::Archive {
                         size,
-                        uri: table.get::<_, String>("uri")?
+                        unpacked_size,
+                        extract,
+                        uri: table.get::<_, String>("uri")?,
+                        mirrors: get_mirrors(&table)?,
+                        hash: get_hash(&table, standard)?
                     }),
 
                     "segments" => Ok(Self::Segments {
                         size,
+                        unpacked_size,
+                        extract,
                         segments: table.get::<_, LuaTable>("segments")?
-                            .sequence_values::<String>()
+                            .sequence_values::<LuaValue>()
                             .flatten()
-                            .collect()
+                            .map(|segment| DiffSegment::from_value(segment, standard))
+                            .collect::<Result<Vec<_>, _>>()?
+                    }),
+
+                    "patch" => Ok(Self::Patch {
+                        size,
+                        unpacked_size,
+                        uri: table.get::<_, String>("uri")?,
+                        mirrors: get_mirrors(&table)?,
+                        hash: get_hash(&table, standard)?
                     }),
 
                     "files" => Ok(Self::Files {
@@ -144,24 +226,51 @@ impl DiffInfo {
                 let table = lua.create_table()?;
 
                 match self {
-                    Self::Archive { size, uri } => {
+                    Self::Archive { size, unpacked_size, extract, uri, mirrors, hash } => {
                         table.set("type", "archive")?;
                         table.set("size", *size)?;
+                        table.set("unpacked_size", *unpacked_size)?;
+                        table.set("extract", extract.to_str(standard))?;
                         table.set("uri", uri.as_str())?;
+
+                        if !mirrors.is_empty() {
+                            table.set("mirrors", mirrors.clone())?;
+                        }
+
+                        if let Some(hash) = hash {
+                            table.set("hash", hash.to_table(lua, standard)?)?;
+                        }
                     }
 
-                    Self::Segments { size, segments } => {
+                    Self::Segments { size, unpacked_size, extract, segments } => {
                         let segments_lua = lua.create_table()?;
 
                         for segment in segments {
-                            segments_lua.push(segment.as_str())?;
+                            segments_lua.push(segment.to_value(lua, standard)?)?;
                         }
 
                         table.set("type", "segments")?;
                         table.set("size", *size)?;
+                        table.set("unpacked_size", *unpacked_size)?;
+                        table.set("extract", extract.to_str(standard))?;
                         table.set("segments", segments_lua)?;
                     }
 
+                    Self::Patch { size, unpacked_size, uri, mirrors, hash } => {
+                        table.set("type", "patch")?;
+                        table.set("size", *size)?;
+                        table.set("unpacked_size", *unpacked_size)?;
+                        table.set("uri", uri.as_str())?;
+
+                        if !mirrors.is_empty() {
+                            table.set("mirrors", mirrors.clone())?;
+                        }
+
+                        if let Some(hash) = hash {
+                            table.set("hash", hash.to_table(lua, standard)?)?;
+                        }
+                    }
+
                     Self::Files { size, files } => {
                         let files_lua = lua.create_table()?;
 
@@ -179,13 +288,214 @@ impl DiffInfo {
             }
         }
     }
+
+    /// Get size of the downloaded files
+    pub fn get_size(&self) -> u64 {
+        match self {
+            Self::Archive { size, .. } |
+            Self::Segments { size, .. } |
+            Self::Patch { size, .. } |
+            Self::Files { size, .. } => *size
+        }
+    }
+
+    /// Get size of the files after unpacking
+    /// 
+    /// Downloaded size is used if the unpacked size is unknown
+    pub fn get_unpacked_size(&self) -> u64 {
+        match self {
+            Self::Archive { size, unpacked_size, .. } |
+            Self::Segments { size, unpacked_size, .. } |
+            Self::Patch { size, unpacked_size, .. } => unpacked_size.unwrap_or(*size),
+
+            Self::Files { size, .. } => *size
+        }
+    }
+
+    /// Get tar flags needed to extract the archive while downloading it
+    /// 
+    /// Return `None` if the archive should be downloaded before extracting:
+    /// when the stream mode is not requested, the archive is not a tar archive,
+    /// or some of its hashes can be verified only after downloading
+    pub fn get_stream_tar_flags(&self) -> Option<&'static [&'static str]> {
+        let (uri, hashes) = match self {
+            Self::Archive { extract: ExtractMode::Stream, uri, hash, .. } => {
+                (uri, vec![hash.as_ref()])
+            }
+
+            Self::Segments { extract: ExtractMode::Stream, segments, .. } => {
+                (&segments.first()?.uri, segments.iter().map(|segment| segment.hash.as_ref()).collect())
+            }
+
+            _ => return None
+        };
+
+        let file_name = get_file_name(uri);
+
+        // Strip segment number like in `game.tar.gz.001`
+        let name = match file_name.rsplit_once('.') {
+            Some((name, number)) if number.chars().all(|char| char.is_ascii_digit()) => name,
+            _ => file_name.as_str()
+        };
+
+        let flags: &[&str] = if name.ends_with(".tar") {
+            &[]
+        } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            &["-z"]
+        } else if name.ends_with(".tar.xz") || name.ends_with(".txz") {
+            &["-J"]
+        } else if name.ends_with(".tar.zst") {
+            &["--zstd"]
+        } else if name.ends_with(".tar.bz2") || name.ends_with(".tbz2") {
+            &["-j"]
+        } else {
+            return None;
+        };
+
+        // Custom hashes are calculated by the integration from the whole file
+        if !hashes.into_iter().all(|hash| hash.map(|hash| hash.hash.hasher().is_some()).unwrap_or(true)) {
+            return None;
+        }
+
+        Some(flags)
+    }
+
+    /// Get approximate maximal size of the files
+    /// stored in the transition folder during downloading
+    pub fn get_peak_size(&self) -> u64 {
+        let size = self.get_size();
+        let unpacked_size = self.get_unpacked_size();
+
+        match self {
+            // Archive is extracted while downloading
+            Self::Archive { .. } |
+            Self::Segments { .. } if self.get_stream_tar_flags().is_some() => unpacked_size,
+
+            Self::Segments { extract: ExtractMode::Sequential, segments, .. } => {
+                unpacked_size + size / segments.len().max(1) as u64
+            }
+
+            Self::Archive { .. } |
+            Self::Segments { .. } |
+            Self::Patch { .. } => size + unpacked_size,
+
+            Self::Files { .. } => size
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExtractMode {
+    /// Download all the files and then extract them
+    #[default]
+    Full,
+
+    /// Extract files while downloading them.
+    /// Only supported for tar archives
+    Stream,
+
+    /// Download and extract segments one by one,
+    /// deleting each segment after extracting
+    Sequential
+}
+
+impl ExtractMode {
+    pub fn from_str(value: impl AsRef<str>, standard: IntegrationStandard) -> anyhow::Result<Self> {
+        match standard {
+            IntegrationStandard::V1 => {
+                match value.as_ref() {
+                    "full"       => Ok(Self::Full),
+                    "stream"     => Ok(Self::Stream),
+                    "sequential" => Ok(Self::Sequential),
+
+                    _ => anyhow::bail!("Wrong v1 extract mode: '{}'", value.as_ref())
+                }
+            }
+        }
+    }
+
+    pub fn to_str(&self, standard: IntegrationStandard) -> &str {
+        match standard {
+            IntegrationStandard::V1 => {
+                match self {
+                    Self::Full       => "full",
+                    Self::Stream     => "stream",
+                    Self::Sequential => "sequential"
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DiffSegment {
+    pub uri: String,
+    pub mirrors: Vec<String>,
+    pub hash: Option<FileHash>
+}
+
+impl DiffSegment {
+    /// Segment can be either a plain URI string or a table with `uri`, `mirrors` and `hash` fields
+    pub fn from_value(value: LuaValue, standard: IntegrationStandard) -> anyhow::Result<Self> {
+        match standard {
+            IntegrationStandard::V1 => {
+                match value {
+                    LuaValue::String(uri) => Ok(Self {
+                        uri: uri.to_str()?.to_string(),
+                        mirrors: vec![],
+                        hash: None
+                    }),
+
+                    LuaValue::Table(table) => Ok(Self {
+                        uri: table.get::<_, String>("uri")?,
+                        mirrors: get_mirrors(&table)?,
+                        hash: get_hash(&table, standard)?
+                    }),
+
+                    value => anyhow::bail!("Wrong v1 diff segment: '{value:?}'")
+                }
+            }
+        }
+    }
+
+    pub fn to_value<'a>(&self, lua: &'a Lua, standard: IntegrationStandard) -> anyhow::Result<LuaValue<'a>> {
+        match standard {
+            IntegrationStandard::V1 => {
+                if self.hash.is_none() && self.mirrors.is_empty() {
+                    return Ok(LuaValue::String(lua.create_string(&self.uri)?));
+                }
+
+                let table = lua.create_table()?;
+
+                table.set("uri", self.uri.as_str())?;
+
+                if !self.mirrors.is_empty() {
+                    table.set("mirrors", self.mirrors.clone())?;
+                }
+
+                if let Some(hash) = &self.hash {
+                    table.set("hash", hash.to_table(lua, standard)?)?;
+                }
+
+                Ok(LuaValue::Table(table))
+            }
+        }
+    }
+
+    #[inline]
+    /// Get segment URI followed by its mirrors
+    pub fn get_uris(&self) -> Vec<&str> {
+        get_uris(&self.uri, &self.mirrors)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DiffFileDownload {
     pub path: String,
     pub uri: String,
-    pub size: u64
+    pub mirrors: Vec<String>,
+    pub size: u64,
+    pub hash: Option<FileHash>
 }
 
 impl DiffFileDownload {
@@ -195,7 +505,9 @@ impl DiffFileDownload {
                 Ok(Self {
                     path: table.get::<_, String>("path")?,
                     uri: table.get::<_, String>("uri")?,
-                    size: table.get::<_, u64>("size")?
+                    mirrors: get_mirrors(&table)?,
+                    size: table.get::<_, u64>("size")?,
+                    hash: get_hash(&table, standard)?
                 })
             }
         }
@@ -210,8 +522,49 @@ impl DiffFileDownload {
                 table.set("uri", self.uri.as_str())?;
                 table.set("size", self.size)?;
 
+                if !self.mirrors.is_empty() {
+                    table.set("mirrors", self.mirrors.clone())?;
+                }
+
+                if let Some(hash) = &self.hash {
+                    table.set("hash", hash.to_table(lua, standard)?)?;
+                }
+
                 Ok(table)
             }
         }
     }
+
+    #[inline]
+    /// Get file URI followed by its mirrors
+    pub fn get_uris(&self) -> Vec<&str> {
+        get_uris(&self.uri, &self.mirrors)
+    }
+}
+
+/// Read optional `hash` field of the table
+fn get_hash(table: &LuaTable, standard: IntegrationStandard) -> anyhow::Result<Option<FileHash>> {
+    if table.contains_key("hash")? {
+        Ok(Some(FileHash::from_table(table.get::<_, LuaTable>("hash")?, standard)?))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Read optional `mirrors` field of the table
+fn get_mirrors(table: &LuaTable) -> anyhow::Result<Vec<String>> {
+    if table.contains_key("mirrors")? {
+        Ok(table.get::<_, LuaTable>("mirrors")?
+            .sequence_values::<String>()
+            .collect::<Result<Vec<_>, _>>()?)
+    } else {
+        Ok(vec![])
+    }
+}
+
+/// Get list of the main URI followed by its mirrors
+fn get_uris<'a>(uri: &'a str, mirrors: &'a [String]) -> Vec<&'a str> {
+    std::iter::once(uri)
+        .chain(mirrors.iter().map(String::as_str))
+        .collect()
 }
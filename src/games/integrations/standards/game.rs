@@ -1,7 +1,13 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, BTreeMap};
 
 use mlua::prelude::*;
 
+use crate::config::games::wine::prelude::{
+    DllOverride,
+    RegistryValue,
+    RegistryValueType
+};
+
 use super::IntegrationStandard;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -117,13 +123,71 @@ impl StatusSeverity {
 pub struct LaunchOptions {
     pub executable: String,
     pub options: Vec<String>,
-    pub environment: HashMap<String, String>
+    pub environment: HashMap<String, String>,
+
+    /// DLL overrides requested by the integration
+    pub dll_overrides: BTreeMap<String, DllOverride>,
+
+    /// Registry values requested by the integration
+    pub registry: Vec<RegistryValue>
 }
 
 impl LaunchOptions {
     pub fn from_table(table: LuaTable, standard: IntegrationStandard) -> anyhow::Result<Self> {
         match standard {
             IntegrationStandard::V1 => {
+                // Collect all malformed entries to report them at once
+                let mut errors = Vec::new();
+
+                let mut dll_overrides = BTreeMap::new();
+
+                if let Some(overrides) = table.get::<_, Option<LuaTable>>("dll_overrides")? {
+                    for pair in overrides.pairs::<String, String>() {
+                        match pair {
+                            Ok((dll, value)) => match DllOverride::from_wine_str(&value) {
+                                Some(value) => {
+                                    dll_overrides.insert(dll, value);
+                                }
+
+                                None => errors.push(format!("wrong dll override of {dll}: '{value}'"))
+                            }
+
+                            Err(err) => errors.push(format!("wrong dll override entry: {err}"))
+                        }
+                    }
+                }
+
+                let mut registry = Vec::new();
+
+                if let Some(values) = table.get::<_, Option<LuaTable>>("registry")? {
+                    for (i, value) in values.sequence_values::<LuaTable>().enumerate() {
+                        let value = value.map_err(anyhow::Error::from).and_then(|value| {
+                            Ok(RegistryValue {
+                                key: value.get::<_, String>("key")?,
+                                name: value.get::<_, String>("name")?,
+
+                                kind: match value.get::<_, String>("type")?.as_str() {
+                                    "string" => RegistryValueType::String,
+                                    "dword"  => RegistryValueType::Dword,
+
+                                    kind => anyhow::bail!("wrong type '{kind}'")
+                                },
+
+                                value: value.get::<_, String>("value")?
+                            })
+                        });
+
+                        match value {
+                            Ok(value) => registry.push(value),
+                            Err(err) => errors.push(format!("wrong registry value #{}: {err}", i + 1))
+                        }
+                    }
+                }
+
+                if !errors.is_empty() {
+                    anyhow::bail!("Malformed v1 launch options: {}", errors.join("; "));
+                }
+
                 Ok(Self {
                     executable: table.get::<_, String>("executable")?,
 
@@ -135,7 +199,10 @@ impl LaunchOptions {
                     environment: table.get::<_, LuaTable>("environment")?
                         .pairs::<String, String>()
                         .flatten()
-                        .collect()
+                        .collect(),
+
+                    dll_overrides,
+                    registry
                 })
             }
         }
@@ -150,7 +217,7 @@ impl LaunchOptions {
                 let environment = lua.create_table()?;
 
                 for option in &self.options {
-                    environment.push(option.as_str())?;
+                    options.push(option.as_str())?;
                 }
 
                 for (key, value) in &self.environment {
@@ -161,6 +228,37 @@ impl LaunchOptions {
                 table.set("options", options)?;
                 table.set("environment", environment)?;
 
+                if !self.dll_overrides.is_empty() {
+                    let dll_overrides = lua.create_table()?;
+
+                    for (dll, value) in &self.dll_overrides {
+                        dll_overrides.set(dll.as_str(), value.to_wine_str())?;
+                    }
+
+                    table.set("dll_overrides", dll_overrides)?;
+                }
+
+                if !self.registry.is_empty() {
+                    let registry = lua.create_table()?;
+
+                    for value in &self.registry {
+                        let entry = lua.create_table()?;
+
+                        entry.set("key", value.key.as_str())?;
+                        entry.set("name", value.name.as_str())?;
+                        entry.set("value", value.value.as_str())?;
+
+                        entry.set("type", match value.kind {
+                            RegistryValueType::String => "string",
+                            RegistryValueType::Dword  => "dword"
+                        })?;
+
+                        registry.push(entry)?;
+                    }
+
+                    table.set("registry", registry)?;
+                }
+
                 Ok(table)
             }
         }
@@ -1,8 +1,16 @@
+use std::path::Path;
+use std::io::Read;
+
 use mlua::prelude::*;
 
+use crate::games::integrations::driver::Driver;
+
 use super::IntegrationStandard;
 use super::diff::DiffFileDownload;
 
+/// Size of the buffer used to read files for hashing
+const HASH_BUFFER_SIZE: usize = 1024 * 1024;
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct IntegrityInfo {
     pub hash: HashType,
@@ -14,10 +22,12 @@ impl IntegrityInfo {
     pub fn from_table(table: LuaTable, standard: IntegrationStandard) -> anyhow::Result<Self> {
         match standard {
             IntegrationStandard::V1 => {
+                let hash = HashType::from_str(table.get::<_, String>("hash")?, standard)?;
+
                 Ok(Self {
-                    hash: HashType::from_str(table.get::<_, String>("hash")?, standard)?,
-                    value: table.get::<_, String>("value")?,
-                    file: DiffFileDownload::from_table(table.get::<_, LuaTable>("file")?, standard)?
+                    value: hash.normalize_value(table.get::<_, String>("value")?),
+                    file: DiffFileDownload::from_table(table.get::<_, LuaTable>("file")?, standard)?,
+                    hash
                 })
             }
         }
@@ -38,6 +48,46 @@ impl IntegrityInfo {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FileHash {
+    pub hash: HashType,
+    pub value: String
+}
+
+impl FileHash {
+    pub fn from_table(table: LuaTable, standard: IntegrationStandard) -> anyhow::Result<Self> {
+        match standard {
+            IntegrationStandard::V1 => {
+                let hash = HashType::from_str(table.get::<_, String>("hash")?, standard)?;
+
+                Ok(Self {
+                    value: hash.normalize_value(table.get::<_, String>("value")?),
+                    hash
+                })
+            }
+        }
+    }
+
+    pub fn to_table<'a>(&self, lua: &'a Lua, standard: IntegrationStandard) -> anyhow::Result<LuaTable<'a>> {
+        match standard {
+            IntegrationStandard::V1 => {
+                let table = lua.create_table()?;
+
+                table.set("hash", self.hash.to_str(standard))?;
+                table.set("value", self.value.as_str())?;
+
+                Ok(table)
+            }
+        }
+    }
+
+    #[inline]
+    /// Check if the file at given path has the same hash
+    pub fn verify(&self, path: impl AsRef<Path>, driver: &Driver) -> anyhow::Result<bool> {
+        Ok(self.hash.hash_file(path, driver)? == self.value)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum HashType {
     Md5,
@@ -91,4 +141,115 @@ impl HashType {
             }
         }
     }
+
+    /// Get streaming hasher of this type
+    /// 
+    /// Return `None` for custom hashes because they
+    /// can only be calculated by the integration script
+    pub fn hasher(&self) -> Option<Hasher> {
+        match self {
+            Self::Md5         => Some(Hasher::Md5(md5::Md5::default())),
+            Self::Sha1        => Some(Hasher::Sha1(sha1::Sha1::default())),
+            Self::Crc32       => Some(Hasher::Crc32(crc32fast::Hasher::new())),
+            Self::Xxhash32    => Some(Hasher::Xxhash32(xxhash_rust::xxh32::Xxh32::new(0))),
+            Self::Xxhash64    => Some(Hasher::Xxhash64(xxhash_rust::xxh64::Xxh64::new(0))),
+            Self::Xxhash3_64  => Some(Hasher::Xxhash3_64(xxhash_rust::xxh3::Xxh3::new())),
+            Self::Xxhash3_128 => Some(Hasher::Xxhash3_128(xxhash_rust::xxh3::Xxh3::new())),
+
+            Self::Custom(_) => None
+        }
+    }
+
+    /// Convert hash value to the format returned by `hash_file`
+    ///
+    /// Built-in hashes are calculated as lowercase hex strings,
+    /// while custom ones are kept as is because they're
+    /// calculated by the integration script
+    pub fn normalize_value(&self, value: String) -> String {
+        match self {
+            Self::Custom(_) => value,
+            _ => value.to_ascii_lowercase()
+        }
+    }
+
+    /// Calculate hash of the file at given path
+    /// 
+    /// Custom hashes are calculated by the integration script
+    pub fn hash_file(&self, path: impl AsRef<Path>, driver: &Driver) -> anyhow::Result<String> {
+        let path = path.as_ref();
+
+        let Some(hasher) = self.hasher() else {
+            let Self::Custom(name) = self else {
+                unreachable!();
+            };
+
+            if !driver.has_integrity_hash()? {
+                anyhow::bail!("Integration script doesn't support custom hash: {name}");
+            }
+
+            return driver.integrity_hash(name, std::fs::read(path)?);
+        };
+
+        hasher.hash_file(path)
+    }
+}
+
+/// Hasher which can calculate hash by chunks of data
+pub enum Hasher {
+    Md5(md5::Md5),
+    Sha1(sha1::Sha1),
+    Crc32(crc32fast::Hasher),
+    Xxhash32(xxhash_rust::xxh32::Xxh32),
+    Xxhash64(xxhash_rust::xxh64::Xxh64),
+    Xxhash3_64(xxhash_rust::xxh3::Xxh3),
+    Xxhash3_128(xxhash_rust::xxh3::Xxh3)
+}
+
+impl Hasher {
+    /// Calculate hash of the file at given path
+    pub fn hash_file(mut self, path: impl AsRef<Path>) -> anyhow::Result<String> {
+        let mut file = std::fs::File::open(path)?;
+        let mut buffer = vec![0; HASH_BUFFER_SIZE];
+
+        loop {
+            let read = file.read(&mut buffer)?;
+
+            if read == 0 {
+                break;
+            }
+
+            self.update(&buffer[..read]);
+        }
+
+        Ok(self.finalize())
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        use md5::Digest as _;
+
+        match self {
+            Self::Md5(hasher)         => hasher.update(data),
+            Self::Sha1(hasher)        => hasher.update(data),
+            Self::Crc32(hasher)       => hasher.update(data),
+            Self::Xxhash32(hasher)    => hasher.update(data),
+            Self::Xxhash64(hasher)    => hasher.update(data),
+            Self::Xxhash3_64(hasher)  => hasher.update(data),
+            Self::Xxhash3_128(hasher) => hasher.update(data)
+        }
+    }
+
+    /// Get hash in the same format as integration scripts use
+    pub fn finalize(self) -> String {
+        use md5::Digest as _;
+
+        match self {
+            Self::Md5(hasher)         => format!("{:x}", hasher.finalize()),
+            Self::Sha1(hasher)        => format!("{:x}", hasher.finalize()),
+            Self::Crc32(hasher)       => hasher.finalize().to_string(),
+            Self::Xxhash32(hasher)    => format!("{:x}", hasher.digest()),
+            Self::Xxhash64(hasher)    => format!("{:x}", hasher.digest()),
+            Self::Xxhash3_64(hasher)  => format!("{:x}", hasher.digest()),
+            Self::Xxhash3_128(hasher) => format!("{:x}", hasher.digest128())
+        }
+    }
 }
@@ -1,3 +1,5 @@
+use std::path::{Path, PathBuf};
+
 use serde::{Serialize, Deserialize};
 use serde_json::Value as Json;
 
@@ -7,14 +9,22 @@ use crate::LAUNCHER_FOLDER;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct LauncherMetadata {
-    pub launches: GameLaunchesMetadata
+    pub launches: GameLaunchesMetadata,
+
+    /// Staged diff of the upcoming game version
+    pub pre_download: Option<GamePreDownloadMetadata>,
+
+    /// Paths to the unfinished transitions of the game edition
+    pub transitions: Vec<PathBuf>
 }
 
 impl Default for LauncherMetadata {
     #[inline]
     fn default() -> Self {
         Self {
-            launches: GameLaunchesMetadata::default()
+            launches: GameLaunchesMetadata::default(),
+            pre_download: None,
+            transitions: Vec::new()
         }
     }
 }
@@ -26,18 +36,43 @@ impl From<&Json> for LauncherMetadata {
         Self {
             launches: value.get("launches")
                 .map(GameLaunchesMetadata::from)
-                .unwrap_or(default.launches)
+                .unwrap_or(default.launches),
+
+            pre_download: value.get("pre_download")
+                .map(|value| {
+                    if value.is_null() {
+                        None
+                    } else {
+                        Some(GamePreDownloadMetadata::from(value))
+                    }
+                })
+                .unwrap_or(default.pre_download),
+
+            transitions: value.get("transitions")
+                .and_then(Json::as_array)
+                .map(|transitions| transitions.iter()
+                    .filter_map(Json::as_str)
+                    .map(PathBuf::from)
+                    .collect()
+                )
+                .unwrap_or(default.transitions)
         }
     }
 }
 
 impl LauncherMetadata {
-    pub fn load_for_game(game: impl AsRef<str>, edition: impl AsRef<str>) -> anyhow::Result<Self> {
-        let path = LAUNCHER_FOLDER
+    #[inline]
+    /// Get path to the metadata file of the game edition
+    pub fn get_path_for_game(game: impl AsRef<str>, edition: impl AsRef<str>) -> PathBuf {
+        LAUNCHER_FOLDER
             .join("games")
             .join(game.as_ref())
             .join(edition.as_ref())
-            .join("launcher_metadata.json");
+            .join("launcher_metadata.json")
+    }
+
+    pub fn load_for_game(game: impl AsRef<str>, edition: impl AsRef<str>) -> anyhow::Result<Self> {
+        let path = Self::get_path_for_game(game, edition);
 
         if !path.exists() {
             return Ok(Self::default());
@@ -49,15 +84,10 @@ impl LauncherMetadata {
     }
 
     pub fn save_for_game(&self, game: impl AsRef<str>, edition: impl AsRef<str>) -> anyhow::Result<()> {
-        let folder_path = LAUNCHER_FOLDER
-            .join("games")
-            .join(game.as_ref())
-            .join(edition.as_ref());
+        let file_path = Self::get_path_for_game(game, edition);
 
-        let file_path = folder_path.join("launcher_metadata.json");
-
-        if !folder_path.exists() {
-            std::fs::create_dir_all(&folder_path)?;
+        if let Some(folder_path) = file_path.parent() {
+            std::fs::create_dir_all(folder_path)?;
         }
 
         std::fs::write(file_path, serde_json::to_string_pretty(&self)?)?;
@@ -65,6 +95,36 @@ impl LauncherMetadata {
         Ok(())
     }
 
+    /// Remember unfinished transition of the game edition
+    pub fn add_transition(game: impl AsRef<str>, edition: impl AsRef<str>, path: impl Into<PathBuf>) -> anyhow::Result<()> {
+        let mut metadata = Self::load_for_game(&game, &edition)?;
+
+        let path = path.into();
+
+        if !metadata.transitions.contains(&path) {
+            metadata.transitions.push(path);
+
+            metadata.save_for_game(game, edition)?;
+        }
+
+        Ok(())
+    }
+
+    /// Forget finished transition of the game edition
+    pub fn remove_transition(game: impl AsRef<str>, edition: impl AsRef<str>, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let mut metadata = Self::load_for_game(&game, &edition)?;
+
+        let path = path.as_ref();
+
+        if metadata.transitions.iter().any(|transition| transition == path) {
+            metadata.transitions.retain(|transition| transition != path);
+
+            metadata.save_for_game(game, edition)?;
+        }
+
+        Ok(())
+    }
+
     pub fn get_last_played_text(&self) -> String {
         let Some(last_launch) = self.launches.last_launch else {
             return String::from("Never");
@@ -192,3 +252,58 @@ impl From<&Json> for GameLastLaunchMetadata {
         }
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct GamePreDownloadMetadata {
+    /// Game version the diff should be applied to
+    pub current_version: String,
+
+    /// Game version after applying the diff
+    pub latest_version: String,
+
+    /// Staged diff contains binary patches
+    pub is_patch: bool,
+
+    /// Path to the transition with pre-downloaded files.
+    /// Set when the pre-download is finished
+    pub transition_path: Option<PathBuf>
+}
+
+impl GamePreDownloadMetadata {
+    #[inline]
+    /// Get name of the transition with pre-downloaded files
+    pub fn get_transition_name(&self, game: impl AsRef<str>, edition: impl AsRef<str>) -> String {
+        format!("pre-download:{}:{}:{}:{}", game.as_ref(), edition.as_ref(), self.current_version, self.latest_version)
+    }
+
+    /// Check that the pre-download transition exists and contains any files
+    pub fn is_staged(&self) -> bool {
+        self.transition_path.as_ref()
+            .and_then(|path| path.read_dir().ok())
+            .is_some_and(|mut entries| entries.next().is_some())
+    }
+}
+
+impl From<&Json> for GamePreDownloadMetadata {
+    fn from(value: &Json) -> Self {
+        Self {
+            current_version: value.get("current_version")
+                .and_then(Json::as_str)
+                .map(String::from)
+                .unwrap_or_default(),
+
+            latest_version: value.get("latest_version")
+                .and_then(Json::as_str)
+                .map(String::from)
+                .unwrap_or_default(),
+
+            is_patch: value.get("is_patch")
+                .and_then(Json::as_bool)
+                .unwrap_or_default(),
+
+            transition_path: value.get("transition_path")
+                .and_then(Json::as_str)
+                .map(PathBuf::from)
+        }
+    }
+}
@@ -1,12 +1,41 @@
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use crate::config;
 
 pub mod integrations;
 pub mod metadata;
+pub mod patch;
 
 static mut GAMES_SINGLETON: Option<HashMap<String, integrations::Game>> = None;
 
+/// Amount of currently running games
+static RUNNING_GAMES: AtomicU64 = AtomicU64::new(0);
+
+/// Game is considered running until this guard is dropped
+pub struct RunningGameGuard;
+
+impl Drop for RunningGameGuard {
+    #[inline]
+    fn drop(&mut self) {
+        RUNNING_GAMES.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+#[inline]
+/// Mark some game as running until the returned guard is dropped
+pub fn mark_running() -> RunningGameGuard {
+    RUNNING_GAMES.fetch_add(1, Ordering::Relaxed);
+
+    RunningGameGuard
+}
+
+#[inline]
+/// Check if any game is currently running
+pub fn is_any_running() -> bool {
+    RUNNING_GAMES.load(Ordering::Relaxed) > 0
+}
+
 pub fn init() -> anyhow::Result<()> {
     let integration_scripts = config::get().games.integrations.path;
 
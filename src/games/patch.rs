@@ -0,0 +1,152 @@
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use crate::components::system_wine;
+
+/// HDiffPatch binary used to apply the patches
+pub const PATCH_TOOL: &str = "hpatchz";
+
+/// Extension of the binary patch files
+pub const PATCH_EXTENSION: &str = "hdiff";
+
+/// Name of the file with list of files which should be deleted
+pub const DELETE_LIST: &str = "deletefiles.txt";
+
+#[inline]
+/// Check that the patch tool is installed in the system
+pub fn is_available() -> bool {
+    system_wine::find_executable(PATCH_TOOL).is_some()
+}
+
+/// Find all the patch files in the given folder
+fn find_patches(folder: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let mut patches = Vec::new();
+    let mut folders = vec![folder.to_path_buf()];
+
+    while let Some(folder) = folders.pop() {
+        for entry in folder.read_dir()?.flatten() {
+            let path = entry.path();
+
+            if entry.file_type()?.is_dir() {
+                folders.push(path);
+            }
+
+            else if path.extension().is_some_and(|extension| extension == PATCH_EXTENSION) {
+                patches.push(path);
+            }
+        }
+    }
+
+    Ok(patches)
+}
+
+/// Apply all the hdiff patches from the transition folder
+///
+/// Patch `<path>.hdiff` is applied to the `<path>` file from the original folder,
+/// and the patched file is saved as `<path>` in the transition folder.
+/// Patches are deleted after applying so the process can be continued if interrupted
+///
+/// `progress` is called with `(current, total)` applied patches
+pub fn apply_patches(
+    original_path: impl AsRef<Path>,
+    transition_path: impl AsRef<Path>,
+    mut progress: impl FnMut(u64, u64) -> anyhow::Result<()>
+) -> anyhow::Result<()> {
+    let original_path = original_path.as_ref();
+    let transition_path = transition_path.as_ref();
+
+    let patches = find_patches(transition_path)?;
+    let total = patches.len() as u64;
+
+    progress(0, total)?;
+
+    for (i, patch) in patches.into_iter().enumerate() {
+        let patched = patch.with_extension("");
+        let original = original_path.join(patched.strip_prefix(transition_path)?);
+
+        if !original.exists() {
+            anyhow::bail!("Failed to find file to patch: {:?}", original);
+        }
+
+        let output = Command::new(PATCH_TOOL)
+            .arg("-f")
+            .arg(&original)
+            .arg(&patch)
+            .arg(&patched)
+            .stdout(Stdio::null())
+            .output()
+            .map_err(|err| anyhow::anyhow!("Failed to run hpatchz, make sure that HDiffPatch is installed: {err}"))?;
+
+        if !output.status.success() {
+            anyhow::bail!("Failed to apply patch {:?}: {}", patch, String::from_utf8_lossy(&output.stderr).trim());
+        }
+
+        std::fs::remove_file(&patch)?;
+
+        progress(i as u64 + 1, total)?;
+    }
+
+    Ok(())
+}
+
+/// Read list of files which should be deleted from the `deletefiles.txt` file of the transition folder
+///
+/// Listed files are deleted from the transition folder so they're not
+/// restored when the transition is finished. The list itself is deleted
+/// as well, so original folder is not changed until [`delete_files`]
+/// is called after finishing the transition
+pub fn take_delete_list(transition_path: impl AsRef<Path>) -> anyhow::Result<Vec<PathBuf>> {
+    let transition_path = transition_path.as_ref();
+
+    let list = transition_path.join(DELETE_LIST);
+
+    if !list.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut files = Vec::new();
+
+    for file in std::fs::read_to_string(&list)?.lines() {
+        let file = file.trim();
+
+        if file.is_empty() {
+            continue;
+        }
+
+        let file = PathBuf::from(file);
+
+        // Don't allow to delete files outside of the folder
+        if file.is_absolute() || file.components().any(|component| component.as_os_str() == "..") {
+            tracing::warn!("Skipping deletion of the file outside of the game folder: {:?}", file);
+
+            continue;
+        }
+
+        let path = transition_path.join(&file);
+
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+
+        files.push(file);
+    }
+
+    std::fs::remove_file(list)?;
+
+    Ok(files)
+}
+
+/// Delete files from the list returned by [`take_delete_list`]
+pub fn delete_files(original_path: impl AsRef<Path>, files: &[PathBuf]) -> anyhow::Result<()> {
+    let original_path = original_path.as_ref();
+
+    for file in files {
+        let path = original_path.join(file);
+
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+    }
+
+    Ok(())
+}
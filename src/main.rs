@@ -4,6 +4,7 @@ use relm4::prelude::*;
 
 pub mod i18n;
 pub mod utils;
+pub mod network;
 pub mod config;
 pub mod games;
 pub mod components;
@@ -0,0 +1,224 @@
+use std::path::Path;
+use std::fs::File;
+use std::time::SystemTime;
+
+use crate::config;
+
+use crate::games::integrations::standards::integrity::FileHash;
+
+use super::download::{
+    self,
+    DownloadOptions
+};
+
+/// Get name of the cached file
+///
+/// Files with known hashes are identified by them so the same
+/// file downloaded from different URIs is cached only once.
+/// Other files are identified by their URI and size.
+///
+/// Return `None` if neither hash nor size is known because
+/// the file under the same URI can be changed at any time
+pub fn get_key(uri: impl AsRef<str>, size: Option<u64>, hash: Option<&FileHash>) -> Option<String> {
+    let key = match (hash, size) {
+        (Some(hash), _) => format!("hash:{:?}:{}", hash.hash, hash.value),
+        (None, Some(size)) => format!("uri:{}:{size}", uri.as_ref()),
+
+        (None, None) => return None
+    };
+
+    Some(format!("{:032x}", xxhash_rust::xxh3::xxh3_128(key.as_bytes())))
+}
+
+/// Create hard link to the file, or copy it
+/// if they're on different filesystems
+fn link_or_copy(from: &Path, to: &Path) -> anyhow::Result<()> {
+    if std::fs::hard_link(from, to).is_err() {
+        std::fs::copy(from, to)?;
+    }
+
+    Ok(())
+}
+
+/// Put cached file to the given path
+///
+/// Return `false` if the file is not cached
+pub fn restore(key: impl AsRef<str>, path: impl AsRef<Path>) -> anyhow::Result<bool> {
+    let config = config::get().general.cache;
+
+    if !config.enabled {
+        return Ok(false);
+    }
+
+    let cached = config.path.join(key.as_ref());
+
+    if !cached.exists() {
+        return Ok(false);
+    }
+
+    let path = path.as_ref();
+
+    if path.exists() {
+        std::fs::remove_file(path)?;
+    }
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    link_or_copy(&cached, path)?;
+
+    // Recently used files are removed from the cache last
+    File::options()
+        .write(true)
+        .open(&cached)?
+        .set_modified(SystemTime::now())?;
+
+    tracing::debug!("Restored {:?} from the cache", path);
+
+    Ok(true)
+}
+
+/// Put downloaded file to the cache
+///
+/// Least recently used files are removed
+/// if the cache exceeds its size limit
+pub fn store(key: impl AsRef<str>, path: impl AsRef<Path>) -> anyhow::Result<()> {
+    let config = config::get().general.cache;
+
+    if !config.enabled {
+        return Ok(());
+    }
+
+    let path = path.as_ref();
+    let max_size = config.max_size * 1024 * 1024;
+
+    if path.metadata()?.len() > max_size {
+        return Ok(());
+    }
+
+    let cached = config.path.join(key.as_ref());
+
+    if cached.exists() {
+        return Ok(());
+    }
+
+    std::fs::create_dir_all(&config.path)?;
+
+    // Don't leave partially copied files in the cache
+    let temp = config.path.join(format!("{}.tmp", key.as_ref()));
+
+    link_or_copy(path, &temp)?;
+
+    std::fs::rename(temp, cached)?;
+
+    evict(&config.path, max_size)
+}
+
+/// Remove file from the cache
+pub fn remove(key: impl AsRef<str>) -> anyhow::Result<()> {
+    let cached = config::get().general.cache.path.join(key.as_ref());
+
+    if cached.exists() {
+        std::fs::remove_file(cached)?;
+    }
+
+    Ok(())
+}
+
+/// Remove least recently used files until the cache fits the given size
+fn evict(folder: &Path, max_size: u64) -> anyhow::Result<()> {
+    let mut files = Vec::new();
+    let mut total = 0;
+
+    for entry in folder.read_dir()?.flatten() {
+        let metadata = entry.metadata()?;
+
+        if metadata.is_file() {
+            total += metadata.len();
+
+            files.push((entry.path(), metadata.len(), metadata.modified()?));
+        }
+    }
+
+    files.sort_by_key(|(_, _, modified)| *modified);
+
+    for (path, size, _) in files {
+        if total <= max_size {
+            break;
+        }
+
+        std::fs::remove_file(path)?;
+
+        total -= size;
+    }
+
+    Ok(())
+}
+
+/// Get total size of the cached files
+pub fn get_size() -> anyhow::Result<u64> {
+    let path = config::get().general.cache.path;
+
+    if !path.exists() {
+        return Ok(0);
+    }
+
+    let mut size = 0;
+
+    for entry in path.read_dir()?.flatten() {
+        size += entry.metadata()?.len();
+    }
+
+    Ok(size)
+}
+
+/// Remove all the cached files
+pub fn clear() -> anyhow::Result<()> {
+    let path = config::get().general.cache.path;
+
+    if path.exists() {
+        std::fs::remove_dir_all(path)?;
+    }
+
+    Ok(())
+}
+
+/// Download file from the given URI, or take it from the cache
+///
+/// Downloaded file is stored in the cache.
+/// Files without hash are not cached.
+/// Cache errors don't stop the downloading
+pub fn download(
+    uri: impl AsRef<str>,
+    path: impl AsRef<Path>,
+    hash: Option<&FileHash>,
+    options: DownloadOptions,
+    mut progress: impl FnMut(u64, u64) -> anyhow::Result<()>
+) -> anyhow::Result<()> {
+    let path = path.as_ref();
+
+    let Some(key) = get_key(&uri, None, hash) else {
+        return download::download(uri, path, options, progress);
+    };
+
+    match restore(&key, path) {
+        Ok(true) => {
+            let size = path.metadata()?.len();
+
+            return progress(size, size);
+        }
+
+        Ok(false) => (),
+
+        Err(err) => tracing::warn!("Failed to restore {:?} from the cache: {err}", path)
+    }
+
+    download::download(uri, path, options, progress)?;
+
+    if let Err(err) = store(&key, path) {
+        tracing::warn!("Failed to store {:?} in the cache: {err}", path);
+    }
+
+    Ok(())
+}
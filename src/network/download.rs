@@ -0,0 +1,566 @@
+use std::path::{Path, PathBuf};
+use std::fs::File;
+use std::io::{Read, Write, BufWriter};
+use std::time::{Duration, Instant};
+
+use chrono::NaiveTime;
+
+use anime_game_core::network::minreq;
+
+use crate::config;
+use crate::config::general::prelude::Downloads;
+
+use crate::games;
+
+use super::mirrors;
+use super::throttle;
+
+/// Delay between download progress updates
+pub const PROGRESS_TIMEOUT: Duration = Duration::from_millis(50);
+
+/// Delay between checks if paused downloads can be continued
+pub const PAUSE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Maximal power of 2 used to calculate retry delay
+const MAX_BACKOFF_POWER: u32 = 8;
+
+/// Size of chunks read from the network
+const CHUNK_SIZE: usize = 64 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DownloadOptions {
+    /// Amount of retries after the first failed attempt
+    pub retries: u64,
+
+    /// Delay before the first retry. Doubled after each failed attempt
+    pub retry_delay: Duration,
+
+    /// Stop the attempt if no data was received during this time
+    pub stall_timeout: Duration,
+
+    /// Continue downloading partially downloaded file instead of overwriting it
+    pub resume: bool,
+
+    /// Maximal download speed of all the downloads together, in bytes per second.
+    /// 0 means no limit
+    pub speed_limit: u64,
+
+    /// Local time window in which downloads are allowed
+    pub schedule: Option<(NaiveTime, NaiveTime)>,
+
+    /// Pause downloads while any game is running
+    pub pause_while_playing: bool
+}
+
+impl From<&Downloads> for DownloadOptions {
+    #[inline]
+    fn from(value: &Downloads) -> Self {
+        Self {
+            retries: value.retries,
+            retry_delay: Duration::from_millis(value.retry_delay),
+            stall_timeout: Duration::from_secs(value.stall_timeout),
+            resume: true,
+            speed_limit: value.speed_limit * 1024,
+            schedule: value.schedule.get_window(),
+            pause_while_playing: value.pause_while_playing
+        }
+    }
+}
+
+impl DownloadOptions {
+    #[inline]
+    /// Get download options from the config file
+    pub fn from_config() -> Self {
+        Self::from(&config::get().general.downloads)
+    }
+
+    #[inline]
+    pub fn with_resume(self, resume: bool) -> Self {
+        Self {
+            resume,
+            ..self
+        }
+    }
+
+    /// Check if downloads should be paused now
+    pub fn is_paused(&self) -> bool {
+        if self.pause_while_playing && games::is_any_running() {
+            return true;
+        }
+
+        if let Some((from, to)) = self.schedule {
+            let now = chrono::Local::now().time();
+
+            let allowed = if from <= to {
+                now >= from && now < to
+            } else {
+                now >= from || now < to
+            };
+
+            if !allowed {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Block current thread while downloads are paused
+    pub fn wait_unpaused(&self) {
+        if self.is_paused() {
+            tracing::info!("Downloads are paused");
+
+            while self.is_paused() {
+                std::thread::sleep(PAUSE_TIMEOUT);
+            }
+
+            tracing::info!("Downloads are continued");
+        }
+    }
+}
+
+/// Get name of the file downloaded from the given URI
+pub fn get_file_name(uri: impl AsRef<str>) -> String {
+    let uri = uri.as_ref();
+
+    let path = uri.split(['?', '#'])
+        .next()
+        .unwrap_or(uri);
+
+    match path.trim_end_matches('/').rsplit('/').next() {
+        Some(name) if !name.is_empty() => name.to_string(),
+
+        _ => String::from("index.html")
+    }
+}
+
+/// Download file from the given URI
+/// 
+/// Failed attempts are repeated with exponential backoff,
+/// and attempts which stopped receiving data are considered failed
+/// 
+/// `progress` is called with `(current, total)` downloaded bytes.
+/// If it returns an error then downloading is stopped without retries
+#[inline]
+pub fn download(
+    uri: impl AsRef<str>,
+    path: impl AsRef<Path>,
+    options: DownloadOptions,
+    progress: impl FnMut(u64, u64) -> anyhow::Result<()>
+) -> anyhow::Result<()> {
+    download_mirrors(&[uri], path, options, progress)
+}
+
+/// Download file from one of the given mirrors
+/// 
+/// Mirrors are tried from the fastest measured one,
+/// and failed attempts are repeated using the next mirror.
+/// Each mirror is tried at least once
+/// 
+/// `progress` is called with `(current, total)` downloaded bytes.
+/// If it returns an error then downloading is stopped without retries
+#[inline]
+pub fn download_mirrors(
+    uris: &[impl AsRef<str>],
+    path: impl AsRef<Path>,
+    options: DownloadOptions,
+    progress: impl FnMut(u64, u64) -> anyhow::Result<()>
+) -> anyhow::Result<()> {
+    let mut target = FileTarget {
+        path: path.as_ref().to_path_buf(),
+        resume: options.resume,
+        file: None
+    };
+
+    download_target(uris, &mut target, options, progress)
+}
+
+/// Download file from one of the given mirrors
+/// and write its content to the given writer
+/// 
+/// Failed attempts continue writing from the same place,
+/// so the writer receives the file's content only once
+#[inline]
+pub fn download_stream(
+    uris: &[impl AsRef<str>],
+    writer: impl Write,
+    options: DownloadOptions,
+    progress: impl FnMut(u64, u64) -> anyhow::Result<()>
+) -> anyhow::Result<()> {
+    let mut target = StreamTarget {
+        writer,
+        written: 0
+    };
+
+    download_target(uris, &mut target, options, progress)
+}
+
+/// Destination of the downloaded data
+trait DownloadTarget {
+    /// Get amount of bytes which shouldn't be downloaded again
+    fn get_offset(&self) -> u64;
+
+    /// Prepare target to receive data starting from the given offset
+    /// 
+    /// Return amount of received bytes which should be skipped
+    fn prepare(&mut self, offset: u64) -> anyhow::Result<u64>;
+
+    fn write(&mut self, chunk: &[u8]) -> anyhow::Result<()>;
+
+    fn flush(&mut self) -> anyhow::Result<()>;
+}
+
+struct FileTarget {
+    path: PathBuf,
+    resume: bool,
+    file: Option<BufWriter<File>>
+}
+
+impl DownloadTarget for FileTarget {
+    fn get_offset(&self) -> u64 {
+        if !self.resume {
+            return 0;
+        }
+
+        self.path.metadata()
+            .map(|metadata| metadata.len())
+            .unwrap_or(0)
+    }
+
+    fn prepare(&mut self, offset: u64) -> anyhow::Result<u64> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let mut file = File::options();
+
+        if offset > 0 {
+            file.append(true);
+        } else {
+            file.write(true).truncate(true);
+        }
+
+        self.file = Some(BufWriter::new(file.create(true).open(&self.path)?));
+
+        // File is written by this download now, so next attempts
+        // should continue it even if it wasn't resumed at start
+        self.resume = true;
+
+        Ok(0)
+    }
+
+    fn write(&mut self, chunk: &[u8]) -> anyhow::Result<()> {
+        match &mut self.file {
+            Some(file) => Ok(file.write_all(chunk)?),
+            None => anyhow::bail!("Download target is not prepared")
+        }
+    }
+
+    fn flush(&mut self) -> anyhow::Result<()> {
+        if let Some(file) = &mut self.file {
+            file.flush()?;
+        }
+
+        Ok(())
+    }
+}
+
+struct StreamTarget<T> {
+    writer: T,
+    written: u64
+}
+
+impl<T: Write> DownloadTarget for StreamTarget<T> {
+    #[inline]
+    fn get_offset(&self) -> u64 {
+        self.written
+    }
+
+    #[inline]
+    fn prepare(&mut self, offset: u64) -> anyhow::Result<u64> {
+        Ok(self.written.saturating_sub(offset))
+    }
+
+    fn write(&mut self, chunk: &[u8]) -> anyhow::Result<()> {
+        self.writer.write_all(chunk)?;
+
+        self.written += chunk.len() as u64;
+
+        Ok(())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> anyhow::Result<()> {
+        Ok(self.writer.flush()?)
+    }
+}
+
+fn download_target(
+    uris: &[impl AsRef<str>],
+    target: &mut impl DownloadTarget,
+    options: DownloadOptions,
+    mut progress: impl FnMut(u64, u64) -> anyhow::Result<()>
+) -> anyhow::Result<()> {
+    let mut uris = uris.iter()
+        .map(|uri| uri.as_ref().to_string())
+        .collect::<Vec<_>>();
+
+    if uris.is_empty() {
+        anyhow::bail!("No URIs given to download");
+    }
+
+    mirrors::sort(&mut uris);
+
+    let mirrors = uris.len() as u64;
+    let attempts = (options.retries + 1).max(mirrors);
+
+    let mut attempt = 0;
+
+    loop {
+        let uri = &uris[(attempt % mirrors) as usize];
+
+        options.wait_unpaused();
+
+        match try_download(uri, target, options, &mut progress)? {
+            Ok(true) => return Ok(()),
+
+            // Downloading was paused or the attempt received some data
+            // before it was stopped, so this attempt is not counted
+            Ok(false) => continue,
+
+            Err(err) if attempt + 1 < attempts => {
+                attempt += 1;
+
+                // Don't wait before trying other mirrors for the first time
+                if attempt < mirrors {
+                    tracing::warn!("Failed to download {uri}: {err}. Trying another mirror");
+
+                    continue;
+                }
+
+                let power = (attempt / mirrors - 1) as u32;
+                let delay = options.retry_delay * 2u32.pow(power.min(MAX_BACKOFF_POWER));
+
+                tracing::warn!("Failed to download {uri} (attempt {attempt}): {err}. Retrying in {} ms", delay.as_millis());
+
+                std::thread::sleep(delay);
+            }
+
+            Err(err) => return Err(err.context(format!("Failed to download {uri} after {} attempts", attempt + 1)))
+        }
+    }
+}
+
+/// Make single download attempt
+/// 
+/// Outer result contains errors which should stop downloading,
+/// inner - errors of the current attempt. Inner value is `false`
+/// if the attempt was interrupted because downloads were paused,
+/// or if it was stopped after receiving some data
+fn try_download(
+    uri: &str,
+    target: &mut impl DownloadTarget,
+    options: DownloadOptions,
+    progress: &mut impl FnMut(u64, u64) -> anyhow::Result<()>
+) -> anyhow::Result<anyhow::Result<bool>> {
+    let result = try_download_uri(uri, target, options, progress)?;
+
+    if result.is_err() {
+        mirrors::report_failure(uri);
+    }
+
+    Ok(result)
+}
+
+#[derive(Debug)]
+enum Message {
+    Response {
+        status_code: i32,
+        length: Option<u64>,
+
+        /// Total file size from the `Content-Range` header
+        range_total: Option<u64>
+    },
+
+    Chunk(Vec<u8>),
+    Finished,
+    Error(anyhow::Error)
+}
+
+fn try_download_uri(
+    uri: &str,
+    target: &mut impl DownloadTarget,
+    options: DownloadOptions,
+    progress: &mut impl FnMut(u64, u64) -> anyhow::Result<()>
+) -> anyhow::Result<anyhow::Result<bool>> {
+    let offset = target.get_offset();
+
+    // Request has no timeout because it would limit the whole download
+    // time, not only stalls. Stalled connections are detected by the
+    // channel timeout below, and the network thread is left to finish
+    // when the connection is closed
+    let mut request = minreq::get(uri);
+
+    if offset > 0 {
+        request = request.with_header("Range", format!("bytes={offset}-"));
+    }
+
+    // Network is read in a separate thread so we can
+    // detect stalled connections using channel timeouts
+    let (sender, receiver) = flume::bounded(16);
+
+    std::thread::spawn(move || {
+        let mut response = match request.send_lazy() {
+            Ok(response) => response,
+            Err(err) => {
+                let _ = sender.send(Message::Error(err.into()));
+
+                return;
+            }
+        };
+
+        let length = response.headers.get("content-length")
+            .and_then(|length| length.parse::<u64>().ok());
+
+        // bytes <start>-<end>/<total> or bytes */<total>
+        let range_total = response.headers.get("content-range")
+            .and_then(|range| range.rsplit_once('/'))
+            .and_then(|(_, total)| total.trim().parse::<u64>().ok());
+
+        let response_info = Message::Response {
+            status_code: response.status_code,
+            length,
+            range_total
+        };
+
+        if sender.send(response_info).is_err() {
+            return;
+        }
+
+        let mut buffer = vec![0; CHUNK_SIZE];
+
+        loop {
+            let message = match response.read(&mut buffer) {
+                Ok(0) => Message::Finished,
+                Ok(read) => Message::Chunk(buffer[..read].to_vec()),
+                Err(err) => Message::Error(err.into())
+            };
+
+            let finished = !matches!(message, Message::Chunk(_));
+
+            // Channel is closed when the attempt is stopped
+            if sender.send(message).is_err() || finished {
+                return;
+            }
+        }
+    });
+
+    let receive = || -> anyhow::Result<Message> {
+        match receiver.recv_timeout(options.stall_timeout) {
+            Ok(message) => Ok(message),
+
+            Err(flume::RecvTimeoutError::Timeout) => anyhow::bail!("No data received during {} seconds", options.stall_timeout.as_secs()),
+            Err(flume::RecvTimeoutError::Disconnected) => anyhow::bail!("Connection was closed")
+        }
+    };
+
+    let (start, total) = match receive() {
+        Ok(Message::Response { status_code: 206, length, .. }) => (offset, length.map(|length| offset + length)),
+        // Server doesn't support ranges, so the file is downloaded from the start
+        Ok(Message::Response { status_code: 200, length, .. }) => {
+            if offset > 0 {
+                tracing::debug!("Server ignored requested range of {uri}. Downloading from the start");
+            }
+
+            (0, length)
+        }
+
+        // Requested range is outside of the file, so it's either already downloaded
+        // or the local file is larger than the remote one and should be downloaded again
+        Ok(Message::Response { status_code: 416, range_total, .. }) if offset > 0 => {
+            if range_total == Some(offset) {
+                return Ok(Ok(true));
+            }
+
+            target.prepare(0)?;
+
+            return Ok(Err(anyhow::anyhow!("Local file is larger than the remote one ({offset} bytes, remote size: {range_total:?})")));
+        }
+
+        Ok(Message::Response { status_code, .. }) => return Ok(Err(anyhow::anyhow!("Server responded with {status_code} status code"))),
+        Ok(Message::Error(err)) => return Ok(Err(err)),
+        Ok(message) => return Ok(Err(anyhow::anyhow!("Unexpected download message: {message:?}"))),
+        Err(err) => return Ok(Err(err))
+    };
+
+    // Amount of received bytes which were already written before
+    let mut skip = target.prepare(start)?;
+
+    let started = Instant::now();
+
+    let mut current = start;
+    let mut last_progress = Instant::now();
+
+    loop {
+        match receive() {
+            Ok(Message::Chunk(chunk)) => {
+                let skipped = skip.min(chunk.len() as u64);
+
+                skip -= skipped;
+
+                target.write(&chunk[skipped as usize..])?;
+
+                current += chunk.len() as u64;
+
+                throttle::throttle(chunk.len() as u64, options.speed_limit);
+
+                if last_progress.elapsed() >= PROGRESS_TIMEOUT {
+                    progress(current, total.unwrap_or(current))?;
+
+                    last_progress = Instant::now();
+                }
+
+                // Stop the attempt and continue downloading later
+                if options.is_paused() {
+                    target.flush()?;
+
+                    return Ok(Ok(false));
+                }
+            }
+
+            Ok(Message::Finished) => break,
+
+            Ok(Message::Error(err)) | Err(err) => {
+                target.flush()?;
+
+                // Connection has stalled or was broken after receiving some new data,
+                // so the attempt is continued from the new offset. Attempts which restarted
+                // the file are not counted only if they went further than the previous ones,
+                // so servers ignoring ranges can't repeat them forever
+                if current > offset {
+                    tracing::debug!("Download attempt stopped after {current} bytes: {err}. Continuing");
+
+                    return Ok(Ok(false));
+                }
+
+                return Ok(Err(err));
+            }
+
+            Ok(message) => return Ok(Err(anyhow::anyhow!("Unexpected download message: {message:?}")))
+        }
+    }
+
+    target.flush()?;
+
+    if let Some(total) = total {
+        if current < total {
+            return Ok(Err(anyhow::anyhow!("Connection was closed after {current} of {total} bytes")));
+        }
+    }
+
+    progress(current, total.unwrap_or(current))?;
+
+    mirrors::report_speed(uri, current - start, started.elapsed());
+
+    Ok(Ok(true))
+}
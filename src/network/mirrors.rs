@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::time::Duration;
+
+/// Weight of the latest measurement in the host speed
+const SPEED_SMOOTHING: f64 = 0.5;
+
+lazy_static::lazy_static! {
+    /// Measured download speeds of the hosts, in bytes per second
+    static ref HOSTS_SPEED: RwLock<HashMap<String, f64>> = RwLock::new(HashMap::new());
+}
+
+/// Get host part of the given URI
+pub fn get_host(uri: impl AsRef<str>) -> String {
+    let uri = uri.as_ref();
+
+    let uri = match uri.split_once("://") {
+        Some((_, uri)) => uri,
+        None => uri
+    };
+
+    uri.split(['/', '?', '#'])
+        .next()
+        .unwrap_or(uri)
+        .to_string()
+}
+
+/// Get measured download speed of the URI's host
+pub fn get_speed(uri: impl AsRef<str>) -> Option<f64> {
+    HOSTS_SPEED.read()
+        .ok()?
+        .get(&get_host(uri))
+        .copied()
+}
+
+/// Remember download speed of the URI's host
+pub fn report_speed(uri: impl AsRef<str>, bytes: u64, duration: Duration) {
+    let secs = duration.as_secs_f64();
+
+    // Too short downloads don't say anything about the mirror
+    if bytes == 0 || secs < 0.1 {
+        return;
+    }
+
+    let speed = bytes as f64 / secs;
+
+    if let Ok(mut hosts) = HOSTS_SPEED.write() {
+        hosts.entry(get_host(uri))
+            .and_modify(|prev| *prev = *prev * (1.0 - SPEED_SMOOTHING) + speed * SPEED_SMOOTHING)
+            .or_insert(speed);
+    }
+}
+
+/// Move the URI's host to the end of the mirrors list
+pub fn report_failure(uri: impl AsRef<str>) {
+    if let Ok(mut hosts) = HOSTS_SPEED.write() {
+        hosts.insert(get_host(uri), 0.0);
+    }
+}
+
+/// Sort mirrors in order they should be tried
+///
+/// Not yet measured mirrors go first in their original order
+/// so each of them will be tried once, then the rest sorted
+/// from the fastest to the slowest
+pub fn sort<T: AsRef<str>>(mirrors: &mut [T]) {
+    mirrors.sort_by(|a, b| {
+        let a = get_speed(a).unwrap_or(f64::INFINITY);
+        let b = get_speed(b).unwrap_or(f64::INFINITY);
+
+        b.total_cmp(&a)
+    });
+}
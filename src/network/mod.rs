@@ -0,0 +1,4 @@
+pub mod download;
+pub mod cache;
+pub mod mirrors;
+pub mod throttle;
@@ -0,0 +1,54 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Shared download speed budget of all the downloads
+struct Bucket {
+    updated: Instant,
+
+    /// Amount of bytes which can be downloaded without waiting.
+    /// Negative value means that downloads should wait before continuing
+    available: f64
+}
+
+lazy_static::lazy_static! {
+    static ref BUCKET: Mutex<Bucket> = Mutex::new(Bucket {
+        updated: Instant::now(),
+        available: 0.0
+    });
+}
+
+/// Account downloaded bytes and sleep the current thread
+/// if the speed limit is exceeded
+///
+/// `limit` is a maximal speed of all the downloads together,
+/// in bytes per second. 0 means no limit
+pub fn throttle(bytes: u64, limit: u64) {
+    if limit == 0 {
+        return;
+    }
+
+    let delay = {
+        let Ok(mut bucket) = BUCKET.lock() else {
+            return;
+        };
+
+        let now = Instant::now();
+        let limit = limit as f64;
+
+        // Don't allow to accumulate more than 1 second of downloading
+        bucket.available = (bucket.available + now.duration_since(bucket.updated).as_secs_f64() * limit).min(limit);
+        bucket.updated = now;
+
+        bucket.available -= bytes as f64;
+
+        if bucket.available < 0.0 {
+            Duration::from_secs_f64(-bucket.available / limit)
+        } else {
+            Duration::ZERO
+        }
+    };
+
+    if !delay.is_zero() {
+        std::thread::sleep(delay);
+    }
+}
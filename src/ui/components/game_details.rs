@@ -24,7 +24,10 @@ pub struct GameDetailsComponent {
 
     pub installed: bool,
     pub running: bool,
-    pub status: Option<Status>
+    pub status: Option<Status>,
+
+    /// Upcoming game version which can be pre-downloaded
+    pub pre_download: Option<String>
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -34,14 +37,20 @@ pub enum GameDetailsComponentInput {
     SetInstalled(bool),
     SetRunning(bool),
     SetStatus(Option<Status>),
+    SetPreDownload(Option<String>),
 
     EditCard(CardComponentInput),
 
     EmitDownloadGame,
+    EmitImportGame,
+    EmitPreDownloadGame,
     EmitVerifyGame,
+    EmitMoveGame,
+    EmitUninstallGame,
     EmitLaunchGame,
     EmitKillGame,
-    EmitOpenAddonsManager
+    EmitOpenAddonsManager,
+    EmitOpenGameSettings
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -50,10 +59,15 @@ pub enum GameDetailsComponentOutput {
     ShowTasksFlap,
 
     DownloadGame(CardInfo),
+    ImportGame(CardInfo),
+    PreDownloadGame(CardInfo),
     VerifyGame(CardInfo),
+    MoveGame(CardInfo),
+    UninstallGame(CardInfo),
     LaunchGame(CardInfo),
     KillGame(CardInfo),
     OpenAddonsManager(CardInfo),
+    OpenGameSettings(CardInfo),
 
     ShowToast {
         title: String,
@@ -221,6 +235,67 @@ impl SimpleAsyncComponent for GameDetailsComponent {
 
                             connect_clicked => GameDetailsComponentInput::EmitOpenAddonsManager
                         },
+
+                        gtk::Button {
+                            adw::ButtonContent {
+                                set_icon_name: "emblem-system-symbolic",
+                                set_label: &tr!("details-settings")
+                            },
+
+                            add_css_class: "pill",
+
+                            #[watch]
+                            set_visible: !model.running,
+
+                            connect_clicked => GameDetailsComponentInput::EmitOpenGameSettings
+                        },
+
+                        gtk::Button {
+                            adw::ButtonContent {
+                                set_icon_name: "folder-symbolic",
+                                set_label: &tr!("details-move")
+                            },
+
+                            add_css_class: "pill",
+
+                            #[watch]
+                            set_visible: !model.running,
+
+                            connect_clicked => GameDetailsComponentInput::EmitMoveGame
+                        },
+
+                        gtk::Button {
+                            adw::ButtonContent {
+                                set_icon_name: "user-trash-symbolic",
+                                set_label: &tr!("details-uninstall")
+                            },
+
+                            add_css_class: "pill",
+                            add_css_class: "destructive-action",
+
+                            #[watch]
+                            set_visible: !model.running,
+
+                            connect_clicked => GameDetailsComponentInput::EmitUninstallGame
+                        },
+
+                        gtk::Button {
+                            adw::ButtonContent {
+                                set_icon_name: "document-save-symbolic",
+
+                                #[watch]
+                                set_label: &tr!("details-pre-download", {
+                                    "version" = model.pre_download.clone().unwrap_or_default()
+                                })
+                            },
+
+                            add_css_class: "pill",
+
+                            #[watch]
+                            set_visible: !model.running && model.pre_download.is_some(),
+
+                            connect_clicked => GameDetailsComponentInput::EmitPreDownloadGame
+                        }
                     }
                 },
 
@@ -248,6 +323,17 @@ impl SimpleAsyncComponent for GameDetailsComponent {
                             },
 
                             connect_clicked => GameDetailsComponentInput::EmitDownloadGame
+                        },
+
+                        gtk::Button {
+                            add_css_class: "pill",
+
+                            adw::ButtonContent {
+                                set_icon_name: "folder-open-symbolic",
+                                set_label: &tr!("details-import")
+                            },
+
+                            connect_clicked => GameDetailsComponentInput::EmitImportGame
                         }
                     }
                 }
@@ -266,7 +352,8 @@ impl SimpleAsyncComponent for GameDetailsComponent {
 
             installed: false,
             running: false,
-            status: None
+            status: None,
+            pre_download: None
         };
 
         model.game_card.emit(CardComponentInput::SetClickable(false));
@@ -295,6 +382,7 @@ impl SimpleAsyncComponent for GameDetailsComponent {
 
             GameDetailsComponentInput::SetRunning(running) => self.running = running,
             GameDetailsComponentInput::SetStatus(status) => self.status = status,
+            GameDetailsComponentInput::SetPreDownload(version) => self.pre_download = version,
 
             GameDetailsComponentInput::EditCard(message) => self.game_card.emit(message),
 
@@ -305,6 +393,17 @@ impl SimpleAsyncComponent for GameDetailsComponent {
                 sender.output(GameDetailsComponentOutput::ShowTasksFlap).unwrap();
             }
 
+            GameDetailsComponentInput::EmitImportGame => {
+                sender.output(GameDetailsComponentOutput::ImportGame(self.info.clone())).unwrap();
+            }
+
+            GameDetailsComponentInput::EmitPreDownloadGame => {
+                sender.output(GameDetailsComponentOutput::PreDownloadGame(self.info.clone())).unwrap();
+
+                sender.output(GameDetailsComponentOutput::HideDetails).unwrap();
+                sender.output(GameDetailsComponentOutput::ShowTasksFlap).unwrap();
+            }
+
             GameDetailsComponentInput::EmitVerifyGame => {
                 sender.output(GameDetailsComponentOutput::VerifyGame(self.info.clone())).unwrap();
 
@@ -312,6 +411,14 @@ impl SimpleAsyncComponent for GameDetailsComponent {
                 sender.output(GameDetailsComponentOutput::ShowTasksFlap).unwrap();
             }
 
+            GameDetailsComponentInput::EmitMoveGame => {
+                sender.output(GameDetailsComponentOutput::MoveGame(self.info.clone())).unwrap();
+            }
+
+            GameDetailsComponentInput::EmitUninstallGame => {
+                sender.output(GameDetailsComponentOutput::UninstallGame(self.info.clone())).unwrap();
+            }
+
             GameDetailsComponentInput::EmitLaunchGame => {
                 sender.output(GameDetailsComponentOutput::LaunchGame(self.info.clone())).unwrap();
             }
@@ -323,6 +430,10 @@ impl SimpleAsyncComponent for GameDetailsComponent {
             GameDetailsComponentInput::EmitOpenAddonsManager => {
                 sender.output(GameDetailsComponentOutput::OpenAddonsManager(self.info.clone())).unwrap();
             }
+
+            GameDetailsComponentInput::EmitOpenGameSettings => {
+                sender.output(GameDetailsComponentOutput::OpenGameSettings(self.info.clone())).unwrap();
+            }
         }
     }
 }
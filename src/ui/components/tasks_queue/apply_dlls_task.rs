@@ -0,0 +1,108 @@
+use std::path::PathBuf;
+
+use anime_game_core::updater::{
+    UpdaterExt,
+    BasicUpdater,
+    Status as BasicStatus
+};
+
+use crate::ui::components::game_card::CardInfo;
+
+use crate::components::prefix_dlls::PrefixDlls;
+
+use super::{
+    QueuedTask,
+    ResolvedTask,
+    TaskStatus
+};
+
+#[derive(Debug)]
+pub struct ApplyDllsQueuedTask {
+    pub card_info: CardInfo,
+    pub component: PrefixDlls,
+
+    /// Downloaded component folder
+    pub folder: PathBuf,
+
+    /// Component version name
+    pub version: String,
+
+    pub prefix_path: PathBuf
+}
+
+impl QueuedTask for ApplyDllsQueuedTask {
+    #[inline]
+    fn get_info(&self) -> CardInfo {
+        self.card_info.clone()
+    }
+
+    fn resolve(self: Box<Self>) -> anyhow::Result<Box<dyn ResolvedTask>> {
+        Ok(Box::new(ApplyDllsResolvedTask {
+            card_info: self.card_info.clone(),
+            component: self.component,
+
+            updater: BasicUpdater::spawn(move |sender| {
+                Box::new(move || -> Result<(), anyhow::Error> {
+                    sender.send(((), 0, 1))?;
+
+                    self.component.install(&self.folder, &self.prefix_path)?;
+
+                    sender.send(((), 1, 1))?;
+
+                    Ok(())
+                })
+            })
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub struct ApplyDllsResolvedTask {
+    pub updater: BasicUpdater<(), (), anyhow::Error>,
+    pub card_info: CardInfo,
+    pub component: PrefixDlls
+}
+
+impl ResolvedTask for ApplyDllsResolvedTask {
+    #[inline]
+    fn get_info(&self) -> CardInfo {
+        self.card_info.clone()
+    }
+
+    #[inline]
+    fn is_finished(&mut self) -> bool {
+        self.updater.is_finished()
+    }
+
+    #[inline]
+    fn get_current(&self) -> u64 {
+        self.updater.current()
+    }
+
+    #[inline]
+    fn get_total(&self) -> u64 {
+        self.updater.total()
+    }
+
+    #[inline]
+    fn get_progress(&self) -> f64 {
+        self.updater.progress()
+    }
+
+    fn get_status(&mut self) -> anyhow::Result<TaskStatus> {
+        match self.updater.status() {
+            Ok(status) => Ok(match status {
+                BasicStatus::Pending => TaskStatus::Pending,
+
+                BasicStatus::Working(()) => match self.component {
+                    PrefixDlls::Vkd3d => TaskStatus::InstallingVkd3d,
+                    PrefixDlls::Nvapi => TaskStatus::InstallingNvapi
+                },
+
+                BasicStatus::Finished => TaskStatus::Finished
+            }),
+
+            Err(err) => anyhow::bail!(err.to_string())
+        }
+    }
+}
@@ -10,6 +10,12 @@ use anime_game_core::updater::UpdaterExt;
 
 use crate::components::wine::Wine;
 use crate::components::dxvk::Dxvk;
+use crate::components::vkd3d::Vkd3d;
+use crate::components::nvapi::Nvapi;
+
+use crate::config;
+
+use crate::config::games::settings::edition_overrides::ComponentVersion;
 
 use crate::ui::components::game_card::CardInfo;
 
@@ -19,6 +25,8 @@ use super::{QueuedTask, ResolvedTask, TaskStatus};
 pub enum Status {
     CreatingPrefix,
     InstallingDxvk,
+    InstallingVkd3d,
+    InstallingNvapi,
     InstallingFonts,
     Finished
 }
@@ -105,7 +113,13 @@ impl UpdaterExt for Updater {
 #[derive(Debug)]
 pub struct CreatePrefixQueuedTask {
     pub path: PathBuf,
-    pub install_corefonts: bool
+    pub install_corefonts: bool,
+
+    /// Wine version used to create the prefix. Selected in the config if not set
+    pub wine: Option<ComponentVersion>,
+
+    /// DXVK version installed to the prefix. Selected in the config if not set
+    pub dxvk: Option<ComponentVersion>
 }
 
 impl QueuedTask for CreatePrefixQueuedTask {
@@ -120,10 +134,17 @@ impl QueuedTask for CreatePrefixQueuedTask {
     fn resolve(self: Box<Self>) -> anyhow::Result<Box<dyn ResolvedTask>> {
         let (sender, receiver) = flume::unbounded();
 
-        let Some(wine) = Wine::from_config()?.to_wincompatlib() else {
+        let wine = match &self.wine {
+            Some(wine) => Wine::from_version(&wine.build, &wine.version)?,
+            None => Wine::from_config()?
+        };
+
+        let Some(wine) = wine.to_wincompatlib() else {
             anyhow::bail!("Failed to resolve wincompatlib wine descriptor");
         };
 
+        let components = config::get().components;
+
         Ok(Box::new(CreatePrefixResolvedTask {
             updater: Updater {
                 status: Cell::new(Status::CreatingPrefix),
@@ -157,7 +178,10 @@ impl QueuedTask for CreatePrefixQueuedTask {
 
                     sender.send((Status::InstallingDxvk, 0, 1))?;
 
-                    let dxvk = Dxvk::from_config()?;
+                    let dxvk = match &self.dxvk {
+                        Some(dxvk) => Dxvk::from_version(&dxvk.build, &dxvk.version)?,
+                        None => Dxvk::from_config()?
+                    };
 
                     wine.install_dxvk(dxvk.get_folder(), InstallParams {
                         repair_dlls: false,
@@ -166,6 +190,35 @@ impl QueuedTask for CreatePrefixQueuedTask {
 
                     sender.send((Status::InstallingDxvk, 1, 1))?;
 
+                    // Apply VKD3D-Proton
+
+                    if components.vkd3d.enabled {
+                        sender.send((Status::InstallingVkd3d, 0, 1))?;
+
+                        let vkd3d = Vkd3d::from_config()?;
+
+                        // Not downloaded version will be applied after downloading
+                        if vkd3d.is_downloaded() {
+                            vkd3d.install(&self.path)?;
+                        }
+
+                        sender.send((Status::InstallingVkd3d, 1, 1))?;
+                    }
+
+                    // Apply DXVK-NVAPI
+
+                    if components.nvapi.enabled {
+                        sender.send((Status::InstallingNvapi, 0, 1))?;
+
+                        let nvapi = Nvapi::from_config()?;
+
+                        if nvapi.is_downloaded() {
+                            nvapi.install(&self.path)?;
+                        }
+
+                        sender.send((Status::InstallingNvapi, 1, 1))?;
+                    }
+
                     // Install fonts
 
                     if self.install_corefonts {
@@ -272,6 +325,8 @@ impl ResolvedTask for CreatePrefixResolvedTask {
             Ok(status) => Ok(match status {
                 Status::CreatingPrefix  => TaskStatus::CreatingPrefix,
                 Status::InstallingDxvk  => TaskStatus::InstallingDxvk,
+                Status::InstallingVkd3d => TaskStatus::InstallingVkd3d,
+                Status::InstallingNvapi => TaskStatus::InstallingNvapi,
                 Status::InstallingFonts => TaskStatus::InstallingFonts,
                 Status::Finished        => TaskStatus::Finished
             }),
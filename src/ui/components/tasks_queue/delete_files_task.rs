@@ -18,23 +18,33 @@ use super::{
 
 #[derive(Debug, Clone)]
 pub struct DeleteFilesQueuedTask {
+    /// Card of the game which files are deleted
+    pub card_info: Option<CardInfo>,
+
     pub paths: Vec<PathBuf>
 }
 
+#[inline]
+fn get_card_info(card_info: &Option<CardInfo>) -> CardInfo {
+    card_info.clone().unwrap_or_else(|| CardInfo::Component {
+        name: String::from("delete-files"),
+        title: tr!("tasks-delete-files"),
+        developer: String::new()
+    })
+}
+
 impl QueuedTask for DeleteFilesQueuedTask {
     #[inline]
     fn get_info(&self) -> CardInfo {
-        CardInfo::Component {
-            name: String::from("delete-files"),
-            title: tr!("tasks-delete-files"),
-            developer: String::new()
-        }
+        get_card_info(&self.card_info)
     }
 
     fn resolve(self: Box<Self>) -> anyhow::Result<Box<dyn ResolvedTask>> {
         let paths = self.paths.clone();
 
         Ok(Box::new(DeleteFilesResolvedTask {
+            card_info: self.card_info.clone(),
+
             updater: BasicUpdater::spawn(move |sender| {
                 Box::new(move || -> Result<(), anyhow::Error> {
                     sender.send(((), 0, 1))?;
@@ -58,17 +68,14 @@ impl QueuedTask for DeleteFilesQueuedTask {
 
 #[derive(Debug)]
 pub struct DeleteFilesResolvedTask {
+    pub card_info: Option<CardInfo>,
     pub updater: BasicUpdater<(), (), anyhow::Error>
 }
 
 impl ResolvedTask for DeleteFilesResolvedTask {
     #[inline]
     fn get_info(&self) -> CardInfo {
-        CardInfo::Component {
-            name: String::from("delete-files"),
-            title: tr!("tasks-delete-files"),
-            developer: String::new()
-        }
+        get_card_info(&self.card_info)
     }
 
     #[inline]
@@ -1,5 +1,8 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio, ChildStdin};
+use std::io::Write;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use std::sync::atomic::{
     AtomicU64,
     Ordering
@@ -14,15 +17,30 @@ use anime_game_core::updater::{
 use anime_game_core::archive;
 use anime_game_core::filesystem::transition::Transition;
 
-use anime_game_core::network::downloader::{
-    DownloaderExt,
-    basic::Downloader
-};
-
 use crate::ui::components::game_card::CardInfo;
 
+use crate::network::cache;
+use crate::network::download::{
+    self,
+    DownloadOptions
+};
+
 use crate::games;
-use crate::games::integrations::standards::diff::DiffInfo;
+use crate::games::patch;
+use crate::games::integrations::driver::Driver;
+use crate::games::integrations::standards::diff::{
+    DiffInfo,
+    ExtractMode
+};
+
+use crate::games::integrations::standards::integrity::{
+    FileHash,
+    Hasher
+};
+use crate::games::metadata::{
+    LauncherMetadata,
+    GamePreDownloadMetadata
+};
 
 use crate::config;
 
@@ -41,12 +59,26 @@ pub enum DiffOrigin {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DiffStage {
+    /// Download and apply the diff
+    Apply,
+
+    /// Download the diff into a staged transition without applying it
+    PreDownload(GamePreDownloadMetadata),
+
+    /// Apply previously pre-downloaded diff
+    ApplyPreDownloaded(GamePreDownloadMetadata)
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Status {
     PreparingTransition,
     RunPreTransitionCode,
     Downloading,
+    Verifying,
     Unpacking,
+    ApplyingPatches,
     RunTransitionCode,
     FinishingTransition,
     RunPostTransitionCode
@@ -57,9 +89,38 @@ pub struct DownloadDiffQueuedTask {
     pub card_info: CardInfo,
     pub diff_info: DiffInfo,
     pub diff_origin: DiffOrigin,
+    pub diff_stage: DiffStage,
     pub download_path: PathBuf
 }
 
+impl DownloadDiffQueuedTask {
+    /// Get name of the transition used by the task
+    pub fn get_transition_name(&self) -> String {
+        let game_name = self.card_info.get_name();
+        let game_edition = self.card_info.get_edition();
+
+        // Pre-downloaded diffs use the same transition
+        // so it can be applied later without downloading
+        match &self.diff_stage {
+            DiffStage::Apply => format!("download-diff:{game_name}:{game_edition}:{:?}:{:?}", self.diff_origin, self.diff_info),
+
+            DiffStage::PreDownload(pre_download) |
+            DiffStage::ApplyPreDownloaded(pre_download) => pre_download.get_transition_name(game_name, game_edition)
+        }
+    }
+
+    /// Check if the task applies binary patches
+    pub fn is_patch(&self) -> bool {
+        match &self.diff_stage {
+            // Staged files were downloaded from the pre-download diff
+            // which can differ from the diff of the released version
+            DiffStage::ApplyPreDownloaded(pre_download) => pre_download.is_patch,
+
+            _ => matches!(self.diff_info, DiffInfo::Patch { .. })
+        }
+    }
+}
+
 impl QueuedTask for DownloadDiffQueuedTask {
     #[inline]
     fn get_info(&self) -> CardInfo {
@@ -74,8 +135,16 @@ impl QueuedTask for DownloadDiffQueuedTask {
 
         let diff_info = self.diff_info.clone();
         let diff_origin = self.diff_origin.clone();
+        let diff_stage = self.diff_stage.clone();
 
         let download_path = self.download_path.clone();
+        let transition_name = self.get_transition_name();
+        let is_patch = self.is_patch();
+
+        // Patches of the pre-downloaded diff are applied later
+        if is_patch && !matches!(diff_stage, DiffStage::PreDownload(_)) && !patch::is_available() {
+            anyhow::bail!("Failed to find {}, make sure that HDiffPatch is installed", patch::PATCH_TOOL);
+        }
 
         Ok(Box::new(DownloadDiffResolvedTask {
             card_info: self.card_info.clone(),
@@ -91,16 +160,23 @@ impl QueuedTask for DownloadDiffQueuedTask {
                     sender.send((Status::PreparingTransition, 0, 1))?;
 
                     let transition = Transition::get_in(
-                        format!("download-diff:{game_name}:{game_edition}:{:?}:{:?}", diff_origin, diff_info),
+                        transition_name,
                         &download_path,
                         config.general.transitions.path
                     )?;
 
+                    LauncherMetadata::add_transition(&game_name, &game_edition, transition.transition_path())?;
+
                     sender.send((Status::PreparingTransition, 1, 1))?;
 
                     // Run pre-transition code
 
+                    let is_pre_download = matches!(diff_stage, DiffStage::PreDownload(_));
+
                     match &diff_origin {
+                        // Original files must not be changed before the new version is released
+                        _ if is_pre_download => (),
+
                         DiffOrigin::Game if game.driver.has_game_diff_pre_transition()? => {
                             sender.send((Status::RunPreTransitionCode, 0, 1))?;
 
@@ -130,101 +206,184 @@ impl QueuedTask for DownloadDiffQueuedTask {
 
                     // Download and extract diff files
 
-                    match diff_info {
-                        DiffInfo::Archive { size: _, uri } => {
-                            // Download archive
+                    let download_options = DownloadOptions::from(&config.general.downloads);
 
-                            let downloader = Downloader::new(uri);
+                    // Same check is used to estimate the required disk space
+                    let tar_flags = diff_info.get_stream_tar_flags();
 
-                            let archive = transition.transition_path()
-                                .join(downloader.file_name());
+                    let stream_requested = matches!(
+                        diff_info,
+                        DiffInfo::Archive { extract: ExtractMode::Stream, .. } |
+                        DiffInfo::Segments { extract: ExtractMode::Stream, .. }
+                    );
 
-                            let mut updater = downloader.download(&archive)?;
+                    if stream_requested && tar_flags.is_none() {
+                        tracing::warn!("Stream extraction is not supported for this diff, downloading it fully");
+                    }
 
-                            while !updater.is_finished() {
-                                // TODO: add timeouts
+                    match diff_info {
+                        // Files were already downloaded into the staged transition
+                        _ if matches!(diff_stage, DiffStage::ApplyPreDownloaded(_)) => (),
 
-                                sender.send((
-                                    Status::Downloading,
-                                    updater.current(),
-                                    updater.total()
-                                ))?;
+                        DiffInfo::Archive { size, uri, mirrors, hash, .. } => {
+                            let archive = transition.transition_path()
+                                .join(download::get_file_name(&uri));
+
+                            let uris = std::iter::once(uri.as_str())
+                                .chain(mirrors.iter().map(String::as_str))
+                                .collect::<Vec<_>>();
+
+                            // Extract archive while downloading it
+                            if let Some(flags) = tar_flags {
+                                extract_stream(flags, transition.transition_path(), |stdin| {
+                                    download_stream_verified(&uris, stdin, hash.as_ref(), download_options, |current, total| {
+                                        Ok(sender.send((Status::Downloading, current, total))?)
+                                    })
+                                })?;
                             }
 
-                            // Extract archive
+                            else {
+                                // Download archive
 
-                            let Some(mut updater) = archive::extract(&archive, transition.transition_path()) else {
-                                anyhow::bail!("Failed to extract files from the archive: {:?}", archive);
-                            };
+                                let cache_key = cache::get_key(&uri, Some(size), hash.as_ref());
 
-                            while let Ok(false) = updater.status() {
-                                // TODO: add timeouts
+                                download_verified(&game.driver, &uris, &archive, hash.as_ref(), cache_key.as_deref(), download_options, |status, current, total| {
+                                    Ok(sender.send((status, current, total))?)
+                                })?;
 
-                                sender.send((
-                                    Status::Unpacking,
-                                    updater.current(),
-                                    updater.total()
-                                ))?;
-                            }
+                                // Extract archive
 
-                            // Delete archive
+                                extract_archive(&archive, transition.transition_path(), download_options.stall_timeout, |current, total| {
+                                    Ok(sender.send((Status::Unpacking, current, total))?)
+                                })?;
 
-                            std::fs::remove_file(archive)?;
+                                // Delete archive
+
+                                std::fs::remove_file(archive)?;
+                            }
                         }
 
-                        DiffInfo::Segments { size, segments } => {
-                            // Download segments
+                        DiffInfo::Segments { size, segments, extract, .. } => {
+                            let Some(first_segment) = segments.first() else {
+                                anyhow::bail!("Segmented archive has no segments");
+                            };
+
+                            let first_archive = transition.transition_path()
+                                .join(download::get_file_name(&first_segment.uri));
 
-                            let mut archives = vec![];
                             let mut downloaded = 0;
 
-                            for uri in segments {
-                                let downloader = Downloader::new(uri);
+                            match (extract, tar_flags) {
+                                // Extract segments while downloading them
+                                // as one continuous archive
+                                (_, Some(flags)) => {
+                                    extract_stream(flags, transition.transition_path(), |stdin| {
+                                        for segment in &segments {
+                                            let mut segment_size = 0;
+
+                                            download_stream_verified(&segment.get_uris(), &mut *stdin, segment.hash.as_ref(), download_options, |current, _| {
+                                                segment_size = current;
+
+                                                Ok(sender.send((Status::Downloading, downloaded + current, size))?)
+                                            })?;
+
+                                            downloaded += segment_size;
+                                        }
+
+                                        Ok(())
+                                    })?;
+                                }
+
+                                // Download, extract and delete segments one by one
+                                (ExtractMode::Sequential, _) => {
+                                    for segment in &segments {
+                                        let archive = transition.transition_path()
+                                            .join(download::get_file_name(&segment.uri));
 
-                                let archive = transition.transition_path()
-                                    .join(downloader.file_name());
+                                        let cache_key = cache::get_key(&segment.uri, None, segment.hash.as_ref());
 
-                                let mut updater = downloader.download(&archive)?;
+                                        download_verified(&game.driver, &segment.get_uris(), &archive, segment.hash.as_ref(), cache_key.as_deref(), download_options, |status, current, total| {
+                                            match status {
+                                                Status::Downloading => Ok(sender.send((status, downloaded + current, size))?),
+                                                _ => Ok(sender.send((status, current, total))?)
+                                            }
+                                        })?;
 
-                                archives.push(archive);
+                                        downloaded += archive.metadata()?.len();
 
-                                while !updater.is_finished() {
-                                    // TODO: add timeouts
+                                        extract_archive(&archive, transition.transition_path(), download_options.stall_timeout, |current, total| {
+                                            Ok(sender.send((Status::Unpacking, current, total))?)
+                                        })?;
 
-                                    sender.send((
-                                        Status::Downloading,
-                                        downloaded + updater.current(),
-                                        size
-                                        // updater.total()
-                                    ))?;
+                                        std::fs::remove_file(archive)?;
+                                    }
                                 }
 
-                                downloaded += updater.total();
-                            }
+                                _ => {
+                                    // Download segments
 
-                            // Extract segments
+                                    let mut archives = vec![];
 
-                            let Some(mut updater) = archive::extract(&archives[0], transition.transition_path()) else {
-                                anyhow::bail!("Failed to extract files from segmented archive: {:?}", archives[0]);
-                            };
+                                    for segment in &segments {
+                                        let archive = transition.transition_path()
+                                            .join(download::get_file_name(&segment.uri));
 
-                            while let Ok(false) = updater.status() {
-                                // TODO: add timeouts
+                                        let cache_key = cache::get_key(&segment.uri, None, segment.hash.as_ref());
 
-                                sender.send((
-                                    Status::Unpacking,
-                                    updater.current(),
-                                    updater.total()
-                                ))?;
-                            }
+                                        download_verified(&game.driver, &segment.get_uris(), &archive, segment.hash.as_ref(), cache_key.as_deref(), download_options, |status, current, total| {
+                                            match status {
+                                                Status::Downloading => Ok(sender.send((status, downloaded + current, size))?),
+                                                _ => Ok(sender.send((status, current, total))?)
+                                            }
+                                        })?;
 
-                            // Delete segments
+                                        downloaded += archive.metadata()?.len();
 
-                            for archive in archives {
-                                std::fs::remove_file(archive)?;
+                                        archives.push(archive);
+                                    }
+
+                                    // Extract segments
+
+                                    extract_archive(&first_archive, transition.transition_path(), download_options.stall_timeout, |current, total| {
+                                        Ok(sender.send((Status::Unpacking, current, total))?)
+                                    })?;
+
+                                    // Delete segments
+
+                                    for archive in archives {
+                                        std::fs::remove_file(archive)?;
+                                    }
+                                }
                             }
                         }
 
+                        DiffInfo::Patch { size, uri, mirrors, hash, .. } => {
+                            // Download patches archive
+
+                            let archive = transition.transition_path()
+                                .join(download::get_file_name(&uri));
+
+                            let uris = std::iter::once(uri.as_str())
+                                .chain(mirrors.iter().map(String::as_str))
+                                .collect::<Vec<_>>();
+
+                            let cache_key = cache::get_key(&uri, Some(size), hash.as_ref());
+
+                            download_verified(&game.driver, &uris, &archive, hash.as_ref(), cache_key.as_deref(), download_options, |status, current, total| {
+                                Ok(sender.send((status, current, total))?)
+                            })?;
+
+                            // Extract patches archive
+
+                            extract_archive(&archive, transition.transition_path(), download_options.stall_timeout, |current, total| {
+                                Ok(sender.send((Status::Unpacking, current, total))?)
+                            })?;
+
+                            // Delete patches archive
+
+                            std::fs::remove_file(archive)?;
+                        }
+
                         DiffInfo::Files { size, files } => {
                             let pool = rusty_pool::Builder::new()
                                 .name(String::from("download_files"))
@@ -234,23 +393,47 @@ impl QueuedTask for DownloadDiffQueuedTask {
                             let queue_size = config.general.threads.max_queue_size as usize;
 
                             let mut tasks = Vec::with_capacity(queue_size);
+                            let mut failed_files = Vec::new();
 
                             let downloaded = Arc::new(AtomicU64::new(0));
 
+                            // Files are always downloaded from scratch because
+                            // transition can contain their older versions
+                            let download_options = download_options.with_resume(false);
+
                             for chunk in files.chunks(queue_size) {
                                 for file in chunk {
                                     let download_path = transition.transition_path().join(&file.path);
-                                    let download_uri = file.uri.clone();
+                                    let download_uris = file.get_uris()
+                                        .into_iter()
+                                        .map(String::from)
+                                        .collect::<Vec<_>>();
+                                    let file_path = file.path.clone();
+                                    let file_hash = file.hash.clone();
                                     let file_size = file.size;
 
                                     let downloaded = downloaded.clone();
                                     let sender = sender.clone();
 
-                                    tasks.push(pool.evaluate(move || -> anyhow::Result<()> {
-                                        Downloader::new(download_uri)
-                                            .continue_downloading(false)
-                                            .download(download_path)?
-                                            .wait()?;
+                                    tasks.push(pool.evaluate(move || -> anyhow::Result<Option<String>> {
+                                        // Skip files which were already downloaded during the previous attempt
+                                        let downloaded_before = match &file_hash {
+                                            Some(hash) if download_path.exists() => hash.verify(&download_path, &game.driver).unwrap_or(false),
+                                            _ => false
+                                        };
+
+                                        let result = if downloaded_before {
+                                            Ok(())
+                                        } else {
+                                            download_verified(&game.driver, &download_uris, &download_path, file_hash.as_ref(), None, download_options, |_, _, _| Ok(()))
+                                        };
+
+                                        // Don't stop the whole transition because of one file
+                                        if let Err(err) = result {
+                                            tracing::error!("Failed to download {file_path}: {err}");
+
+                                            return Ok(Some(file_path));
+                                        }
 
                                         let prev = downloaded.fetch_add(file_size, Ordering::Relaxed);
 
@@ -260,17 +443,53 @@ impl QueuedTask for DownloadDiffQueuedTask {
                                             size
                                         ))?;
 
-                                        Ok(())
+                                        Ok(None)
                                     }));
                                 }
 
                                 for task in tasks.drain(..) {
-                                    task.await_complete()?;
+                                    if let Some(file) = task.await_complete()? {
+                                        failed_files.push(file);
+                                    }
                                 }
                             }
+
+                            // Transition is not finished so already downloaded
+                            // files will be kept for the next attempt
+                            if !failed_files.is_empty() {
+                                anyhow::bail!("Failed to download {} files:\n{}", failed_files.len(), failed_files.join("\n"));
+                            }
                         }
                     }
 
+                    // Keep pre-downloaded files in the unfinished transition
+
+                    if let DiffStage::PreDownload(pre_download) = &diff_stage {
+                        let mut metadata = LauncherMetadata::load_for_game(&game_name, &game_edition)?;
+
+                        metadata.pre_download = Some(GamePreDownloadMetadata {
+                            transition_path: Some(transition.transition_path()),
+                            ..pre_download.clone()
+                        });
+
+                        metadata.save_for_game(&game_name, &game_edition)?;
+
+                        return Ok(());
+                    }
+
+                    // Apply binary patches. Files removed by them are deleted
+                    // from the original folder after finishing the transition
+
+                    let mut deleted_files = Vec::new();
+
+                    if is_patch {
+                        patch::apply_patches(transition.original_path(), transition.transition_path(), |current, total| {
+                            Ok(sender.send((Status::ApplyingPatches, current, total))?)
+                        })?;
+
+                        deleted_files = patch::take_delete_list(transition.transition_path())?;
+                    }
+
                     // Run transition code
 
                     match &diff_origin {
@@ -307,6 +526,18 @@ impl QueuedTask for DownloadDiffQueuedTask {
 
                     transition.finish()?;
 
+                    LauncherMetadata::remove_transition(&game_name, &game_edition, transition.transition_path())?;
+
+                    patch::delete_files(transition.original_path(), &deleted_files)?;
+
+                    if let DiffStage::ApplyPreDownloaded(_) = diff_stage {
+                        let mut metadata = LauncherMetadata::load_for_game(&game_name, &game_edition)?;
+
+                        metadata.pre_download = None;
+
+                        metadata.save_for_game(&game_name, &game_edition)?;
+                    }
+
                     sender.send((Status::FinishingTransition, 1, 1))?;
 
                     // Run post-transition code
@@ -346,6 +577,222 @@ impl QueuedTask for DownloadDiffQueuedTask {
     }
 }
 
+/// Download file and verify its hash if it's provided
+/// 
+/// File is deleted and downloaded again if its hash doesn't match.
+/// If `cache_key` is given then the file is taken from the download cache
+/// when available, and stored there after downloading
+fn download_verified(
+    driver: &Driver,
+    uris: &[impl AsRef<str>],
+    path: &Path,
+    hash: Option<&FileHash>,
+    cache_key: Option<&str>,
+    options: DownloadOptions,
+    mut progress: impl FnMut(Status, u64, u64) -> anyhow::Result<()>
+) -> anyhow::Result<()> {
+    let mut cached = match cache_key {
+        Some(key) => cache::restore(key, path).unwrap_or_else(|err| {
+            tracing::warn!("Failed to restore {:?} from the cache: {err}", path);
+
+            false
+        }),
+
+        None => false
+    };
+
+    let mut attempt = 0;
+
+    loop {
+        if cached {
+            let size = path.metadata()?.len();
+
+            progress(Status::Downloading, size, size)?;
+        }
+
+        else {
+            download::download_mirrors(uris, path, options, |current, total| {
+                progress(Status::Downloading, current, total)
+            })?;
+        }
+
+        let verified = match hash {
+            Some(hash) => {
+                progress(Status::Verifying, 0, 1)?;
+
+                let verified = hash.verify(path, driver)?;
+
+                if verified {
+                    progress(Status::Verifying, 1, 1)?;
+                }
+
+                verified
+            }
+
+            None => true
+        };
+
+        if verified {
+            if let (Some(key), false) = (cache_key, cached) {
+                if let Err(err) = cache::store(key, path) {
+                    tracing::warn!("Failed to store {:?} in the cache: {err}", path);
+                }
+            }
+
+            return Ok(());
+        }
+
+        std::fs::remove_file(path)?;
+
+        // Cached file is broken so it should be downloaded again
+        if cached {
+            if let Some(key) = cache_key {
+                cache::remove(key)?;
+            }
+
+            cached = false;
+
+            tracing::warn!("Cached file has wrong hash, downloading it again: {:?}", path);
+
+            continue;
+        }
+
+        if attempt >= options.retries {
+            anyhow::bail!("Downloaded file has wrong hash: {:?}", path);
+        }
+
+        attempt += 1;
+
+        tracing::warn!("Downloaded file has wrong hash, downloading it again: {:?}", path);
+    }
+}
+
+/// Download file into the writer and verify its hash if it's provided
+/// 
+/// Unlike `download_verified` the file can't be downloaded
+/// again because the writer has already consumed its content
+fn download_stream_verified(
+    uris: &[impl AsRef<str>],
+    writer: impl Write,
+    hash: Option<&FileHash>,
+    options: DownloadOptions,
+    progress: impl FnMut(u64, u64) -> anyhow::Result<()>
+) -> anyhow::Result<()> {
+    let mut writer = HashingWriter {
+        writer,
+        hasher: hash.and_then(|hash| hash.hash.hasher())
+    };
+
+    download::download_stream(uris, &mut writer, options, progress)?;
+
+    if let (Some(hash), Some(hasher)) = (hash, writer.hasher) {
+        if hasher.finalize() != hash.value {
+            anyhow::bail!("Downloaded file has wrong hash: {}", download::get_file_name(uris[0].as_ref()));
+        }
+    }
+
+    Ok(())
+}
+
+/// Writer which calculates hash of the written data
+struct HashingWriter<T> {
+    writer: T,
+    hasher: Option<Hasher>
+}
+
+impl<T: Write> Write for HashingWriter<T> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.writer.write(buf)?;
+
+        if let Some(hasher) = &mut self.hasher {
+            hasher.update(&buf[..written]);
+        }
+
+        Ok(written)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// Extract tar archive which content is written to the stdin of the tar process
+fn extract_stream(
+    flags: &[&str],
+    folder: impl AsRef<Path>,
+    write: impl FnOnce(&mut ChildStdin) -> anyhow::Result<()>
+) -> anyhow::Result<()> {
+    let mut child = Command::new("tar")
+        .arg("-x")
+        .args(flags)
+        .arg("-C")
+        .arg(folder.as_ref())
+        .arg("-f")
+        .arg("-")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    let Some(mut stdin) = child.stdin.take() else {
+        anyhow::bail!("Failed to open tar process input");
+    };
+
+    let result = write(&mut stdin);
+
+    // Close the input so tar knows that the archive is finished
+    drop(stdin);
+
+    let output = child.wait_with_output()?;
+
+    if !output.status.success() {
+        let message = format!("Failed to extract archive: {}", String::from_utf8_lossy(&output.stderr).trim());
+
+        return Err(match result {
+            Ok(()) => anyhow::anyhow!(message),
+            Err(err) => err.context(message)
+        });
+    }
+
+    result
+}
+
+/// Extract downloaded archive
+///
+/// Extraction is considered stalled if no files
+/// were extracted during the given timeout
+fn extract_archive(
+    archive: &Path,
+    folder: impl AsRef<Path>,
+    stall_timeout: Duration,
+    mut progress: impl FnMut(u64, u64) -> anyhow::Result<()>
+) -> anyhow::Result<()> {
+    let Some(mut updater) = archive::extract(archive, folder.as_ref()) else {
+        anyhow::bail!("Failed to extract files from the archive: {:?}", archive);
+    };
+
+    let mut current = updater.current();
+    let mut last_update = Instant::now();
+
+    while let Ok(false) = updater.status() {
+        if updater.current() != current {
+            current = updater.current();
+            last_update = Instant::now();
+        }
+
+        else if last_update.elapsed() >= stall_timeout {
+            anyhow::bail!("Archive extraction has stalled for {} seconds: {:?}", stall_timeout.as_secs(), archive);
+        }
+
+        progress(current, updater.total())?;
+
+        std::thread::sleep(download::PROGRESS_TIMEOUT);
+    }
+
+    Ok(())
+}
+
 #[derive(Debug)]
 pub struct DownloadDiffResolvedTask {
     pub updater: BasicUpdater<Status, (), anyhow::Error>,
@@ -386,7 +833,9 @@ impl ResolvedTask for DownloadDiffResolvedTask {
                 BasicStatus::Working(Status::PreparingTransition)   => TaskStatus::PreparingTransition,
                 BasicStatus::Working(Status::RunPreTransitionCode)  => TaskStatus::RunPreTransitionCode,
                 BasicStatus::Working(Status::Downloading)           => TaskStatus::Downloading,
+                BasicStatus::Working(Status::Verifying)             => TaskStatus::VerifyingFiles,
                 BasicStatus::Working(Status::Unpacking)             => TaskStatus::Unpacking,
+                BasicStatus::Working(Status::ApplyingPatches)       => TaskStatus::ApplyingPatches,
                 BasicStatus::Working(Status::RunTransitionCode)     => TaskStatus::RunTransitionCode,
                 BasicStatus::Working(Status::FinishingTransition)   => TaskStatus::FinishingTransition,
                 BasicStatus::Working(Status::RunPostTransitionCode) => TaskStatus::RunPostTransitionCode,
@@ -0,0 +1,105 @@
+use std::path::PathBuf;
+
+use anime_game_core::updater::{
+    UpdaterExt,
+    BasicUpdater,
+    Status as BasicStatus
+};
+
+use crate::tr;
+
+use crate::ui::components::game_card::CardInfo;
+
+use crate::components::wine::Wine;
+use crate::components::prefix_tools::{self, PrefixVerb};
+
+use super::{
+    QueuedTask,
+    ResolvedTask,
+    TaskStatus
+};
+
+#[derive(Debug)]
+pub struct InstallVerbsQueuedTask {
+    pub wine: Wine,
+    pub verbs: Vec<PrefixVerb>,
+    pub prefix_path: PathBuf
+}
+
+impl QueuedTask for InstallVerbsQueuedTask {
+    #[inline]
+    fn get_info(&self) -> CardInfo {
+        CardInfo::Component {
+            name: String::from("install-verbs"),
+            title: tr!("tasks-install-verbs"),
+            developer: String::new()
+        }
+    }
+
+    fn resolve(self: Box<Self>) -> anyhow::Result<Box<dyn ResolvedTask>> {
+        Ok(Box::new(InstallVerbsResolvedTask {
+            card_info: self.get_info(),
+
+            updater: BasicUpdater::spawn(move |sender| {
+                Box::new(move || -> Result<(), anyhow::Error> {
+                    let total = self.verbs.len() as u64;
+
+                    sender.send(((), 0, total))?;
+
+                    for (i, verb) in self.verbs.iter().enumerate() {
+                        prefix_tools::install_verb(&self.wine, &self.prefix_path, *verb)?;
+
+                        sender.send(((), i as u64 + 1, total))?;
+                    }
+
+                    Ok(())
+                })
+            })
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub struct InstallVerbsResolvedTask {
+    pub updater: BasicUpdater<(), (), anyhow::Error>,
+    pub card_info: CardInfo
+}
+
+impl ResolvedTask for InstallVerbsResolvedTask {
+    #[inline]
+    fn get_info(&self) -> CardInfo {
+        self.card_info.clone()
+    }
+
+    #[inline]
+    fn is_finished(&mut self) -> bool {
+        self.updater.is_finished()
+    }
+
+    #[inline]
+    fn get_current(&self) -> u64 {
+        self.updater.current()
+    }
+
+    #[inline]
+    fn get_total(&self) -> u64 {
+        self.updater.total()
+    }
+
+    #[inline]
+    fn get_progress(&self) -> f64 {
+        self.updater.progress()
+    }
+
+    fn get_status(&mut self) -> anyhow::Result<TaskStatus> {
+        match self.updater.status() {
+            Ok(status) => Ok(match status {
+                BasicStatus::Pending => TaskStatus::Pending,
+                BasicStatus::Working(()) => TaskStatus::InstallingVerbs,
+                BasicStatus::Finished => TaskStatus::Finished
+            }),
+
+            Err(err) => anyhow::bail!(err.to_string())
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::VecDeque;
+use std::collections::{VecDeque, HashSet};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::thread::JoinHandle;
@@ -27,9 +27,13 @@ use crate::utils::{
 pub mod task;
 pub mod create_prefix_task;
 pub mod apply_dxvk_task;
+pub mod apply_dlls_task;
+pub mod install_verbs_task;
+pub mod snapshot_prefix_task;
 pub mod download_diff_task;
 pub mod verify_integrity_task;
 pub mod delete_files_task;
+pub mod move_folder_task;
 
 pub use task::*;
 
@@ -58,6 +62,9 @@ pub struct TasksQueueComponent {
     pub queued_tasks_factory: FactoryVecDeque<CardFactory>,
     pub queued_tasks: VecDeque<Box<dyn QueuedTask>>,
 
+    /// Cards of the failed tasks which are not reported yet
+    pub failed_tasks: HashSet<CardInfo>,
+
     pub progress_label: gtk::Label,
     pub progress_bar: gtk::ProgressBar,
 
@@ -74,7 +81,12 @@ pub enum TasksQueueComponentInput {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TasksQueueComponentOutput {
-    TaskFinished(CardInfo),
+    /// All the queued tasks of the card are finished.
+    /// `success` is false if any of them has failed
+    TaskFinished {
+        info: CardInfo,
+        success: bool
+    },
 
     HideTasksFlap,
 
@@ -84,6 +96,19 @@ pub enum TasksQueueComponentOutput {
     }
 }
 
+impl TasksQueueComponent {
+    /// Report finished task if there are no other queued tasks of the same card
+    fn finish_task(&mut self, info: CardInfo, sender: &AsyncComponentSender<Self>) {
+        if self.queued_tasks.iter().any(|task| task.get_info() == info) {
+            return;
+        }
+
+        let success = !self.failed_tasks.remove(&info);
+
+        sender.output(TasksQueueComponentOutput::TaskFinished { info, success }).unwrap();
+    }
+}
+
 #[relm4::component(pub, async)]
 impl SimpleAsyncComponent for TasksQueueComponent {
     type Init = CardInfo;
@@ -263,6 +288,7 @@ impl SimpleAsyncComponent for TasksQueueComponent {
                 .detach(),
 
             queued_tasks: VecDeque::new(),
+            failed_tasks: HashSet::new(),
 
             progress_label: gtk::Label::new(None),
             progress_bar: gtk::ProgressBar::new(),
@@ -288,6 +314,8 @@ impl SimpleAsyncComponent for TasksQueueComponent {
         match msg {
             TasksQueueComponentInput::AddTask(task) => {
                 if self.current_task.is_none() {
+                    let info = task.get_info();
+
                     match task.resolve() {
                         Ok(task) => {
                             self.current_task_card.emit(CardComponentInput::SetInfo(task.get_info()));
@@ -301,6 +329,10 @@ impl SimpleAsyncComponent for TasksQueueComponent {
                                 title: tr!("tasks-resolve-queued-failed"),
                                 message: Some(err.to_string())
                             }).unwrap();
+
+                            self.failed_tasks.insert(info.clone());
+
+                            self.finish_task(info, &sender);
                         }
                     }
                 }
@@ -319,37 +351,36 @@ impl SimpleAsyncComponent for TasksQueueComponent {
             TasksQueueComponentInput::UpdateCurrentTask => {
                 if let Some(task) = &mut self.current_task {
                     if task.is_finished() {
+                        let info = task.get_info();
+
                         if let Err(err) = task.get_status() {
                             sender.output(TasksQueueComponentOutput::ShowToast {
                                 title: tr!("tasks-get-status-failed", {
-                                    "game-title" = task.get_info().get_title().to_string()
+                                    "game-title" = info.get_title().to_string()
                                 }),
                                 message: Some(err.to_string())
                             }).unwrap();
-                        }
-
-                        let mut is_task_queued = false;
 
-                        for queued_task in &self.queued_tasks {
-                            if queued_task.get_info() == task.get_info() {
-                                is_task_queued = true;
-
-                                break;
-                            }
+                            self.failed_tasks.insert(info.clone());
                         }
 
-                        if !is_task_queued {
-                            sender.output(TasksQueueComponentOutput::TaskFinished(task.get_info().to_owned())).unwrap();
-                        }
+                        self.finish_task(info, &sender);
 
-                        if let Some(queued_task) = self.queued_tasks.pop_front() {
+                        self.current_task = None;
+
+                        // Start the next task which can be resolved
+                        while let Some(queued_task) = self.queued_tasks.pop_front() {
                             self.queued_tasks_factory.guard().pop_front();
 
+                            let info = queued_task.get_info();
+
                             match queued_task.resolve() {
                                 Ok(task) => {
                                     self.current_task_card.emit(CardComponentInput::SetInfo(task.get_info().to_owned()));
 
                                     self.current_task = Some(task);
+
+                                    break;
                                 }
 
                                 Err(err) => {
@@ -357,13 +388,15 @@ impl SimpleAsyncComponent for TasksQueueComponent {
                                         title: tr!("tasks-resolve-queued-failed"),
                                         message: Some(err.to_string())
                                     }).unwrap();
+
+                                    self.failed_tasks.insert(info.clone());
+
+                                    self.finish_task(info, &sender);
                                 }
                             }
                         }
 
-                        else {
-                            self.current_task = None;
-
+                        if self.current_task.is_none() {
                             sender.input(TasksQueueComponentInput::StopUpdater);
                             sender.output(TasksQueueComponentOutput::HideTasksFlap).unwrap();
                         }
@@ -393,6 +426,8 @@ impl SimpleAsyncComponent for TasksQueueComponent {
                                 TaskStatus::Downloading => (false, tr!("tasks-downloading")),
                                 TaskStatus::Unpacking   => (false, tr!("tasks-unpacking")),
 
+                                TaskStatus::ApplyingPatches => (false, tr!("tasks-applying-patches")),
+
                                 TaskStatus::DeletingFiles => (true, tr!("tasks-deleting-files")),
 
                                 TaskStatus::RunPreTransitionCode  => (true, tr!("tasks-pre-transition-code")),
@@ -401,10 +436,17 @@ impl SimpleAsyncComponent for TasksQueueComponent {
 
                                 TaskStatus::CreatingPrefix  => (true, tr!("tasks-creating-prefix")),
                                 TaskStatus::InstallingDxvk  => (true, tr!("tasks-installing-dxvk")),
+                                TaskStatus::InstallingVkd3d => (true, tr!("tasks-installing-vkd3d")),
+                                TaskStatus::InstallingNvapi => (true, tr!("tasks-installing-nvapi")),
                                 TaskStatus::InstallingFonts => (false, tr!("tasks-installing-fonts")),
+                                TaskStatus::InstallingVerbs => (false, tr!("tasks-installing-verbs")),
+
+                                TaskStatus::CreatingSnapshot  => (true, tr!("tasks-creating-snapshot")),
+                                TaskStatus::RestoringSnapshot => (true, tr!("tasks-restoring-snapshot")),
 
                                 TaskStatus::VerifyingFiles => (false, tr!("tasks-verifying-files")),
                                 TaskStatus::RepairingFiles => (false, tr!("tasks-repairing-files")),
+                                TaskStatus::MovingFiles    => (false, tr!("tasks-moving-files")),
 
                                 TaskStatus::Finished => (true, tr!("tasks-finished"))
                             };
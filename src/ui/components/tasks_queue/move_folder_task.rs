@@ -0,0 +1,200 @@
+use std::path::{Path, PathBuf};
+use std::os::unix::fs::MetadataExt;
+
+use anime_game_core::updater::{
+    UpdaterExt,
+    BasicUpdater,
+    Status as BasicStatus
+};
+
+use crate::config;
+use crate::utils::get_folder_size;
+
+use crate::ui::components::game_card::CardInfo;
+
+use super::{
+    QueuedTask,
+    ResolvedTask,
+    TaskStatus
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Status {
+    MovingFiles,
+    DeletingFiles
+}
+
+#[derive(Debug, Clone)]
+pub struct MoveFolderQueuedTask {
+    pub card_info: CardInfo,
+
+    pub from: PathBuf,
+    pub to: PathBuf,
+
+    /// Config property which stores the folder path
+    pub property: String
+}
+
+impl QueuedTask for MoveFolderQueuedTask {
+    #[inline]
+    fn get_info(&self) -> CardInfo {
+        self.card_info.clone()
+    }
+
+    fn resolve(self: Box<Self>) -> anyhow::Result<Box<dyn ResolvedTask>> {
+        let from = self.from.clone();
+        let to = self.to.clone();
+        let property = self.property.clone();
+
+        Ok(Box::new(MoveFolderResolvedTask {
+            card_info: self.card_info.clone(),
+
+            updater: BasicUpdater::spawn(move |sender| {
+                Box::new(move || -> Result<(), anyhow::Error> {
+                    sender.send((Status::MovingFiles, 0, 1))?;
+
+                    if to.starts_with(&from) {
+                        anyhow::bail!("Can't move folder {:?} into itself: {:?}", from, to);
+                    }
+
+                    if to.exists() && to.read_dir()?.next().is_some() {
+                        anyhow::bail!("Destination folder is not empty: {:?}", to);
+                    }
+
+                    // Nothing to move, just use the new path
+                    if !from.exists() {
+                        config::set(property, to.to_string_lossy().to_string())?;
+
+                        sender.send((Status::MovingFiles, 1, 1))?;
+
+                        return Ok(());
+                    }
+
+                    let to_parent = match to.parent() {
+                        Some(parent) => parent.to_path_buf(),
+                        None => anyhow::bail!("Failed to get parent folder of {:?}", to)
+                    };
+
+                    std::fs::create_dir_all(&to_parent)?;
+
+                    // Folders on the same filesystem can be renamed without copying
+                    if from.metadata()?.dev() == to_parent.metadata()?.dev() {
+                        std::fs::rename(&from, &to)?;
+
+                        config::set(property, to.to_string_lossy().to_string())?;
+
+                        sender.send((Status::MovingFiles, 1, 1))?;
+
+                        return Ok(());
+                    }
+
+                    // Copy files to another filesystem
+
+                    let total = get_folder_size(&from)?;
+                    let mut copied = 0;
+
+                    let result = copy_folder(&from, &to, &mut |size| {
+                        copied += size;
+
+                        Ok(sender.send((Status::MovingFiles, copied, total))?)
+                    });
+
+                    // Old files are kept if copying has failed
+                    if let Err(err) = result {
+                        if let Err(err) = std::fs::remove_dir_all(&to) {
+                            tracing::error!("Failed to delete partially copied folder {:?}: {err}", to);
+                        }
+
+                        return Err(err);
+                    }
+
+                    config::set(property, to.to_string_lossy().to_string())?;
+
+                    // Delete old files
+
+                    sender.send((Status::DeletingFiles, 0, 1))?;
+
+                    std::fs::remove_dir_all(&from)?;
+
+                    sender.send((Status::DeletingFiles, 1, 1))?;
+
+                    Ok(())
+                })
+            })
+        }))
+    }
+}
+
+/// Recursively copy folder keeping symlinks
+///
+/// `progress` is called with size of every copied file
+fn copy_folder(from: &Path, to: &Path, progress: &mut impl FnMut(u64) -> anyhow::Result<()>) -> anyhow::Result<()> {
+    std::fs::create_dir_all(to)?;
+
+    for entry in from.read_dir()?.flatten() {
+        let file_type = entry.file_type()?;
+        let target = to.join(entry.file_name());
+
+        if file_type.is_dir() {
+            copy_folder(&entry.path(), &target, progress)?;
+        }
+
+        else if file_type.is_symlink() {
+            std::os::unix::fs::symlink(std::fs::read_link(entry.path())?, target)?;
+        }
+
+        else {
+            progress(std::fs::copy(entry.path(), target)?)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Debug)]
+pub struct MoveFolderResolvedTask {
+    pub updater: BasicUpdater<Status, (), anyhow::Error>,
+    pub card_info: CardInfo
+}
+
+impl ResolvedTask for MoveFolderResolvedTask {
+    #[inline]
+    fn get_info(&self) -> CardInfo {
+        self.card_info.clone()
+    }
+
+    #[inline]
+    fn is_finished(&mut self) -> bool {
+        self.updater.is_finished()
+    }
+
+    #[inline]
+    fn get_current(&self) -> u64 {
+        self.updater.current()
+    }
+
+    #[inline]
+    fn get_total(&self) -> u64 {
+        self.updater.total()
+    }
+
+    #[inline]
+    fn get_progress(&self) -> f64 {
+        self.updater.progress()
+    }
+
+    fn get_status(&mut self) -> anyhow::Result<TaskStatus> {
+        match self.updater.status() {
+            Ok(status) => Ok(match status {
+                BasicStatus::Pending => TaskStatus::Pending,
+
+                BasicStatus::Working(Status::MovingFiles)   => TaskStatus::MovingFiles,
+                BasicStatus::Working(Status::DeletingFiles) => TaskStatus::DeletingFiles,
+
+                BasicStatus::Finished => TaskStatus::Finished
+            }),
+
+            Err(err) => anyhow::bail!(err.to_string())
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use std::path::PathBuf;
+
+use anime_game_core::updater::{
+    UpdaterExt,
+    BasicUpdater,
+    Status as BasicStatus
+};
+
+use crate::tr;
+
+use crate::config;
+
+use crate::ui::components::game_card::CardInfo;
+
+use crate::components::prefix_snapshots::{self, Snapshot};
+
+use super::{
+    QueuedTask,
+    ResolvedTask,
+    TaskStatus
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SnapshotAction {
+    /// Create new snapshot and delete the oldest ones
+    Create,
+
+    /// Replace prefix with the given snapshot
+    Restore(Snapshot)
+}
+
+#[derive(Debug)]
+pub struct SnapshotPrefixQueuedTask {
+    pub prefix_path: PathBuf,
+    pub action: SnapshotAction
+}
+
+impl QueuedTask for SnapshotPrefixQueuedTask {
+    #[inline]
+    fn get_info(&self) -> CardInfo {
+        CardInfo::Component {
+            name: String::from("prefix-snapshot"),
+            title: tr!("tasks-prefix-snapshot"),
+            developer: String::new()
+        }
+    }
+
+    fn resolve(self: Box<Self>) -> anyhow::Result<Box<dyn ResolvedTask>> {
+        let snapshots = config::get().components.wine.prefix.snapshots;
+
+        Ok(Box::new(SnapshotPrefixResolvedTask {
+            card_info: self.get_info(),
+            restoring: matches!(self.action, SnapshotAction::Restore(_)),
+
+            updater: BasicUpdater::spawn(move |sender| {
+                Box::new(move || -> Result<(), anyhow::Error> {
+                    sender.send(((), 0, 1))?;
+
+                    match &self.action {
+                        // Prefix could be deleted by the previous task
+                        SnapshotAction::Create if !self.prefix_path.exists() => (),
+
+                        SnapshotAction::Create => {
+                            prefix_snapshots::create(&self.prefix_path, snapshots.mode)?;
+                            prefix_snapshots::cleanup(&self.prefix_path, snapshots.keep)?;
+                        }
+
+                        SnapshotAction::Restore(snapshot) => {
+                            prefix_snapshots::restore(snapshot, &self.prefix_path)?;
+                        }
+                    }
+
+                    sender.send(((), 1, 1))?;
+
+                    Ok(())
+                })
+            })
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub struct SnapshotPrefixResolvedTask {
+    pub updater: BasicUpdater<(), (), anyhow::Error>,
+    pub card_info: CardInfo,
+    pub restoring: bool
+}
+
+impl ResolvedTask for SnapshotPrefixResolvedTask {
+    #[inline]
+    fn get_info(&self) -> CardInfo {
+        self.card_info.clone()
+    }
+
+    #[inline]
+    fn is_finished(&mut self) -> bool {
+        self.updater.is_finished()
+    }
+
+    #[inline]
+    fn get_current(&self) -> u64 {
+        self.updater.current()
+    }
+
+    #[inline]
+    fn get_total(&self) -> u64 {
+        self.updater.total()
+    }
+
+    #[inline]
+    fn get_progress(&self) -> f64 {
+        self.updater.progress()
+    }
+
+    fn get_status(&mut self) -> anyhow::Result<TaskStatus> {
+        match self.updater.status() {
+            Ok(status) => Ok(match status {
+                BasicStatus::Pending => TaskStatus::Pending,
+
+                BasicStatus::Working(()) if self.restoring => TaskStatus::RestoringSnapshot,
+                BasicStatus::Working(()) => TaskStatus::CreatingSnapshot,
+
+                BasicStatus::Finished => TaskStatus::Finished
+            }),
+
+            Err(err) => anyhow::bail!(err.to_string())
+        }
+    }
+}
@@ -8,14 +8,21 @@ pub enum TaskStatus {
     RunPreTransitionCode,
     Downloading,
     Unpacking,
+    ApplyingPatches,
     RunTransitionCode,
     FinishingTransition,
     RunPostTransitionCode,
     CreatingPrefix,
     InstallingDxvk,
+    InstallingVkd3d,
+    InstallingNvapi,
     InstallingFonts,
+    InstallingVerbs,
+    CreatingSnapshot,
+    RestoringSnapshot,
     VerifyingFiles,
     RepairingFiles,
+    MovingFiles,
     DeletingFiles,
     Finished
 }
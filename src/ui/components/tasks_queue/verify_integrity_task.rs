@@ -13,14 +13,15 @@ use anime_game_core::updater::{
     Status as BasicStatus
 };
 
-use anime_game_core::network::downloader::{
-    DownloaderExt,
-    basic::Downloader
-};
-
 use crate::ui::components::game_card::CardInfo;
 
+use crate::network::download::{
+    self,
+    DownloadOptions
+};
+
 use crate::games;
+use crate::games::metadata::LauncherMetadata;
 use crate::games::integrations::standards::prelude::*;
 
 use crate::config;
@@ -71,9 +72,6 @@ impl QueuedTask for VerifyIntegrityQueuedTask {
                         games::get_unsafe(&game_name)
                     };
 
-                    // Check if lua script support custom hashes
-                    let has_integrity_hash = game.driver.has_integrity_hash()?;
-
                     // Create transition
 
                     sender.send((Status::PreparingTransition, 0, 1))?;
@@ -84,6 +82,8 @@ impl QueuedTask for VerifyIntegrityQueuedTask {
                         config.general.transitions.path
                     )?;
 
+                    LauncherMetadata::add_transition(&game_name, &game_edition, transition.transition_path())?;
+
                     sender.send((Status::PreparingTransition, 1, 1))?;
 
                     // Verify files
@@ -131,42 +131,8 @@ impl QueuedTask for VerifyIntegrityQueuedTask {
 
                             // Otherwise verifying the file is a heavy task so we put it to the threads pool
                             tasks.push(pool.evaluate(move || -> anyhow::Result<Option<DiffFileDownload>> {
-                                // Read existing file
-                                let data = std::fs::read(&integrity_file)?;
-
                                 // Get existing file hash
-                                let hash = match info.hash {
-                                    HashType::Md5 => {
-                                        use md5::{Md5, Digest};
-
-                                        format!("{:x}", Md5::digest(&data))
-                                    },
-
-                                    HashType::Sha1 => {
-                                        use sha1::{Sha1, Digest};
-
-                                        format!("{:x}", Sha1::digest(&data))
-                                    },
-
-                                    HashType::Crc32 => {
-                                        let mut hasher = crc32fast::Hasher::new();
-
-                                        hasher.update(&data);
-
-                                        hasher.finalize().to_string()
-                                    }
-
-                                    HashType::Xxhash32    => format!("{:x}", xxhash_rust::xxh32::xxh32(&data, 0)),
-                                    HashType::Xxhash64    => format!("{:x}", xxhash_rust::xxh64::xxh64(&data, 0)),
-                                    HashType::Xxhash3_64  => format!("{:x}", xxhash_rust::xxh3::xxh3_64(&data)),
-                                    HashType::Xxhash3_128 => format!("{:x}", xxhash_rust::xxh3::xxh3_128(&data)),
-
-                                    HashType::Custom(name) if has_integrity_hash => {
-                                        game.driver.integrity_hash(&name, data)?
-                                    }
-
-                                    _ => unimplemented!()
-                                };
+                                let hash = info.hash.hash_file(&integrity_file, &game.driver)?;
 
                                 sender.send((
                                     Status::VerifyingFiles,
@@ -201,6 +167,9 @@ impl QueuedTask for VerifyIntegrityQueuedTask {
 
                     let mut tasks = Vec::with_capacity(queue_size);
 
+                    let download_options = DownloadOptions::from(&config.general.downloads)
+                        .with_resume(false);
+
                     let total = broken_files.len() as u64;
                     let current = Arc::new(AtomicU64::new(0));
 
@@ -228,10 +197,7 @@ impl QueuedTask for VerifyIntegrityQueuedTask {
                                 }
 
                                 // Download the file
-                                Downloader::new(file.uri)
-                                    .continue_downloading(false)
-                                    .download(file_path)?
-                                    .wait()?;
+                                download::download_mirrors(&file.get_uris(), file_path, download_options, |_, _| Ok(()))?;
 
                                 sender.send((
                                     Status::RepairingFiles,
@@ -261,6 +227,8 @@ impl QueuedTask for VerifyIntegrityQueuedTask {
 
                     transition.finish()?;
 
+                    LauncherMetadata::remove_transition(&game_name, &game_edition, transition.transition_path())?;
+
                     sender.send((Status::FinishingTransition, 1, 1))?;
 
                     Ok(())
@@ -0,0 +1,345 @@
+use relm4::prelude::*;
+
+use gtk::prelude::*;
+use adw::prelude::*;
+
+use crate::tr;
+
+use crate::config;
+
+use crate::utils::{pretty_bytes, get_folder_size};
+
+use crate::components::wine::{Wine, WineSource, is_proton};
+use crate::components::dxvk::Dxvk;
+use crate::components::channel::ChannelVersions;
+
+use super::main::MainAppMsg;
+
+pub static mut WINDOW: Option<adw::Window> = None;
+
+#[derive(Debug)]
+pub struct ComponentsManagerApp {
+    pub wine_group: adw::PreferencesGroup,
+    pub dxvk_group: adw::PreferencesGroup,
+
+    pub wine_rows: Vec<adw::ActionRow>,
+    pub dxvk_rows: Vec<adw::ActionRow>
+}
+
+#[derive(Debug, Clone)]
+pub enum ComponentsManagerAppMsg {
+    Refresh,
+
+    DownloadWine(Wine),
+    DeleteWine(Wine),
+    SelectWine(Wine),
+
+    DownloadDxvk(Dxvk),
+    DeleteDxvk(Dxvk),
+    SelectDxvk(Dxvk)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Actions available for a component version row
+enum ComponentAction {
+    Download,
+    Delete,
+    Select
+}
+
+#[relm4::component(pub, async)]
+impl SimpleAsyncComponent for ComponentsManagerApp {
+    type Init = adw::Window;
+    type Input = ComponentsManagerAppMsg;
+    type Output = MainAppMsg;
+
+    view! {
+        window = adw::Window {
+            set_default_size: (700, 560),
+            set_title: Some(&tr!("components-manager")),
+
+            set_hide_on_close: true,
+            set_modal: true,
+
+            add_css_class?: crate::APP_DEBUG.then_some("devel"),
+
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+
+                adw::HeaderBar {
+                    add_css_class: "flat",
+
+                    pack_start = &gtk::Button {
+                        set_icon_name: "view-refresh-symbolic",
+
+                        connect_clicked => ComponentsManagerAppMsg::Refresh
+                    }
+                },
+
+                adw::PreferencesPage {
+                    #[local_ref]
+                    add = wine_group -> adw::PreferencesGroup {
+                        set_title: &tr!("components-wine"),
+                        set_description: Some(&tr!("components-manager-description"))
+                    },
+
+                    #[local_ref]
+                    add = dxvk_group -> adw::PreferencesGroup {
+                        set_title: &tr!("components-dxvk")
+                    }
+                }
+            }
+        }
+    }
+
+    async fn init(parent: Self::Init, root: Self::Root, _sender: AsyncComponentSender<Self>) -> AsyncComponentParts<Self> {
+        let model = Self {
+            wine_group: adw::PreferencesGroup::new(),
+            dxvk_group: adw::PreferencesGroup::new(),
+
+            wine_rows: Vec::new(),
+            dxvk_rows: Vec::new()
+        };
+
+        let wine_group = &model.wine_group;
+        let dxvk_group = &model.dxvk_group;
+
+        let widgets = view_output!();
+
+        widgets.window.set_transient_for(Some(&parent));
+
+        unsafe {
+            WINDOW = Some(widgets.window.clone());
+        }
+
+        AsyncComponentParts { model, widgets }
+    }
+
+    async fn update(&mut self, msg: Self::Input, sender: AsyncComponentSender<Self>) {
+        match msg {
+            ComponentsManagerAppMsg::Refresh => {
+                for row in self.wine_rows.drain(..) {
+                    self.wine_group.remove(&row);
+                }
+
+                for row in self.dxvk_rows.drain(..) {
+                    self.dxvk_group.remove(&row);
+                }
+
+                let wine_versions = match Wine::channel_versions() {
+                    Ok(versions) => report_skipped(&sender, versions),
+                    Err(err) => {
+                        sender.output(MainAppMsg::ShowToast {
+                            title: tr!("components-manager-versions-failed"),
+                            message: Some(err.to_string())
+                        }).unwrap();
+
+                        Vec::new()
+                    }
+                };
+
+                let dxvk_versions = match Dxvk::channel_versions() {
+                    Ok(versions) => report_skipped(&sender, versions),
+                    Err(err) => {
+                        sender.output(MainAppMsg::ShowToast {
+                            title: tr!("components-manager-versions-failed"),
+                            message: Some(err.to_string())
+                        }).unwrap();
+
+                        Vec::new()
+                    }
+                };
+
+                let selected_wine = Wine::from_config().ok();
+                let selected_dxvk = Dxvk::from_config().ok();
+
+                for version in Wine::with_downloaded(wine_versions.into_iter().chain(Wine::system_versions())) {
+                    let selected = selected_wine.as_ref() == Some(&version);
+
+                    let (subtitle, actions) = match &version.source {
+                        WineSource::System { .. } | WineSource::Proton { .. } => (
+                            tr!("components-manager-system"),
+                            &[ComponentAction::Select][..]
+                        ),
+
+                        WineSource::Component if version.is_downloaded() => (
+                            tr!("components-manager-downloaded", {
+                                "size" = pretty_bytes(get_folder_size(version.get_folder()).unwrap_or_default())
+                            }),
+                            &[ComponentAction::Select, ComponentAction::Delete][..]
+                        ),
+
+                        WineSource::Component => (
+                            tr!("components-manager-not-downloaded"),
+                            &[ComponentAction::Download][..]
+                        )
+                    };
+
+                    let row = build_row(&version.title, &subtitle, selected, actions, {
+                        let sender = sender.clone();
+
+                        move |action| sender.input(match action {
+                            ComponentAction::Download => ComponentsManagerAppMsg::DownloadWine(version.clone()),
+                            ComponentAction::Delete   => ComponentsManagerAppMsg::DeleteWine(version.clone()),
+                            ComponentAction::Select   => ComponentsManagerAppMsg::SelectWine(version.clone())
+                        })
+                    });
+
+                    self.wine_group.add(&row);
+                    self.wine_rows.push(row);
+                }
+
+                for version in Dxvk::with_downloaded(dxvk_versions) {
+                    let selected = selected_dxvk.as_ref() == Some(&version);
+
+                    let (subtitle, actions) = if version.is_downloaded() {
+                        (
+                            tr!("components-manager-downloaded", {
+                                "size" = pretty_bytes(get_folder_size(version.get_folder()).unwrap_or_default())
+                            }),
+                            &[ComponentAction::Select, ComponentAction::Delete][..]
+                        )
+                    } else {
+                        (tr!("components-manager-not-downloaded"), &[ComponentAction::Download][..])
+                    };
+
+                    let row = build_row(&version.name, &subtitle, selected, actions, {
+                        let sender = sender.clone();
+
+                        move |action| sender.input(match action {
+                            ComponentAction::Download => ComponentsManagerAppMsg::DownloadDxvk(version.clone()),
+                            ComponentAction::Delete   => ComponentsManagerAppMsg::DeleteDxvk(version.clone()),
+                            ComponentAction::Select   => ComponentsManagerAppMsg::SelectDxvk(version.clone())
+                        })
+                    });
+
+                    self.dxvk_group.add(&row);
+                    self.dxvk_rows.push(row);
+                }
+            }
+
+            ComponentsManagerAppMsg::DownloadWine(version) => {
+                sender.output(MainAppMsg::AddDownloadWineTask(version)).unwrap();
+                sender.output(MainAppMsg::ShowTasksFlap).unwrap();
+            }
+
+            ComponentsManagerAppMsg::DeleteWine(version) => {
+                sender.output(MainAppMsg::AddDeleteFilesTask(vec![version.get_folder()])).unwrap();
+                sender.output(MainAppMsg::ShowTasksFlap).unwrap();
+            }
+
+            ComponentsManagerAppMsg::SelectWine(version) => {
+                if let Err(err) = config::set("components.wine.version", version.name) {
+                    sender.output(MainAppMsg::ShowToast {
+                        title: tr!("config-property-update-failed"),
+                        message: Some(err.to_string())
+                    }).unwrap();
+                }
+
+                sender.input(ComponentsManagerAppMsg::Refresh);
+            }
+
+            ComponentsManagerAppMsg::DownloadDxvk(version) => {
+                sender.output(MainAppMsg::AddDownloadDxvkTask(version)).unwrap();
+                sender.output(MainAppMsg::ShowTasksFlap).unwrap();
+            }
+
+            ComponentsManagerAppMsg::DeleteDxvk(version) => {
+                sender.output(MainAppMsg::AddDeleteFilesTask(vec![version.get_folder()])).unwrap();
+                sender.output(MainAppMsg::ShowTasksFlap).unwrap();
+            }
+
+            ComponentsManagerAppMsg::SelectDxvk(version) => {
+                if let Err(err) = config::set("components.dxvk.version", version.name.clone()) {
+                    sender.output(MainAppMsg::ShowToast {
+                        title: tr!("config-property-update-failed"),
+                        message: Some(err.to_string())
+                    }).unwrap();
+
+                    return;
+                }
+
+                let wine = config::get().components.wine;
+
+                // Otherwise DXVK will be installed during prefix creation.
+                // Proton uses its own DXVK build
+                if wine.prefix.path.exists() && !is_proton(&wine.version) {
+                    sender.output(MainAppMsg::AddApplyDxvkTask {
+                        dxvk: version,
+                        prefix: wine.prefix.path
+                    }).unwrap();
+
+                    sender.output(MainAppMsg::ShowTasksFlap).unwrap();
+                }
+
+                sender.input(ComponentsManagerAppMsg::Refresh);
+            }
+        }
+    }
+}
+
+/// Notify user about malformed channel manifest entries
+/// and return parsed versions
+fn report_skipped<T>(sender: &AsyncComponentSender<ComponentsManagerApp>, versions: ChannelVersions<T>) -> Vec<T> {
+    if !versions.skipped.is_empty() {
+        sender.output(MainAppMsg::ShowToast {
+            title: tr!("components-manager-skipped-entries", {
+                "count" = versions.skipped.len()
+            }),
+            message: Some(versions.skipped.join("\n"))
+        }).unwrap();
+    }
+
+    versions.versions
+}
+
+/// Build component version row
+///
+/// Selected version can't be deleted because it would be
+/// downloaded again on the next launcher start
+fn build_row(title: &str, subtitle: &str, selected: bool, actions: &[ComponentAction], callback: impl Fn(ComponentAction) + Clone + 'static) -> adw::ActionRow {
+    let row = adw::ActionRow::new();
+
+    row.set_title(title);
+    row.set_subtitle(subtitle);
+
+    if selected {
+        let icon = gtk::Image::from_icon_name("object-select-symbolic");
+
+        icon.set_tooltip_text(Some(&tr!("components-manager-selected")));
+
+        row.add_suffix(&icon);
+    }
+
+    for action in actions {
+        if selected && *action != ComponentAction::Download {
+            continue;
+        }
+
+        let (icon, tooltip) = match action {
+            ComponentAction::Download => ("folder-download-symbolic", tr!("components-manager-download")),
+            ComponentAction::Delete   => ("user-trash-symbolic", tr!("components-manager-delete")),
+            ComponentAction::Select   => ("object-select-symbolic", tr!("components-manager-select"))
+        };
+
+        let button = gtk::Button::new();
+
+        button.set_icon_name(icon);
+        button.set_tooltip_text(Some(&tooltip));
+        button.set_valign(gtk::Align::Center);
+        button.add_css_class("flat");
+
+        if *action == ComponentAction::Delete {
+            button.add_css_class("error");
+        }
+
+        let callback = callback.clone();
+        let action = *action;
+
+        button.connect_clicked(move |_| callback(action));
+
+        row.add_suffix(&button);
+    }
+
+    row
+}
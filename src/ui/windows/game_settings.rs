@@ -0,0 +1,929 @@
+use relm4::prelude::*;
+
+use gtk::prelude::*;
+use adw::prelude::*;
+
+use crate::tr;
+
+use crate::config;
+use crate::games;
+
+use crate::components::wine::Wine;
+use crate::components::dxvk::Dxvk;
+
+use crate::config::games::settings::prelude::*;
+use crate::config::games::wine::prelude::*;
+use crate::config::games::enhancements::prelude::*;
+use crate::config::games::launch::{self, LaunchWrapper};
+
+use crate::ui::components::game_card::CardInfo;
+
+use super::main::MainAppMsg;
+
+pub static mut WINDOW: Option<adw::Window> = None;
+
+#[derive(Debug)]
+pub struct GameSettingsApp {
+    pub environment_group: adw::PreferencesGroup,
+    pub environment_rows: Vec<adw::ActionRow>,
+
+    pub dll_overrides_group: adw::PreferencesGroup,
+    pub dll_overrides_rows: Vec<adw::ActionRow>,
+
+    pub registry_group: adw::PreferencesGroup,
+    pub registry_rows: Vec<adw::ActionRow>,
+
+    pub launch_group: adw::PreferencesGroup,
+    pub wrappers_rows: Vec<adw::ActionRow>,
+
+    pub wine_versions: Vec<Wine>,
+    pub dxvk_versions: Vec<Dxvk>,
+
+    pub game_info: CardInfo,
+
+    pub paths: Option<GameEditionPaths>,
+    pub overrides: GameEditionOverrides
+}
+
+#[derive(Debug, Clone)]
+pub enum GameSettingsAppMsg {
+    SetGameInfo(CardInfo),
+
+    SetSeparatePrefix(bool),
+    OpenPrefixManager,
+
+    SelectWineVersion(u32),
+    SelectDxvkVersion(u32),
+    SelectSync(u32),
+    SelectVirtualDesktop(u32),
+    SelectHud(u32),
+    SelectFsr(u32),
+    SelectGamescope(u32),
+    SelectGamemode(u32),
+
+    AddEnvironment(String),
+    RemoveEnvironment(String),
+
+    AddDllOverride(String),
+    RemoveDllOverride(String),
+
+    AddRegistryValue(String),
+    RemoveRegistryValue(usize),
+
+    SetLaunchTemplate(String),
+    AddWrapper(String),
+    RemoveWrapper(usize)
+}
+
+/// Get combo row index of the toggleable option
+///
+/// `0` is the global value, `1` is enabled and `2` is disabled
+#[inline]
+fn toggle_index(value: Option<bool>) -> u32 {
+    match value {
+        None => 0,
+        Some(true) => 1,
+        Some(false) => 2
+    }
+}
+
+#[relm4::component(pub, async)]
+impl SimpleAsyncComponent for GameSettingsApp {
+    type Init = adw::Window;
+    type Input = GameSettingsAppMsg;
+    type Output = MainAppMsg;
+
+    view! {
+        window = adw::Window {
+            set_default_size: (700, 560),
+
+            #[watch]
+            set_title: Some(&tr!("game-settings", {
+                "game-title" = model.game_info.get_title()
+            })),
+
+            set_hide_on_close: true,
+            set_modal: true,
+
+            add_css_class?: crate::APP_DEBUG.then_some("devel"),
+
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+
+                adw::HeaderBar {
+                    add_css_class: "flat"
+                },
+
+                adw::PreferencesPage {
+                    add = &adw::PreferencesGroup {
+                        set_title: &tr!("preferences--wine"),
+                        set_description: Some(&tr!("game-settings-description")),
+
+                        adw::SwitchRow {
+                            set_title: &tr!("game-settings-separate-prefix"),
+                            set_subtitle: &tr!("game-settings-separate-prefix-description"),
+
+                            #[watch]
+                            #[block_signal(prefix_handler)]
+                            set_active: matches!(&model.paths, Some(GameEditionPaths { prefix: Some(_), .. })),
+
+                            connect_active_notify[sender] => move |switch| {
+                                sender.input(GameSettingsAppMsg::SetSeparatePrefix(switch.is_active()));
+                            } @prefix_handler
+                        },
+
+                        adw::ActionRow {
+                            set_title: &tr!("game-settings-manage-prefix"),
+                            set_subtitle: &tr!("game-settings-manage-prefix-description"),
+
+                            set_activatable: true,
+
+                            add_suffix = &gtk::Image {
+                                set_icon_name: Some("go-next-symbolic")
+                            },
+
+                            connect_activated => GameSettingsAppMsg::OpenPrefixManager
+                        },
+
+                        adw::ComboRow {
+                            set_title: &tr!("components-wine"),
+
+                            set_model: Some(&{
+                                let strings = gtk::StringList::new(&[&tr!("game-settings-default")]);
+
+                                for version in &model.wine_versions {
+                                    strings.append(&version.title);
+                                }
+
+                                strings
+                            }),
+
+                            #[watch]
+                            #[block_signal(wine_handler)]
+                            set_selected: model.overrides.wine.as_ref()
+                                .and_then(|wine| model.wine_versions.iter().position(|version| version.name == wine.version))
+                                .map(|index| index as u32 + 1)
+                                .unwrap_or(0),
+
+                            connect_selected_notify[sender] => move |row| {
+                                sender.input(GameSettingsAppMsg::SelectWineVersion(row.selected()));
+                            } @wine_handler
+                        },
+
+                        adw::ComboRow {
+                            set_title: &tr!("components-dxvk"),
+                            set_subtitle: &tr!("game-settings-dxvk-description"),
+
+                            set_model: Some(&{
+                                let strings = gtk::StringList::new(&[&tr!("game-settings-default")]);
+
+                                for version in &model.dxvk_versions {
+                                    strings.append(&version.name);
+                                }
+
+                                strings
+                            }),
+
+                            #[watch]
+                            set_sensitive: matches!(&model.paths, Some(GameEditionPaths { prefix: Some(_), .. })),
+
+                            #[watch]
+                            #[block_signal(dxvk_handler)]
+                            set_selected: model.overrides.dxvk.as_ref()
+                                .and_then(|dxvk| model.dxvk_versions.iter().position(|version| version.name == dxvk.version))
+                                .map(|index| index as u32 + 1)
+                                .unwrap_or(0),
+
+                            connect_selected_notify[sender] => move |row| {
+                                sender.input(GameSettingsAppMsg::SelectDxvkVersion(row.selected()));
+                            } @dxvk_handler
+                        },
+
+                        adw::ComboRow {
+                            set_title: &tr!("wine-sync"),
+
+                            set_model: Some(&gtk::StringList::new(&[
+                                &tr!("game-settings-default"),
+                                "None",
+                                "ESync",
+                                "FSync"
+                            ])),
+
+                            #[watch]
+                            #[block_signal(sync_handler)]
+                            set_selected: match model.overrides.sync {
+                                None => 0,
+
+                                Some(WineSync::None)  => 1,
+                                Some(WineSync::ESync) => 2,
+                                Some(WineSync::FSync) => 3
+                            },
+
+                            connect_selected_notify[sender] => move |row| {
+                                sender.input(GameSettingsAppMsg::SelectSync(row.selected()));
+                            } @sync_handler
+                        },
+
+                        adw::ComboRow {
+                            set_title: &tr!("wine-virtual-desktop"),
+
+                            set_model: Some(&gtk::StringList::new(&[
+                                &tr!("game-settings-default"),
+                                &tr!("game-settings-enabled"),
+                                &tr!("game-settings-disabled")
+                            ])),
+
+                            #[watch]
+                            #[block_signal(virtual_desktop_handler)]
+                            set_selected: toggle_index(model.overrides.virtual_desktop.map(|desktop| desktop.enabled)),
+
+                            connect_selected_notify[sender] => move |row| {
+                                sender.input(GameSettingsAppMsg::SelectVirtualDesktop(row.selected()));
+                            } @virtual_desktop_handler
+                        }
+                    },
+
+                    add = &adw::PreferencesGroup {
+                        set_title: &tr!("preferences--gaming"),
+
+                        adw::ComboRow {
+                            set_title: &tr!("game-hud"),
+
+                            set_model: Some(&gtk::StringList::new(&[
+                                &tr!("game-settings-default"),
+                                "None",
+                                "DXVK",
+                                "MangoHUD"
+                            ])),
+
+                            #[watch]
+                            #[block_signal(hud_handler)]
+                            set_selected: match model.overrides.hud {
+                                None => 0,
+
+                                Some(HUD::None)     => 1,
+                                Some(HUD::DXVK)     => 2,
+                                Some(HUD::MangoHUD) => 3
+                            },
+
+                            connect_selected_notify[sender] => move |row| {
+                                sender.input(GameSettingsAppMsg::SelectHud(row.selected()));
+                            } @hud_handler
+                        },
+
+                        adw::ComboRow {
+                            set_title: &tr!("game-fsr"),
+
+                            set_model: Some(&gtk::StringList::new(&[
+                                &tr!("game-settings-default"),
+                                &tr!("game-settings-enabled"),
+                                &tr!("game-settings-disabled")
+                            ])),
+
+                            #[watch]
+                            #[block_signal(fsr_handler)]
+                            set_selected: toggle_index(model.overrides.fsr.map(|fsr| fsr.enabled)),
+
+                            connect_selected_notify[sender] => move |row| {
+                                sender.input(GameSettingsAppMsg::SelectFsr(row.selected()));
+                            } @fsr_handler
+                        },
+
+                        adw::ComboRow {
+                            set_title: &tr!("game-gamescope"),
+
+                            set_model: Some(&gtk::StringList::new(&[
+                                &tr!("game-settings-default"),
+                                &tr!("game-settings-enabled"),
+                                &tr!("game-settings-disabled")
+                            ])),
+
+                            #[watch]
+                            #[block_signal(gamescope_handler)]
+                            set_selected: toggle_index(model.overrides.gamescope.map(|gamescope| gamescope.enabled)),
+
+                            connect_selected_notify[sender] => move |row| {
+                                sender.input(GameSettingsAppMsg::SelectGamescope(row.selected()));
+                            } @gamescope_handler
+                        },
+
+                        adw::ComboRow {
+                            set_title: &tr!("game-gamemode"),
+
+                            set_model: Some(&gtk::StringList::new(&[
+                                &tr!("game-settings-default"),
+                                &tr!("game-settings-enabled"),
+                                &tr!("game-settings-disabled")
+                            ])),
+
+                            #[watch]
+                            #[block_signal(gamemode_handler)]
+                            set_selected: toggle_index(model.overrides.gamemode),
+
+                            connect_selected_notify[sender] => move |row| {
+                                sender.input(GameSettingsAppMsg::SelectGamemode(row.selected()));
+                            } @gamemode_handler
+                        }
+                    },
+
+                    #[local_ref]
+                    add = environment_group -> adw::PreferencesGroup {
+                        set_title: &tr!("game-settings-environment"),
+                        set_description: Some(&tr!("game-settings-environment-description")),
+
+                        adw::EntryRow {
+                            set_title: &tr!("game-settings-environment-add"),
+                            set_show_apply_button: true,
+
+                            connect_apply[sender] => move |row| {
+                                sender.input(GameSettingsAppMsg::AddEnvironment(row.text().to_string()));
+
+                                row.set_text("");
+                            }
+                        }
+                    },
+
+                    #[local_ref]
+                    add = launch_group -> adw::PreferencesGroup {
+                        set_title: &tr!("game-settings-launch"),
+                        set_description: Some(&tr!("game-settings-launch-description")),
+
+                        adw::EntryRow {
+                            set_title: &tr!("game-settings-launch-template"),
+                            set_show_apply_button: true,
+
+                            #[watch]
+                            set_text: model.overrides.launch_template.as_deref().unwrap_or_default(),
+
+                            connect_apply[sender] => move |row| {
+                                sender.input(GameSettingsAppMsg::SetLaunchTemplate(row.text().to_string()));
+                            }
+                        },
+
+                        adw::EntryRow {
+                            set_title: &tr!("launch-wrapper-add"),
+                            set_show_apply_button: true,
+
+                            connect_apply[sender] => move |row| {
+                                sender.input(GameSettingsAppMsg::AddWrapper(row.text().to_string()));
+
+                                row.set_text("");
+                            }
+                        }
+                    },
+
+                    #[local_ref]
+                    add = dll_overrides_group -> adw::PreferencesGroup {
+                        set_title: &tr!("game-settings-dll-overrides"),
+                        set_description: Some(&tr!("game-settings-dll-overrides-description")),
+
+                        adw::EntryRow {
+                            set_title: &tr!("game-settings-dll-overrides-add"),
+                            set_show_apply_button: true,
+
+                            connect_apply[sender] => move |row| {
+                                sender.input(GameSettingsAppMsg::AddDllOverride(row.text().to_string()));
+
+                                row.set_text("");
+                            }
+                        }
+                    },
+
+                    #[local_ref]
+                    add = registry_group -> adw::PreferencesGroup {
+                        set_title: &tr!("game-settings-registry"),
+                        set_description: Some(&tr!("game-settings-registry-description")),
+
+                        adw::EntryRow {
+                            set_title: &tr!("game-settings-registry-add"),
+                            set_show_apply_button: true,
+
+                            connect_apply[sender] => move |row| {
+                                sender.input(GameSettingsAppMsg::AddRegistryValue(row.text().to_string()));
+
+                                row.set_text("");
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    async fn init(parent: Self::Init, root: Self::Root, sender: AsyncComponentSender<Self>) -> AsyncComponentParts<Self> {
+        let model = Self {
+            environment_group: adw::PreferencesGroup::new(),
+            environment_rows: Vec::new(),
+
+            dll_overrides_group: adw::PreferencesGroup::new(),
+            dll_overrides_rows: Vec::new(),
+
+            registry_group: adw::PreferencesGroup::new(),
+            registry_rows: Vec::new(),
+
+            launch_group: adw::PreferencesGroup::new(),
+            wrappers_rows: Vec::new(),
+
+            wine_versions: Wine::with_downloaded(Wine::versions()
+                .unwrap_or_default()
+                .into_iter()
+                .take(12)
+                .chain(Wine::system_versions())),
+
+            dxvk_versions: Dxvk::with_downloaded(Dxvk::versions()
+                .unwrap_or_default()
+                .into_iter()
+                .take(12)),
+
+            game_info: CardInfo::default(),
+
+            paths: None,
+            overrides: GameEditionOverrides::default()
+        };
+
+        let environment_group = &model.environment_group;
+        let dll_overrides_group = &model.dll_overrides_group;
+        let registry_group = &model.registry_group;
+        let launch_group = &model.launch_group;
+
+        let widgets = view_output!();
+
+        widgets.window.set_transient_for(Some(&parent));
+
+        unsafe {
+            WINDOW = Some(widgets.window.clone());
+        }
+
+        AsyncComponentParts { model, widgets }
+    }
+
+    async fn update(&mut self, msg: Self::Input, sender: AsyncComponentSender<Self>) {
+        match msg {
+            GameSettingsAppMsg::SetGameInfo(game_info) => {
+                let game = unsafe {
+                    games::get_unsafe(game_info.get_name())
+                };
+
+                match config::get().games.get_game_settings(game) {
+                    Ok(settings) => {
+                        self.paths = settings.paths.get(game_info.get_edition()).cloned();
+
+                        self.overrides = settings.overrides.get(game_info.get_edition())
+                            .cloned()
+                            .unwrap_or_default();
+                    }
+
+                    Err(err) => {
+                        sender.output(MainAppMsg::ShowToast {
+                            title: tr!("game-get-settings-failed", {
+                                "game-title" = game_info.get_title()
+                            }),
+                            message: Some(err.to_string())
+                        }).unwrap();
+                    }
+                }
+
+                self.game_info = game_info;
+
+                self.update_environment_rows(&sender);
+                self.update_dll_overrides_rows(&sender);
+                self.update_registry_rows(&sender);
+                self.update_wrappers_rows(&sender);
+            }
+
+            GameSettingsAppMsg::SetSeparatePrefix(separate) => {
+                let prefix = separate.then(|| GameEditionPaths::default_prefix(self.game_info.get_name(), self.game_info.get_edition()));
+
+                let property = format!("games.settings.{}.paths.{}.prefix", self.game_info.get_name(), self.game_info.get_edition());
+
+                if let Err(err) = config::set(property, serde_json::to_value(&prefix).unwrap()) {
+                    sender.output(MainAppMsg::ShowToast {
+                        title: tr!("config-property-update-failed"),
+                        message: Some(err.to_string())
+                    }).unwrap();
+
+                    return;
+                }
+
+                if let Some(paths) = &mut self.paths {
+                    paths.prefix = prefix;
+                }
+
+                // DXVK can be overridden only in the game's own prefix
+                if !separate && self.overrides.dxvk.is_some() {
+                    self.overrides.dxvk = None;
+
+                    self.save_overrides(&sender);
+                }
+            }
+
+            GameSettingsAppMsg::OpenPrefixManager => {
+                let Some(paths) = &self.paths else {
+                    return;
+                };
+
+                let separate = paths.prefix.is_some();
+
+                sender.output(MainAppMsg::OpenPrefixManager {
+                    prefix: paths.get_prefix(&config::get()),
+                    wine: self.overrides.wine.clone(),
+
+                    // DXVK can be overridden only in the game's own prefix
+                    dxvk: separate.then(|| self.overrides.dxvk.clone()).flatten()
+                }).unwrap();
+            }
+
+            GameSettingsAppMsg::SelectWineVersion(index) => {
+                self.overrides.wine = match self.wine_versions.get((index as usize).wrapping_sub(1)) {
+                    Some(wine) => {
+                        if !wine.is_downloaded() {
+                            sender.output(MainAppMsg::AddDownloadWineTask(wine.clone())).unwrap();
+                            sender.output(MainAppMsg::ShowTasksFlap).unwrap();
+                        }
+
+                        Some(ComponentVersion {
+                            build: config::get().components.wine.build,
+                            version: wine.name.clone()
+                        })
+                    }
+
+                    None => None
+                };
+
+                self.save_overrides(&sender);
+            }
+
+            GameSettingsAppMsg::SelectDxvkVersion(index) => {
+                let dxvk = match self.dxvk_versions.get((index as usize).wrapping_sub(1)) {
+                    Some(dxvk) => {
+                        self.overrides.dxvk = Some(ComponentVersion {
+                            build: config::get().components.dxvk.build,
+                            version: dxvk.name.clone()
+                        });
+
+                        dxvk.clone()
+                    }
+
+                    None => {
+                        self.overrides.dxvk = None;
+
+                        match Dxvk::from_config() {
+                            Ok(dxvk) => dxvk,
+                            Err(err) => {
+                                sender.output(MainAppMsg::ShowToast {
+                                    title: tr!("game-settings-dxvk-failed"),
+                                    message: Some(err.to_string())
+                                }).unwrap();
+
+                                return;
+                            }
+                        }
+                    }
+                };
+
+                self.save_overrides(&sender);
+
+                if !dxvk.is_downloaded() {
+                    sender.output(MainAppMsg::AddDownloadDxvkTask(dxvk.clone())).unwrap();
+                }
+
+                // Apply selected DXVK if the game's prefix was already created.
+                // Otherwise it will be installed during prefix creation
+                if let Some(GameEditionPaths { prefix: Some(prefix), .. }) = &self.paths {
+                    if prefix.exists() {
+                        sender.output(MainAppMsg::AddApplyDxvkTask {
+                            dxvk,
+                            prefix: prefix.clone()
+                        }).unwrap();
+                    }
+                }
+
+                sender.output(MainAppMsg::ShowTasksFlap).unwrap();
+            }
+
+            GameSettingsAppMsg::SelectSync(index) => {
+                self.overrides.sync = [WineSync::None, WineSync::ESync, WineSync::FSync]
+                    .get((index as usize).wrapping_sub(1))
+                    .copied();
+
+                self.save_overrides(&sender);
+            }
+
+            GameSettingsAppMsg::SelectVirtualDesktop(index) => {
+                self.overrides.virtual_desktop = match index {
+                    0 => None,
+
+                    _ => Some(VirtualDesktop {
+                        enabled: index == 1,
+                        ..config::get().games.wine.virtual_desktop
+                    })
+                };
+
+                self.save_overrides(&sender);
+            }
+
+            GameSettingsAppMsg::SelectHud(index) => {
+                self.overrides.hud = [HUD::None, HUD::DXVK, HUD::MangoHUD]
+                    .get((index as usize).wrapping_sub(1))
+                    .copied();
+
+                self.save_overrides(&sender);
+            }
+
+            GameSettingsAppMsg::SelectFsr(index) => {
+                self.overrides.fsr = match index {
+                    0 => None,
+
+                    _ => Some(FSR {
+                        enabled: index == 1,
+                        ..config::get().games.enhancements.fsr
+                    })
+                };
+
+                self.save_overrides(&sender);
+            }
+
+            GameSettingsAppMsg::SelectGamescope(index) => {
+                self.overrides.gamescope = match index {
+                    0 => None,
+
+                    _ => Some(Gamescope {
+                        enabled: index == 1,
+                        ..config::get().games.enhancements.gamescope
+                    })
+                };
+
+                self.save_overrides(&sender);
+            }
+
+            GameSettingsAppMsg::SelectGamemode(index) => {
+                self.overrides.gamemode = match index {
+                    0 => None,
+                    _ => Some(index == 1)
+                };
+
+                self.save_overrides(&sender);
+            }
+
+            GameSettingsAppMsg::AddEnvironment(variable) => {
+                let Some((key, value)) = variable.split_once('=') else {
+                    sender.output(MainAppMsg::ShowToast {
+                        title: tr!("game-settings-environment-invalid"),
+                        message: None
+                    }).unwrap();
+
+                    return;
+                };
+
+                self.overrides.environment.insert(key.trim().to_string(), value.to_string());
+
+                self.save_overrides(&sender);
+                self.update_environment_rows(&sender);
+            }
+
+            GameSettingsAppMsg::RemoveEnvironment(key) => {
+                self.overrides.environment.remove(&key);
+
+                self.save_overrides(&sender);
+                self.update_environment_rows(&sender);
+            }
+
+            GameSettingsAppMsg::AddDllOverride(dll_override) => {
+                // d3d11=n,b
+                let parsed = dll_override.split_once('=')
+                    .and_then(|(dll, value)| Some((dll.trim(), DllOverride::from_wine_str(value)?)))
+                    .filter(|(dll, _)| !dll.is_empty());
+
+                let Some((dll, value)) = parsed else {
+                    sender.output(MainAppMsg::ShowToast {
+                        title: tr!("game-settings-dll-overrides-invalid"),
+                        message: None
+                    }).unwrap();
+
+                    return;
+                };
+
+                self.overrides.dll_overrides.insert(dll.to_string(), value);
+
+                self.save_overrides(&sender);
+                self.update_dll_overrides_rows(&sender);
+            }
+
+            GameSettingsAppMsg::RemoveDllOverride(dll) => {
+                self.overrides.dll_overrides.remove(&dll);
+
+                self.save_overrides(&sender);
+                self.update_dll_overrides_rows(&sender);
+            }
+
+            GameSettingsAppMsg::AddRegistryValue(value) => {
+                let Some(value) = parse_registry_value(&value) else {
+                    sender.output(MainAppMsg::ShowToast {
+                        title: tr!("game-settings-registry-invalid"),
+                        message: None
+                    }).unwrap();
+
+                    return;
+                };
+
+                // Check dword values before saving them
+                if let Err(err) = value.to_reg_line() {
+                    sender.output(MainAppMsg::ShowToast {
+                        title: tr!("game-settings-registry-invalid"),
+                        message: Some(err.to_string())
+                    }).unwrap();
+
+                    return;
+                }
+
+                self.overrides.registry.retain(|stored| stored.key != value.key || stored.name != value.name);
+                self.overrides.registry.push(value);
+
+                self.save_overrides(&sender);
+                self.update_registry_rows(&sender);
+            }
+
+            GameSettingsAppMsg::RemoveRegistryValue(index) => {
+                if index < self.overrides.registry.len() {
+                    self.overrides.registry.remove(index);
+                }
+
+                self.save_overrides(&sender);
+                self.update_registry_rows(&sender);
+            }
+
+            GameSettingsAppMsg::SetLaunchTemplate(template) => {
+                if !launch::is_valid_template(&template) {
+                    sender.output(MainAppMsg::ShowToast {
+                        title: tr!("launch-template-invalid"),
+                        message: None
+                    }).unwrap();
+
+                    return;
+                }
+
+                let template = template.trim();
+
+                self.overrides.launch_template = (!template.is_empty()).then(|| template.to_string());
+
+                self.save_overrides(&sender);
+            }
+
+            GameSettingsAppMsg::AddWrapper(command) => {
+                if command.trim().is_empty() {
+                    return;
+                }
+
+                let wrapper = LaunchWrapper::from_command(command);
+
+                if !self.overrides.wrappers.contains(&wrapper) {
+                    self.overrides.wrappers.push(wrapper);
+                }
+
+                self.save_overrides(&sender);
+                self.update_wrappers_rows(&sender);
+            }
+
+            GameSettingsAppMsg::RemoveWrapper(index) => {
+                if index < self.overrides.wrappers.len() {
+                    self.overrides.wrappers.remove(index);
+                }
+
+                self.save_overrides(&sender);
+                self.update_wrappers_rows(&sender);
+            }
+        }
+    }
+}
+
+impl GameSettingsApp {
+    /// Write game edition overrides to the config file
+    fn save_overrides(&self, sender: &AsyncComponentSender<Self>) {
+        let property = format!("games.settings.{}.overrides.{}", self.game_info.get_name(), self.game_info.get_edition());
+
+        if let Err(err) = config::set(property, serde_json::to_value(&self.overrides).unwrap()) {
+            sender.output(MainAppMsg::ShowToast {
+                title: tr!("config-property-update-failed"),
+                message: Some(err.to_string())
+            }).unwrap();
+        }
+    }
+
+    /// Recreate environment variables rows
+    fn update_environment_rows(&mut self, sender: &AsyncComponentSender<Self>) {
+        for row in self.environment_rows.drain(..) {
+            self.environment_group.remove(&row);
+        }
+
+        let mut variables = self.overrides.environment.iter().collect::<Vec<_>>();
+
+        variables.sort();
+
+        for (key, value) in variables {
+            let row = removable_row(key, value, sender, GameSettingsAppMsg::RemoveEnvironment(key.clone()));
+
+            self.environment_group.add(&row);
+            self.environment_rows.push(row);
+        }
+    }
+
+    /// Recreate launch wrappers rows
+    fn update_wrappers_rows(&mut self, sender: &AsyncComponentSender<Self>) {
+        for row in self.wrappers_rows.drain(..) {
+            self.launch_group.remove(&row);
+        }
+
+        for (i, wrapper) in self.overrides.wrappers.iter().enumerate() {
+            let row = removable_row(&wrapper.get_command(), "", sender, GameSettingsAppMsg::RemoveWrapper(i));
+
+            self.launch_group.add(&row);
+            self.wrappers_rows.push(row);
+        }
+    }
+
+    /// Recreate DLL overrides rows
+    fn update_dll_overrides_rows(&mut self, sender: &AsyncComponentSender<Self>) {
+        for row in self.dll_overrides_rows.drain(..) {
+            self.dll_overrides_group.remove(&row);
+        }
+
+        for (dll, value) in &self.overrides.dll_overrides {
+            let subtitle = match value {
+                DllOverride::Disabled => tr!("game-settings-dll-overrides-disabled"),
+                _ => value.to_wine_str().to_string()
+            };
+
+            let row = removable_row(dll, &subtitle, sender, GameSettingsAppMsg::RemoveDllOverride(dll.clone()));
+
+            self.dll_overrides_group.add(&row);
+            self.dll_overrides_rows.push(row);
+        }
+    }
+
+    /// Recreate registry values rows
+    fn update_registry_rows(&mut self, sender: &AsyncComponentSender<Self>) {
+        for row in self.registry_rows.drain(..) {
+            self.registry_group.remove(&row);
+        }
+
+        for (i, value) in self.overrides.registry.iter().enumerate() {
+            let data = match value.kind {
+                RegistryValueType::String => value.value.clone(),
+                RegistryValueType::Dword => format!("dword:{}", value.value)
+            };
+
+            let title = format!("{}\\{}", value.key, value.name);
+
+            let row = removable_row(&title, &data, sender, GameSettingsAppMsg::RemoveRegistryValue(i));
+
+            self.registry_group.add(&row);
+            self.registry_rows.push(row);
+        }
+    }
+}
+
+/// Create row with a button which sends the given message
+fn removable_row(title: &str, subtitle: &str, sender: &AsyncComponentSender<GameSettingsApp>, msg: GameSettingsAppMsg) -> adw::ActionRow {
+    let row = adw::ActionRow::new();
+
+    row.set_title(title);
+    row.set_subtitle(subtitle);
+
+    let button = gtk::Button::new();
+
+    button.set_icon_name("user-trash-symbolic");
+    button.set_valign(gtk::Align::Center);
+    button.add_css_class("flat");
+
+    let sender = sender.clone();
+
+    button.connect_clicked(move |_| sender.input(msg.clone()));
+
+    row.add_suffix(&button);
+
+    row
+}
+
+/// Parse registry value in the `<key>\<name>=<value>` format
+///
+/// Dword values are prefixed with `dword:`, e.g. `<key>\<name>=dword:1`
+fn parse_registry_value(value: &str) -> Option<RegistryValue> {
+    let (path, data) = value.split_once('=')?;
+    let (key, name) = path.trim().rsplit_once('\\')?;
+
+    if key.is_empty() || name.is_empty() {
+        return None;
+    }
+
+    let (kind, data) = match data.strip_prefix("dword:") {
+        Some(data) => (RegistryValueType::Dword, data),
+        None => (RegistryValueType::String, data)
+    };
+
+    Some(RegistryValue {
+        key: key.to_string(),
+        name: name.to_string(),
+        kind,
+        value: data.to_string()
+    })
+}
@@ -10,6 +10,8 @@ pub fn check_default_dirs() -> anyhow::Result<()> {
 
         std::fs::create_dir_all(COMPONENTS_FOLDER.join("wine"))?;
         std::fs::create_dir_all(COMPONENTS_FOLDER.join("dxvk"))?;
+        std::fs::create_dir_all(COMPONENTS_FOLDER.join("vkd3d"))?;
+        std::fs::create_dir_all(COMPONENTS_FOLDER.join("nvapi"))?;
     }
 
     Ok(())
@@ -1,6 +1,7 @@
 use wincompatlib::dxvk::Dxvk as WincompatlibDxvk;
 
 use crate::config;
+use crate::components::wine::is_proton;
 use crate::components::dxvk::Dxvk;
 
 #[inline]
@@ -16,7 +17,14 @@ pub fn get_download() -> anyhow::Result<Option<Dxvk>> {
 
 #[inline]
 pub fn get_apply() -> anyhow::Result<Option<Dxvk>> {
-    let prefix = config::get().components.wine.prefix.path;
+    let wine = config::get().components.wine;
+
+    // Proton uses its own DXVK build
+    if is_proton(&wine.version) {
+        return Ok(None);
+    }
+
+    let prefix = wine.prefix.path;
 
     if !prefix.exists() {
         // We don't need to apply DXVK because it's done during prefix creation
@@ -0,0 +1,45 @@
+use crate::config;
+use crate::components::wine::is_proton;
+use crate::components::nvapi::Nvapi;
+
+#[inline]
+pub fn get_download() -> anyhow::Result<Option<Nvapi>> {
+    if !config::get().components.nvapi.enabled {
+        return Ok(None);
+    }
+
+    let nvapi = Nvapi::from_config()?;
+
+    if nvapi.is_downloaded() {
+        return Ok(None);
+    }
+
+    Ok(Some(nvapi))
+}
+
+#[inline]
+pub fn get_apply() -> anyhow::Result<Option<Nvapi>> {
+    let components = config::get().components;
+
+    // Proton uses its own build of the component
+    if !components.nvapi.enabled || is_proton(&components.wine.version) {
+        return Ok(None);
+    }
+
+    if !components.wine.prefix.path.exists() {
+        // We don't need to apply the component because it's done during prefix creation
+        return Ok(None);
+    }
+
+    let selected_nvapi = Nvapi::from_config()?;
+
+    let installed_nvapi = Nvapi::get_installed_version(&components.wine.prefix.path)?;
+
+    if let Some(version) = installed_nvapi {
+        if selected_nvapi.name.contains(&version) || selected_nvapi.version.contains(&version) {
+            return Ok(None);
+        }
+    }
+
+    Ok(Some(selected_nvapi))
+}
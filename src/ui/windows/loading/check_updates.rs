@@ -0,0 +1,29 @@
+use std::path::PathBuf;
+
+use crate::config;
+use crate::config::components::updates::UpdatePolicy;
+
+use crate::components::updates::{self, ComponentsUpdates};
+
+#[inline]
+pub fn get_updates() -> anyhow::Result<ComponentsUpdates> {
+    if config::get().components.updates.policy == UpdatePolicy::Never {
+        return Ok(ComponentsUpdates::default());
+    }
+
+    updates::get_updates()
+}
+
+#[inline]
+pub fn get_unused() -> Vec<PathBuf> {
+    if !config::get().components.updates.delete_unused {
+        return Vec::new();
+    }
+
+    // Unused components will be deleted on the next start
+    updates::get_unused_folders().unwrap_or_else(|err| {
+        tracing::warn!("Failed to find unused components: {err}");
+
+        Vec::new()
+    })
+}
@@ -0,0 +1,45 @@
+use crate::config;
+use crate::components::wine::is_proton;
+use crate::components::vkd3d::Vkd3d;
+
+#[inline]
+pub fn get_download() -> anyhow::Result<Option<Vkd3d>> {
+    if !config::get().components.vkd3d.enabled {
+        return Ok(None);
+    }
+
+    let vkd3d = Vkd3d::from_config()?;
+
+    if vkd3d.is_downloaded() {
+        return Ok(None);
+    }
+
+    Ok(Some(vkd3d))
+}
+
+#[inline]
+pub fn get_apply() -> anyhow::Result<Option<Vkd3d>> {
+    let components = config::get().components;
+
+    // Proton uses its own build of the component
+    if !components.vkd3d.enabled || is_proton(&components.wine.version) {
+        return Ok(None);
+    }
+
+    if !components.wine.prefix.path.exists() {
+        // We don't need to apply the component because it's done during prefix creation
+        return Ok(None);
+    }
+
+    let selected_vkd3d = Vkd3d::from_config()?;
+
+    let installed_vkd3d = Vkd3d::get_installed_version(&components.wine.prefix.path)?;
+
+    if let Some(version) = installed_vkd3d {
+        if selected_vkd3d.name.contains(&version) || selected_vkd3d.version.contains(&version) {
+            return Ok(None);
+        }
+    }
+
+    Ok(Some(selected_vkd3d))
+}
@@ -1,12 +1,15 @@
 use crate::config;
 use crate::config::components::wine::prefix::Prefix;
 
+use crate::components::wine::is_proton;
+
 #[inline]
 pub fn check_wine_prefix() -> Option<Prefix> {
-    let prefix = config::get().components.wine.prefix;
+    let wine = config::get().components.wine;
 
-    if !prefix.path.exists() {
-        return Some(prefix);
+    // Proton creates its prefix by itself
+    if !wine.prefix.path.exists() && !is_proton(&wine.version) {
+        return Some(wine.prefix);
     }
 
     None
@@ -1,21 +1,32 @@
+use std::path::PathBuf;
+
 use relm4::prelude::*;
 
 use crate::tr;
 
 use crate::components::dxvk::Dxvk;
+use crate::components::vkd3d::Vkd3d;
+use crate::components::nvapi::Nvapi;
+use crate::components::updates::ComponentsUpdates;
 use crate::components::wine::Wine;
 
 use crate::config::components::wine::prefix::Prefix;
 
 use super::*;
 
-const TOTAL_STEPS: f64 = 13.0;
+const TOTAL_STEPS: f64 = 16.0;
 
 #[derive(Debug)]
 pub struct LoadingResult {
     pub download_wine: Option<Wine>,
     pub download_dxvk: Option<Dxvk>,
     pub apply_dxvk: Option<Dxvk>,
+    pub download_vkd3d: Option<Vkd3d>,
+    pub apply_vkd3d: Option<Vkd3d>,
+    pub download_nvapi: Option<Nvapi>,
+    pub apply_nvapi: Option<Nvapi>,
+    pub components_updates: ComponentsUpdates,
+    pub delete_components: Vec<PathBuf>,
     pub create_prefix: Option<Prefix>,
     pub download_addons: Vec<check_addons::AddonsListEntry>,
 
@@ -119,11 +130,43 @@ pub fn load_app(sender: &AsyncComponentSender<LoadingApp>) -> Result<LoadingResu
     })?;
 
     sender.input(LoadingAppMsg::SetProgress(11.0 / TOTAL_STEPS));
+    sender.input(LoadingAppMsg::SetActiveStage(tr!("loading-checking-vkd3d")));
+
+    let (download_vkd3d, apply_vkd3d) = check_vkd3d::get_download()
+        .and_then(|download| Ok((download, check_vkd3d::get_apply()?)))
+        .map_err(|err| LoadingAppMsg::DisplayError {
+            title: tr!("loading-checking-vkd3d-failed"),
+            message: err.to_string()
+        })?;
+
+    sender.input(LoadingAppMsg::SetProgress(12.0 / TOTAL_STEPS));
+    sender.input(LoadingAppMsg::SetActiveStage(tr!("loading-checking-nvapi")));
+
+    let (download_nvapi, apply_nvapi) = check_nvapi::get_download()
+        .and_then(|download| Ok((download, check_nvapi::get_apply()?)))
+        .map_err(|err| LoadingAppMsg::DisplayError {
+            title: tr!("loading-checking-nvapi-failed"),
+            message: err.to_string()
+        })?;
+
+    sender.input(LoadingAppMsg::SetProgress(13.0 / TOTAL_STEPS));
+    sender.input(LoadingAppMsg::SetActiveStage(tr!("loading-checking-components-updates")));
+
+    // Components can still be used if their channel is unavailable
+    let components_updates = check_updates::get_updates().unwrap_or_else(|err| {
+        tracing::warn!("Failed to check components updates: {err}");
+
+        ComponentsUpdates::default()
+    });
+
+    let delete_components = check_updates::get_unused();
+
+    sender.input(LoadingAppMsg::SetProgress(14.0 / TOTAL_STEPS));
     sender.input(LoadingAppMsg::SetActiveStage(tr!("loading-checking-wine-prefix")));
 
     let create_prefix = check_wine_prefix::check_wine_prefix();
 
-    sender.input(LoadingAppMsg::SetProgress(12.0 / TOTAL_STEPS));
+    sender.input(LoadingAppMsg::SetProgress(15.0 / TOTAL_STEPS));
     sender.input(LoadingAppMsg::SetActiveStage(tr!("loading-checking-games-addons")));
 
     let download_addons = check_addons::get_download(&pool).map_err(|err| LoadingAppMsg::DisplayError {
@@ -142,6 +185,12 @@ pub fn load_app(sender: &AsyncComponentSender<LoadingApp>) -> Result<LoadingResu
         download_wine,
         download_dxvk,
         apply_dxvk,
+        download_vkd3d,
+        apply_vkd3d,
+        download_nvapi,
+        apply_nvapi,
+        components_updates,
+        delete_components,
         create_prefix,
         download_addons,
 
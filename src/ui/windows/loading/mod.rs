@@ -11,6 +11,9 @@ pub mod update_integrations;
 pub mod init_games;
 pub mod check_wine;
 pub mod check_dxvk;
+pub mod check_vkd3d;
+pub mod check_nvapi;
+pub mod check_updates;
 pub mod check_wine_prefix;
 pub mod check_addons;
 pub mod load_app;
@@ -0,0 +1,184 @@
+use std::path::{Path, PathBuf};
+use std::os::unix::fs::MetadataExt;
+
+use relm4::prelude::*;
+use gtk::prelude::*;
+use adw::prelude::*;
+
+use crate::tr;
+
+use crate::config;
+use crate::utils::pretty_bytes;
+
+use crate::ui::components::tasks_queue::download_diff_task::{
+    DownloadDiffQueuedTask,
+    DiffStage
+};
+
+use super::{
+    MainApp,
+    MainAppMsg,
+    WINDOW
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpaceRequirement {
+    pub path: PathBuf,
+    pub required: u64,
+    pub available: u64,
+
+    /// Config property which can be changed to use another folder
+    pub property: Option<String>
+}
+
+/// Find the closest existing folder of the given path
+fn get_existing_parent(path: &Path) -> PathBuf {
+    let mut path = path.to_path_buf();
+
+    while !path.exists() {
+        match path.parent() {
+            Some(parent) => path = parent.to_path_buf(),
+            None => break
+        }
+    }
+
+    path
+}
+
+/// Check if the task has enough free space in the transitions and destination folders
+///
+/// Return list of folders without enough free space
+pub fn check_task(task: &DownloadDiffQueuedTask, destination_property: Option<String>) -> anyhow::Result<Vec<SpaceRequirement>> {
+    let transitions = get_existing_parent(&config::get().general.transitions.path);
+    let destination = get_existing_parent(&task.download_path);
+
+    let unpacked = task.diff_info.get_unpacked_size();
+
+    let transition_required = match task.diff_stage {
+        // Files were already downloaded into the transition
+        DiffStage::ApplyPreDownloaded(_) => 0,
+
+        // Archives can be kept in the transition folder until all the files are unpacked
+        _ => task.diff_info.get_peak_size()
+    };
+
+    let same_filesystem = transitions.metadata()?.dev() == destination.metadata()?.dev();
+
+    let mut requirements = vec![SpaceRequirement {
+        available: fs2::available_space(&transitions)?,
+        path: transitions,
+        required: transition_required,
+        property: Some(String::from("general.transitions.path"))
+    }];
+
+    // Files are moved without copying within the same filesystem
+    if !same_filesystem {
+        requirements.push(SpaceRequirement {
+            available: fs2::available_space(&destination)?,
+            path: destination,
+            required: unpacked,
+            property: destination_property
+        });
+    }
+
+    requirements.retain(|requirement| requirement.required > requirement.available);
+
+    Ok(requirements)
+}
+
+/// Check free space for the task and show dialog if it's not enough
+///
+/// `message` is used to queue the task again. Its argument
+/// tells if free space should be checked again
+///
+/// Return `true` if the task can be queued right now
+pub fn verify_task(
+    task: &DownloadDiffQueuedTask,
+    destination_property: Option<String>,
+    sender: &AsyncComponentSender<MainApp>,
+    message: impl Fn(bool) -> MainAppMsg + 'static
+) -> bool {
+    let requirements = match check_task(task, destination_property) {
+        Ok(requirements) if requirements.is_empty() => return true,
+        Ok(requirements) => requirements,
+
+        Err(err) => {
+            tracing::warn!("Failed to check free space: {err}");
+
+            return true;
+        }
+    };
+
+    let window = unsafe {
+        WINDOW.as_ref().unwrap_unchecked()
+    };
+
+    let body = requirements.iter()
+        .map(|requirement| tr!("disk-space-required", {
+            "path" = requirement.path.to_string_lossy().to_string(),
+            "required" = pretty_bytes(requirement.required),
+            "available" = pretty_bytes(requirement.available)
+        }))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let dialog = adw::MessageDialog::new(
+        Some(window),
+        Some(&tr!("disk-space-insufficient")),
+        Some(&body)
+    );
+
+    dialog.add_response("cancel", &tr!("dialog-cancel"));
+
+    // Offer to change the first folder which can be changed
+    let property = requirements.into_iter()
+        .find_map(|requirement| requirement.property);
+
+    if property.is_some() {
+        dialog.add_response("change", &tr!("dialog-change-path"));
+        dialog.set_response_appearance("change", adw::ResponseAppearance::Suggested);
+    }
+
+    dialog.add_response("continue", &tr!("dialog-continue"));
+    dialog.set_response_appearance("continue", adw::ResponseAppearance::Destructive);
+
+    let sender = sender.clone();
+
+    dialog.connect_response(None, move |_, response| {
+        match response {
+            "continue" => sender.input(message(false)),
+
+            "change" => {
+                let Some(property) = property.clone() else {
+                    return;
+                };
+
+                let sender = sender.clone();
+                let retry = message(true);
+
+                gtk::FileDialog::new().select_folder(Some(window), None::<&gtk::gio::Cancellable>, move |result| {
+                    let Some(path) = result.ok().and_then(|folder| folder.path()) else {
+                        return;
+                    };
+
+                    if let Err(err) = config::set(property, path.to_string_lossy().to_string()) {
+                        sender.input(MainAppMsg::ShowToast {
+                            title: tr!("config-property-update-failed"),
+                            message: Some(err.to_string())
+                        });
+
+                        return;
+                    }
+
+                    sender.input(retry);
+                });
+            }
+
+            _ => ()
+        }
+    });
+
+    dialog.present();
+
+    false
+}
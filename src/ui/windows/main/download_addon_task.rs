@@ -6,7 +6,8 @@ use crate::ui::components::game_card::CardInfo;
 
 use crate::ui::components::tasks_queue::download_diff_task::{
     DownloadDiffQueuedTask,
-    DiffOrigin
+    DiffOrigin,
+    DiffStage
 };
 
 use crate::games::integrations::Game;
@@ -17,6 +18,7 @@ use crate::games::integrations::standards::addons::{
     AddonsGroup
 };
 
+use super::download_game_task;
 use super::MainAppMsg;
 
 type HeapResult<T> = Result<T, Box<MainAppMsg>>;
@@ -83,7 +85,7 @@ pub fn get_download_addon_task(game_info: &CardInfo, addon: &Addon, group: &Addo
         games::get_unsafe(game_info.get_name())
     };
 
-    Ok(Box::new(DownloadDiffQueuedTask {
+    download_game_task::verify_patch_tool(Box::new(DownloadDiffQueuedTask {
         card_info: game_info.clone(),
         diff_info: get_diff_or_download(
             game,
@@ -96,6 +98,7 @@ pub fn get_download_addon_task(game_info: &CardInfo, addon: &Addon, group: &Addo
             group_name: group.name.clone(),
             addon_name: addon.name.clone()
         },
+        diff_stage: DiffStage::Apply,
         download_path
-    }))
+    })))
 }
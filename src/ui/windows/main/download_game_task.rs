@@ -9,11 +9,21 @@ use crate::ui::components::game_card::CardInfo;
 
 use crate::ui::components::tasks_queue::download_diff_task::{
     DownloadDiffQueuedTask,
-    DiffOrigin
+    DiffOrigin,
+    DiffStage
 };
 
+use crate::games::patch;
 use crate::games::integrations::Game;
-use crate::games::integrations::standards::diff::DiffInfo;
+use crate::games::integrations::standards::diff::{
+    Diff,
+    DiffInfo
+};
+
+use crate::games::metadata::{
+    LauncherMetadata,
+    GamePreDownloadMetadata
+};
 
 use crate::ui::windows::loading::check_addons::{
     AddonsListEntry,
@@ -36,7 +46,7 @@ fn is_installed(game: &Game, game_path: &str, edition: &str) -> HeapResult<bool>
 }
 
 #[inline]
-fn get_diff(game: &Game, edition: impl AsRef<str>, game_path: impl AsRef<str>) -> HeapResult<DiffInfo> {
+fn get_diff(game: &Game, edition: impl AsRef<str>, game_path: impl AsRef<str>) -> HeapResult<Diff> {
     game.driver.get_game_diff(game_path.as_ref(), edition.as_ref())
         .map_err(|err| MainAppMsg::ShowToast {
             title: tr!("game-find-diff-failed", {
@@ -44,7 +54,80 @@ fn get_diff(game: &Game, edition: impl AsRef<str>, game_path: impl AsRef<str>) -
             }),
             message: Some(err.to_string())
         })?
-        .and_then(|diff| diff.diff)
+        .ok_or_else(|| Box::new(MainAppMsg::ShowToast {
+            title: tr!("game-not-installed", {
+                "game-title" = game.manifest.game_title.clone()
+            }),
+            message: None
+        }))
+}
+
+/// Get pre-downloaded diff which was staged for the given game diff
+///
+/// Pre-download is removed if it was made for another game version,
+/// or if its transition was deleted. Returns `None` if there's no
+/// pre-download or it can't be applied yet
+///
+/// Should be called only when a download task is started
+fn get_staged_pre_download(game_name: &str, edition: &str, diff: &Diff) -> anyhow::Result<Option<GamePreDownloadMetadata>> {
+    let mut metadata = LauncherMetadata::load_for_game(game_name, edition)?;
+
+    let Some(pre_download) = metadata.pre_download.clone() else {
+        return Ok(None);
+    };
+
+    let released = pre_download.current_version == diff.current_version &&
+        pre_download.latest_version == diff.latest_version;
+
+    let upcoming = pre_download.current_version == diff.current_version &&
+        diff.pre_download.as_ref().is_some_and(|upcoming| upcoming.version == pre_download.latest_version);
+
+    if pre_download.is_staged() {
+        if released {
+            return Ok(Some(pre_download));
+        }
+
+        if upcoming {
+            return Ok(None);
+        }
+    }
+
+    tracing::warn!("Removing outdated pre-download of {game_name} ({edition}): {} -> {}", pre_download.current_version, pre_download.latest_version);
+
+    if let Some(transition_path) = pre_download.transition_path {
+        if transition_path.exists() {
+            std::fs::remove_dir_all(&transition_path)?;
+        }
+
+        metadata.transitions.retain(|transition| transition != &transition_path);
+    }
+
+    metadata.pre_download = None;
+
+    metadata.save_for_game(game_name, edition)?;
+
+    Ok(None)
+}
+
+#[inline]
+fn get_diff_info(game: &Game, edition: &str, game_path: &str) -> HeapResult<(DiffInfo, DiffStage)> {
+    let diff = get_diff(game, edition, game_path)?;
+
+    // Apply pre-downloaded files if they were staged for this version
+    let pre_download = get_staged_pre_download(&game.manifest.game_name, edition, &diff)
+        .unwrap_or_else(|err| {
+            tracing::error!("Failed to check pre-downloaded diff: {err}");
+
+            None
+        });
+
+    let stage = match pre_download {
+        Some(pre_download) => DiffStage::ApplyPreDownloaded(pre_download),
+        None => DiffStage::Apply
+    };
+
+    diff.diff
+        .map(|diff| (diff, stage))
         .ok_or_else(|| Box::new(MainAppMsg::ShowToast {
             title: tr!("game-not-installed", {
                 "game-title" = game.manifest.game_title.clone()
@@ -66,10 +149,10 @@ fn get_download(game: &Game, edition: &str) -> HeapResult<DiffInfo> {
 }
 
 #[inline]
-fn get_diff_or_download(game: &Game, game_path: &str, edition: &str) -> HeapResult<DiffInfo> {
+fn get_diff_or_download(game: &Game, game_path: &str, edition: &str) -> HeapResult<(DiffInfo, DiffStage)> {
     is_installed(game, game_path, edition)?
-        .then(|| get_diff(game, edition, game_path))
-        .unwrap_or_else(|| get_download(game, edition))
+        .then(|| get_diff_info(game, edition, game_path))
+        .unwrap_or_else(|| get_download(game, edition).map(|diff| (diff, DiffStage::Apply)))
 }
 
 #[inline]
@@ -94,6 +177,20 @@ fn get_addons(game: &Game, game_info: &CardInfo, edition: &str, enabled_addons:
         }))
 }
 
+/// Make sure that binary patches of the task can be applied
+pub fn verify_patch_tool(task: Box<DownloadDiffQueuedTask>) -> HeapResult<Box<DownloadDiffQueuedTask>> {
+    if task.is_patch() && !patch::is_available() {
+        return Err(Box::new(MainAppMsg::ShowToast {
+            title: tr!("diff-patch-tool-missing", {
+                "game-title" = task.card_info.get_title().to_owned()
+            }),
+            message: None
+        }));
+    }
+
+    Ok(task)
+}
+
 pub struct DownloadGameResult {
     pub game_task: Box<DownloadDiffQueuedTask>,
     pub download_addons: Vec<AddonsListEntry>
@@ -113,18 +210,92 @@ pub fn get_download_game_task(game_info: &CardInfo, config: &config::Config) ->
     // Enabled game addons
     let enabled_addons = &settings.addons[game_info.get_edition()];
 
+    let (diff_info, diff_stage) = get_diff_or_download(
+        game,
+        &game_path.to_string_lossy(),
+        game_info.get_edition()
+    )?;
+
     Ok(DownloadGameResult {
-        game_task: Box::new(DownloadDiffQueuedTask {
+        game_task: verify_patch_tool(Box::new(DownloadDiffQueuedTask {
             card_info: game_info.clone(),
             download_path: game_path.clone(),
-            diff_info: get_diff_or_download(
-                game,
-                &game_path.to_string_lossy(),
-                game_info.get_edition()
-            )?,
-            diff_origin: DiffOrigin::Game
-        }),
+            diff_info,
+            diff_origin: DiffOrigin::Game,
+            diff_stage
+        }))?,
 
         download_addons: get_addons(game, game_info, game_info.get_edition(), enabled_addons)?
     })
 }
+
+#[inline]
+pub fn get_pre_download_game_task(game_info: &CardInfo, config: &config::Config) -> HeapResult<Box<DownloadDiffQueuedTask>> {
+    let game = unsafe {
+        games::get_unsafe(game_info.get_name())
+    };
+
+    let settings = get_settings(game, config)?;
+
+    // Game installation path
+    let game_path = &settings.paths[game_info.get_edition()].game;
+
+    let diff = get_diff(game, game_info.get_edition(), game_path.to_string_lossy())?;
+
+    // Remove outdated pre-download so the new one can be downloaded
+    if let Err(err) = get_staged_pre_download(game_info.get_name(), game_info.get_edition(), &diff) {
+        tracing::error!("Failed to check pre-downloaded diff: {err}");
+    }
+
+    let Some(pre_download) = diff.pre_download else {
+        return Err(Box::new(MainAppMsg::ShowToast {
+            title: tr!("game-no-pre-download", {
+                "game-title" = game.manifest.game_title.clone()
+            }),
+            message: None
+        }));
+    };
+
+    let is_patch = matches!(pre_download.diff, DiffInfo::Patch { .. });
+
+    Ok(Box::new(DownloadDiffQueuedTask {
+        card_info: game_info.clone(),
+        download_path: game_path.clone(),
+        diff_info: pre_download.diff,
+        diff_origin: DiffOrigin::Game,
+        diff_stage: DiffStage::PreDownload(GamePreDownloadMetadata {
+            current_version: diff.current_version,
+            latest_version: pre_download.version,
+            is_patch,
+            transition_path: None
+        })
+    }))
+}
+
+/// Get version of the game which can be pre-downloaded
+/// 
+/// Return `None` if there's no pre-download or it's already downloaded.
+/// Requests game diff, so shouldn't be called from the UI thread
+pub fn get_pre_download_version(game_info: &CardInfo, game_path: &str) -> anyhow::Result<Option<String>> {
+    let game = unsafe {
+        games::get_unsafe(game_info.get_name())
+    };
+
+    let Some(diff) = game.driver.get_game_diff(game_path, game_info.get_edition())? else {
+        return Ok(None);
+    };
+
+    let Some(pre_download) = diff.pre_download else {
+        return Ok(None);
+    };
+
+    let metadata = LauncherMetadata::load_for_game(game_info.get_name(), game_info.get_edition())?;
+
+    let downloaded = metadata.pre_download.is_some_and(|metadata| {
+        metadata.current_version == diff.current_version &&
+        metadata.latest_version == pre_download.version &&
+        metadata.is_staged()
+    });
+
+    Ok((!downloaded).then_some(pre_download.version))
+}
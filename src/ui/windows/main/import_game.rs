@@ -0,0 +1,103 @@
+use std::path::Path;
+
+use crate::tr;
+
+use crate::games;
+use crate::config;
+
+use crate::ui::components::game_card::CardInfo;
+
+use crate::games::integrations::Game;
+use crate::games::integrations::standards::diff::{
+    Diff,
+    DiffStatus
+};
+
+use super::MainAppMsg;
+
+type HeapResult<T> = Result<T, Box<MainAppMsg>>;
+
+pub struct ImportGameResult {
+    /// Version of the imported game
+    pub version: String,
+
+    /// Imported game needs to be updated
+    pub outdated: bool
+}
+
+#[inline]
+fn is_installed(game: &Game, game_path: &str, edition: &str) -> HeapResult<bool> {
+    game.driver.is_game_installed(game_path, edition)
+        .map_err(|err| Box::new(MainAppMsg::ShowToast {
+            title: tr!("game-verify-installation-failed", {
+                "game-title" = game.manifest.game_title.clone()
+            }),
+            message: Some(err.to_string())
+        }))
+}
+
+#[inline]
+fn get_version(game: &Game, game_path: &str, edition: &str) -> HeapResult<Option<String>> {
+    game.driver.get_game_version(game_path, edition)
+        .map_err(|err| Box::new(MainAppMsg::ShowToast {
+            title: tr!("game-import-version-failed", {
+                "game-title" = game.manifest.game_title.clone()
+            }),
+            message: Some(err.to_string())
+        }))
+}
+
+#[inline]
+fn get_diff(game: &Game, game_path: &str, edition: &str) -> HeapResult<Option<Diff>> {
+    game.driver.get_game_diff(game_path, edition)
+        .map_err(|err| Box::new(MainAppMsg::ShowToast {
+            title: tr!("game-find-diff-failed", {
+                "game-title" = game.manifest.game_title.clone()
+            }),
+            message: Some(err.to_string())
+        }))
+}
+
+/// Verify game installation in the given folder
+/// and use it as the game path
+pub fn import_game(game_info: &CardInfo, path: &Path) -> HeapResult<ImportGameResult> {
+    let game = unsafe {
+        games::get_unsafe(game_info.get_name())
+    };
+
+    let game_path = path.to_string_lossy();
+    let edition = game_info.get_edition();
+
+    if !is_installed(game, &game_path, edition)? {
+        return Err(Box::new(MainAppMsg::ShowToast {
+            title: tr!("game-import-not-found", {
+                "game-title" = game.manifest.game_title.clone()
+            }),
+            message: Some(game_path.to_string())
+        }));
+    }
+
+    let Some(version) = get_version(game, &game_path, edition)? else {
+        return Err(Box::new(MainAppMsg::ShowToast {
+            title: tr!("game-import-version-failed", {
+                "game-title" = game.manifest.game_title.clone()
+            }),
+            message: None
+        }));
+    };
+
+    let outdated = matches!(get_diff(game, &game_path, edition)?, Some(Diff { status: DiffStatus::Outdated, .. }));
+
+    let property = format!("games.settings.{}.paths.{}.game", game_info.get_name(), edition);
+
+    config::set(property, game_path.to_string())
+        .map_err(|err| Box::new(MainAppMsg::ShowToast {
+            title: tr!("config-property-update-failed"),
+            message: Some(err.to_string())
+        }))?;
+
+    Ok(ImportGameResult {
+        version,
+        outdated
+    })
+}
@@ -11,9 +11,14 @@ use crate::games;
 use crate::config;
 
 use crate::config::games::settings::prelude::*;
+use crate::config::games::launch::LaunchWrapper;
+use crate::config::games::wine::dll_overrides;
 use crate::games::integrations::standards::prelude::*;
 
 use crate::components::wine::Wine;
+use crate::components::system_wine;
+use crate::components::prefix_dlls::PrefixDlls;
+use crate::components::prefix_tools;
 
 use crate::games::metadata::{
     LauncherMetadata,
@@ -155,18 +160,36 @@ pub fn prepare_folders(game: &Game, info: &CardInfo, paths: &GameEditionPaths, e
 }
 
 #[inline]
-pub fn prepare_bash_command(config: &config::Config, wine: &Wine) -> String {
-    let mut bash_command = String::new();
+pub fn prepare_wrappers(config: &config::Config) -> String {
+    let mut wrappers = Vec::new();
 
-    // [gamemoderun]
-    if config.games.enhancements.gamemode {
-        bash_command = format!("{bash_command} gamemoderun");
+    // [gamescope <options> --]
+    if let Some(gamescope) = config.games.enhancements.gamescope.get_command(config.games.enhancements.hud) {
+        wrappers.push(gamescope);
     }
 
-    // [gamemoderun] '<wine path>'
-    bash_command = format!("{bash_command} '{}'", wine.get_executable().to_string_lossy());
+    // [gamescope <options> --] [gamemoderun]
+    if config.games.enhancements.gamemode && !config.games.launch.wrappers.contains(&LaunchWrapper::Gamemode) {
+        wrappers.push(LaunchWrapper::Gamemode.get_command());
+    }
+
+    // [gamescope <options> --] [gamemoderun] [wrappers]
+    for wrapper in &config.games.launch.wrappers {
+        wrappers.push(wrapper.get_command());
+    }
 
-    bash_command
+    wrappers.join(" ")
+}
+
+#[inline]
+pub fn prepare_wine_command(wine: &Wine) -> String {
+    match wine.get_proton_script() {
+        // '<proton script path>' run
+        Some(script) => format!("'{}' run", script.to_string_lossy()),
+
+        // '<wine path>'
+        None => format!("'{}'", wine.get_executable().to_string_lossy())
+    }
 }
 
 #[inline]
@@ -199,6 +222,25 @@ pub fn prepare_launch_args(config: &config::Config) -> String {
     launch_args
 }
 
+/// Get paths and overridden settings of the game edition
+pub fn get_edition_settings(info: &CardInfo, config: &config::Config) -> anyhow::Result<(GameEditionPaths, GameEditionOverrides)> {
+    let game = unsafe {
+        games::get_unsafe(info.get_name())
+    };
+
+    let settings = config.games.get_game_settings(game)?;
+
+    let Some(paths) = settings.paths.get(info.get_edition()) else {
+        anyhow::bail!("Unable to find {} paths", info.get_title());
+    };
+
+    let overrides = settings.overrides.get(info.get_edition())
+        .cloned()
+        .unwrap_or_default();
+
+    Ok((paths.clone(), overrides))
+}
+
 #[inline]
 #[tracing::instrument]
 pub fn launch_game(info: &CardInfo) -> anyhow::Result<()> {
@@ -208,9 +250,14 @@ pub fn launch_game(info: &CardInfo) -> anyhow::Result<()> {
     };
 
     // Get game settings
-    let config = config::get();
+    let mut config = config::get();
     let settings = config.games.get_game_settings(game)?;
 
+    // Apply game settings overrides on top of global ones
+    if let Some(overrides) = settings.overrides.get(info.get_edition()) {
+        overrides.apply(&mut config);
+    }
+
     // Get game paths
     let Some(paths) = settings.paths.get(info.get_edition()) else {
         anyhow::bail!("Unable to find {} paths", info.get_title());
@@ -234,14 +281,27 @@ pub fn launch_game(info: &CardInfo) -> anyhow::Result<()> {
     )?;
 
     // Get selected wine version
-    let wine = Wine::from_config()?;
+    let wine = Wine::from_version(&config.components.wine.build, &config.components.wine.version)?;
+
+    if !wine.is_downloaded() {
+        anyhow::bail!("Wine version {} is not downloaded", wine.title);
+    }
+
+    if config.games.enhancements.gamescope.enabled && system_wine::find_executable("gamescope").is_none() {
+        anyhow::bail!("Gamescope is enabled but not installed");
+    }
 
     // Prepare game launching command
-    let bash_command = prepare_bash_command(&config, &wine);
+    let wrappers = prepare_wrappers(&config);
+    let wine_command = prepare_wine_command(&wine);
     let windows_command = prepare_windows_command(&config, info, &options);
     let launch_args = prepare_launch_args(&config);
 
-    let launch_command = format!("{bash_command} {windows_command} {launch_args}");
+    let launch_command = config.games.launch.build_command(
+        &wrappers,
+        &wine_command,
+        &format!("{windows_command} {launch_args}")
+    )?;
 
     // Prepare game launcher
     let mut command = Command::new("bash");
@@ -251,15 +311,78 @@ pub fn launch_game(info: &CardInfo) -> anyhow::Result<()> {
     command.arg(launch_command);
 
     // Setup command environment
-    command.env("WINEARCH", "win64");
-    command.env("WINEPREFIX", config.components.wine.prefix.path);
+    let prefix = paths.get_prefix(&config);
+
+    // Settings from the config take priority over integration's ones
+    let mut dll_overrides = options.dll_overrides.clone();
+    let mut registry = options.registry.clone();
+
+    dll_overrides.extend(config.games.wine.dll_overrides.clone());
+    registry.extend(config.games.wine.registry.clone());
+
+    prefix_tools::apply_registry(&wine, &prefix, &registry)?;
+
+    let mut overrides = Vec::new();
+
+    if wine.get_proton_script().is_some() {
+        // Proton keeps the wine prefix in the "pfx" subfolder of its data folder
+        std::fs::create_dir_all(&prefix)?;
+
+        let steam_folder = system_wine::get_steam_folders()
+            .into_iter()
+            .next()
+            .unwrap_or_default();
+
+        command.env("STEAM_COMPAT_DATA_PATH", prefix);
+        command.env("STEAM_COMPAT_CLIENT_INSTALL_PATH", steam_folder);
+
+        // Proton ships its own vkd3d-proton and dxvk-nvapi builds
+        if config.components.nvapi.enabled {
+            command.env("PROTON_ENABLE_NVAPI", "1");
+        }
+    }
+
+    else {
+        // Override DLLs of the components installed to the prefix
+        let components = [
+            (PrefixDlls::Vkd3d, config.components.vkd3d.enabled),
+            (PrefixDlls::Nvapi, config.components.nvapi.enabled)
+        ];
 
-    command.envs(config.games.enhancements.hud.get_env_vars(false));
+        for (component, enabled) in components {
+            if enabled && component.get_version(&prefix)?.is_some() {
+                overrides.push(component.get_overrides());
+            }
+        }
+
+        if config.components.nvapi.enabled {
+            command.env("DXVK_ENABLE_NVAPI", "1");
+        }
+
+        command.env("WINEARCH", "win64");
+        command.env("WINEPREFIX", prefix);
+    }
+
+    // Proton merges this variable with its own overrides
+    overrides.extend(dll_overrides::get_wine_overrides(&dll_overrides));
+
+    if !overrides.is_empty() {
+        command.env("WINEDLLOVERRIDES", overrides.join(";"));
+    }
+
+    command.envs(config.games.enhancements.hud.get_env_vars(
+        &config.games.enhancements.hud_options,
+        config.games.enhancements.gamescope.enabled
+    ));
     command.envs(config.games.enhancements.fsr.get_env_vars());
 
     command.envs(config.games.wine.sync.get_env_vars());
     command.envs(config.games.wine.language.get_env_vars());
-    command.envs(config.games.wine.shared_libraries.get_env_vars(wine.get_folder()));
+
+    // System and Proton builds find their libraries by themselves
+    if wine.is_component() {
+        command.envs(config.games.wine.shared_libraries.get_env_vars(wine.get_folder()));
+    }
 
     command.envs(config.games.environment);
 
@@ -268,6 +391,9 @@ pub fn launch_game(info: &CardInfo) -> anyhow::Result<()> {
 
     tracing::debug!("{:?}", &command);
 
+    // Mark the game as running until this function returns
+    let _running_game = games::mark_running();
+
     // Get game starting timestamp
     let started_at = chrono::Utc::now().timestamp();
 
@@ -16,10 +16,23 @@ use crate::tr;
 use crate::config;
 use crate::games;
 
+use crate::utils::{
+    pretty_bytes,
+    get_folder_size
+};
+
 use crate::components::wine::*;
 use crate::components::dxvk::*;
+use crate::components::vkd3d::*;
+use crate::components::nvapi::*;
+use crate::components::prefix_dlls::PrefixDlls;
+use crate::components::prefix_tools::PrefixVerb;
+use crate::components::updates::ComponentsUpdates;
+
+use crate::config::components::updates::UpdatePolicy;
 
 use crate::config::games::settings::edition_addons::GameEditionAddon;
+use crate::config::games::settings::edition_overrides::ComponentVersion;
 
 use crate::games::metadata::LauncherMetadata;
 use crate::games::integrations::standards::addons::{
@@ -41,6 +54,21 @@ use crate::ui::windows::game_addons_manager::{
     GameAddonsManagerAppMsg
 };
 
+use crate::ui::windows::game_settings::{
+    GameSettingsApp,
+    GameSettingsAppMsg
+};
+
+use crate::ui::windows::components_manager::{
+    ComponentsManagerApp,
+    ComponentsManagerAppMsg
+};
+
+use crate::ui::windows::prefix_manager::{
+    PrefixManagerApp,
+    PrefixManagerAppMsg
+};
+
 use crate::ui::components::game_card::{
     CardInfo,
     CardComponentInput,
@@ -61,7 +89,11 @@ use crate::ui::components::tasks_queue::{
     TasksQueueComponentOutput,
 
     apply_dxvk_task::ApplyDxvkQueuedTask,
-    create_prefix_task::CreatePrefixQueuedTask
+    apply_dlls_task::ApplyDllsQueuedTask,
+    install_verbs_task::InstallVerbsQueuedTask,
+    snapshot_prefix_task::{SnapshotPrefixQueuedTask, SnapshotAction},
+    create_prefix_task::CreatePrefixQueuedTask,
+    delete_files_task::DeleteFilesQueuedTask
 };
 
 use crate::LAUNCHER_FOLDER;
@@ -69,15 +101,22 @@ use crate::CONFIG_FILE;
 use crate::DEBUG_FILE;
 
 pub mod launch_game;
+pub mod disk_space;
 pub mod kill_game;
 pub mod download_game_task;
+pub mod import_game;
+pub mod move_game_task;
 pub mod download_addon_task;
 pub mod uninstall_addon_task;
+pub mod uninstall_game_task;
 pub mod verify_game_task;
 
 pub static mut WINDOW: Option<adw::Window> = None;
 pub static mut PREFERENCES_APP: Option<AsyncController<PreferencesApp>> = None;
 pub static mut GAME_ADDONS_MANAGER_APP: Option<AsyncController<GameAddonsManagerApp>> = None;
+pub static mut GAME_SETTINGS_APP: Option<AsyncController<GameSettingsApp>> = None;
+pub static mut COMPONENTS_MANAGER_APP: Option<AsyncController<ComponentsManagerApp>> = None;
+pub static mut PREFIX_MANAGER_APP: Option<AsyncController<PrefixManagerApp>> = None;
 pub static mut ABOUT_DIALOG: Option<Controller<AboutDialog>> = None;
 
 pub struct MainApp {
@@ -102,6 +141,15 @@ pub struct MainApp {
     outdated_games_indexes: HashMap<CardInfo, DynamicIndex>,
     available_games_indexes: HashMap<CardInfo, DynamicIndex>,
 
+    /// Games which have unfinished tasks in the queue
+    queued_tasks_games: HashSet<CardInfo>,
+
+    /// Outdated games which are queued for tasks not updating them
+    queued_outdated_games: HashSet<CardInfo>,
+
+    /// Games which are queued to be uninstalled
+    uninstalling_games: HashSet<CardInfo>,
+
     tasks_queue: AsyncController<TasksQueueComponent>
 }
 
@@ -115,10 +163,22 @@ pub enum MainAppMsg {
         running: bool
     },
 
+    SetPreDownloadVersion {
+        game_info: CardInfo,
+        version: Option<String>
+    },
+
     HideDetails,
 
     OpenPreferences,
     OpenAddonsManager(CardInfo),
+    OpenGameSettings(CardInfo),
+    OpenComponentsManager,
+    OpenPrefixManager {
+        prefix: PathBuf,
+        wine: Option<ComponentVersion>,
+        dxvk: Option<ComponentVersion>
+    },
 
     SetEnabledAddons {
         game: CardInfo,
@@ -129,9 +189,52 @@ pub enum MainAppMsg {
     HideTasksFlap,
     ToggleTasksFlap,
 
-    AddDownloadGameTask(CardInfo),
+    AddDownloadGameTask {
+        game_info: CardInfo,
+        check_space: bool
+    },
+
+    AddPreDownloadGameTask {
+        game_info: CardInfo,
+        check_space: bool
+    },
+
+    ImportGame(CardInfo),
+
+    ImportGameFolder {
+        game_info: CardInfo,
+        path: PathBuf
+    },
+
     AddVerifyGameTask(CardInfo),
-    FinishQueuedTask(CardInfo),
+
+    MoveGame(CardInfo),
+
+    AddMoveGameTask {
+        game_info: CardInfo,
+        folder: move_game_task::GameFolder,
+        path: PathBuf
+    },
+
+    UninstallGame(CardInfo),
+
+    ShowUninstallGameDialog {
+        game_info: CardInfo,
+        size: u64,
+
+        /// Wine prefix which is used by other games and is not deleted
+        shared_prefix: Option<PathBuf>
+    },
+
+    AddUninstallGameTask {
+        game_info: CardInfo,
+        delete_metadata: bool
+    },
+
+    FinishQueuedTask {
+        info: CardInfo,
+        success: bool
+    },
 
     AddDownloadAddonTask {
         game_info: CardInfo,
@@ -147,11 +250,41 @@ pub enum MainAppMsg {
 
     AddDownloadWineTask(Wine),
     AddDownloadDxvkTask(Dxvk),
-    AddApplyDxvkTask(Dxvk),
+    AddApplyDxvkTask {
+        dxvk: Dxvk,
+        prefix: PathBuf
+    },
+
+    AddDownloadVkd3dTask(Vkd3d),
+    AddDownloadNvapiTask(Nvapi),
+    AddApplyDllsTask {
+        component: PrefixDlls,
+        folder: PathBuf,
+        version: String,
+        prefix: PathBuf
+    },
+
+    AddDeleteFilesTask(Vec<PathBuf>),
+
+    AddSnapshotPrefixTask {
+        prefix: PathBuf,
+        action: SnapshotAction
+    },
+
+    AddInstallVerbsTask {
+        wine: Wine,
+        verbs: Vec<PrefixVerb>,
+        prefix: PathBuf
+    },
+
+    AskUpdateComponents(ComponentsUpdates),
+    UpdateComponents(ComponentsUpdates),
 
     AddCreatePrefixTask {
         path: PathBuf,
-        install_corefonts: bool
+        install_corefonts: bool,
+        wine: Option<ComponentVersion>,
+        dxvk: Option<ComponentVersion>
     },
 
     LaunchGame(CardInfo),
@@ -170,6 +303,9 @@ relm4::new_stateless_action!(LauncherFolder, WindowActionGroup, "launcher_folder
 relm4::new_stateless_action!(ConfigFile, WindowActionGroup, "config_file");
 relm4::new_stateless_action!(DebugFile, WindowActionGroup, "debug_file");
 
+relm4::new_stateless_action!(ComponentsManager, WindowActionGroup, "components_manager");
+relm4::new_stateless_action!(PrefixManager, WindowActionGroup, "prefix_manager");
+
 relm4::new_stateless_action!(About, WindowActionGroup, "about");
 
 #[relm4::component(pub, async)]
@@ -186,6 +322,11 @@ impl SimpleAsyncComponent for MainApp {
                 &tr!("main-menu-debug-file")      => DebugFile,
             },
 
+            section! {
+                &tr!("main-menu-components")  => ComponentsManager,
+                &tr!("main-menu-wine-prefix") => PrefixManager
+            },
+
             section! {
                 &tr!("main-menu-about") => About
             }
@@ -423,12 +564,24 @@ impl SimpleAsyncComponent for MainApp {
                     GameDetailsComponentOutput::HideDetails => MainAppMsg::HideDetails,
                     GameDetailsComponentOutput::ShowTasksFlap => MainAppMsg::ShowTasksFlap,
 
-                    GameDetailsComponentOutput::DownloadGame(info)
-                        => MainAppMsg::AddDownloadGameTask(info),
+                    GameDetailsComponentOutput::DownloadGame(game_info)
+                        => MainAppMsg::AddDownloadGameTask { game_info, check_space: true },
+
+                    GameDetailsComponentOutput::ImportGame(game_info)
+                        => MainAppMsg::ImportGame(game_info),
+
+                    GameDetailsComponentOutput::PreDownloadGame(game_info)
+                        => MainAppMsg::AddPreDownloadGameTask { game_info, check_space: true },
 
                     GameDetailsComponentOutput::VerifyGame(info)
                         => MainAppMsg::AddVerifyGameTask(info),
 
+                    GameDetailsComponentOutput::MoveGame(info)
+                        => MainAppMsg::MoveGame(info),
+
+                    GameDetailsComponentOutput::UninstallGame(info)
+                        => MainAppMsg::UninstallGame(info),
+
                     GameDetailsComponentOutput::LaunchGame(info)
                         => MainAppMsg::LaunchGame(info),
 
@@ -438,6 +591,9 @@ impl SimpleAsyncComponent for MainApp {
                     GameDetailsComponentOutput::OpenAddonsManager(info)
                         => MainAppMsg::OpenAddonsManager(info),
 
+                    GameDetailsComponentOutput::OpenGameSettings(info)
+                        => MainAppMsg::OpenGameSettings(info),
+
                     GameDetailsComponentOutput::ShowToast { title, message }
                         => MainAppMsg::ShowToast { title, message }
                 }),
@@ -450,6 +606,10 @@ impl SimpleAsyncComponent for MainApp {
             outdated_games_indexes: HashMap::new(),
             available_games_indexes: HashMap::new(),
 
+            queued_tasks_games: HashSet::new(),
+            queued_outdated_games: HashSet::new(),
+            uninstalling_games: HashSet::new(),
+
             running_games: FactoryVecDeque::builder()
                 .launch_default()
                 .forward(sender.input_sender(), |output: CardComponentOutput| -> MainAppMsg {
@@ -499,8 +659,8 @@ impl SimpleAsyncComponent for MainApp {
             tasks_queue: TasksQueueComponent::builder()
                 .launch(CardInfo::default())
                 .forward(sender.input_sender(), |output| match output {
-                    TasksQueueComponentOutput::TaskFinished(info)
-                        => MainAppMsg::FinishQueuedTask(info),
+                    TasksQueueComponentOutput::TaskFinished { info, success }
+                        => MainAppMsg::FinishQueuedTask { info, success },
 
                     TasksQueueComponentOutput::HideTasksFlap
                         => MainAppMsg::HideTasksFlap,
@@ -537,6 +697,18 @@ impl SimpleAsyncComponent for MainApp {
                 .launch(widgets.window.clone())
                 .forward(sender.input_sender(), std::convert::identity));
 
+            GAME_SETTINGS_APP = Some(GameSettingsApp::builder()
+                .launch(widgets.window.clone())
+                .forward(sender.input_sender(), std::convert::identity));
+
+            COMPONENTS_MANAGER_APP = Some(ComponentsManagerApp::builder()
+                .launch(widgets.window.clone())
+                .forward(sender.input_sender(), std::convert::identity));
+
+            PREFIX_MANAGER_APP = Some(PrefixManagerApp::builder()
+                .launch(widgets.window.clone())
+                .forward(sender.input_sender(), std::convert::identity));
+
             ABOUT_DIALOG = Some(AboutDialog::builder()
                 .transient_for(widgets.window.clone())
                 .launch_with_broker((), &about_dialog_broker)
@@ -578,6 +750,18 @@ impl SimpleAsyncComponent for MainApp {
             }
         })));
 
+        group.add_action::<ComponentsManager>(RelmAction::new_stateless(gtk::glib::clone!(@strong sender => move |_| {
+            sender.input(MainAppMsg::OpenComponentsManager);
+        })));
+
+        group.add_action::<PrefixManager>(RelmAction::new_stateless(gtk::glib::clone!(@strong sender => move |_| {
+            sender.input(MainAppMsg::OpenPrefixManager {
+                prefix: config::get().components.wine.prefix.path,
+                wine: None,
+                dxvk: None
+            });
+        })));
+
         group.add_action::<About>(RelmAction::new_stateless(move |_| {
             about_dialog_broker.send(AboutDialogMsg::Show);
         }));
@@ -649,19 +833,66 @@ impl SimpleAsyncComponent for MainApp {
                 }
 
                 if let Some(dxvk) = init.apply_dxvk {
-                    sender.input(MainAppMsg::AddApplyDxvkTask(dxvk));
+                    sender.input(MainAppMsg::AddApplyDxvkTask {
+                        dxvk,
+                        prefix: config::get().components.wine.prefix.path
+                    });
+                    sender.input(MainAppMsg::ShowTasksFlap);
+                }
+
+                if let Some(vkd3d) = init.download_vkd3d {
+                    sender.input(MainAppMsg::AddDownloadVkd3dTask(vkd3d));
+                    sender.input(MainAppMsg::ShowTasksFlap);
+                }
+
+                if let Some(vkd3d) = init.apply_vkd3d {
+                    sender.input(MainAppMsg::AddApplyDllsTask {
+                        component: PrefixDlls::Vkd3d,
+                        folder: vkd3d.get_folder(),
+                        version: vkd3d.name,
+                        prefix: config::get().components.wine.prefix.path
+                    });
+                    sender.input(MainAppMsg::ShowTasksFlap);
+                }
+
+                if let Some(nvapi) = init.download_nvapi {
+                    sender.input(MainAppMsg::AddDownloadNvapiTask(nvapi));
+                    sender.input(MainAppMsg::ShowTasksFlap);
+                }
+
+                if let Some(nvapi) = init.apply_nvapi {
+                    sender.input(MainAppMsg::AddApplyDllsTask {
+                        component: PrefixDlls::Nvapi,
+                        folder: nvapi.get_folder(),
+                        version: nvapi.name,
+                        prefix: config::get().components.wine.prefix.path
+                    });
                     sender.input(MainAppMsg::ShowTasksFlap);
                 }
 
                 if let Some(prefix) = init.create_prefix {
                     sender.input(MainAppMsg::AddCreatePrefixTask {
                         path: prefix.path.clone(),
-                        install_corefonts: prefix.install_corefonts
+                        install_corefonts: prefix.install_corefonts,
+                        wine: None,
+                        dxvk: None
                     });
 
                     sender.input(MainAppMsg::ShowTasksFlap);
                 }
 
+                if !init.components_updates.is_empty() {
+                    match config::get().components.updates.policy {
+                        UpdatePolicy::Always => sender.input(MainAppMsg::UpdateComponents(init.components_updates)),
+                        UpdatePolicy::Ask    => sender.input(MainAppMsg::AskUpdateComponents(init.components_updates)),
+                        UpdatePolicy::Never  => ()
+                    }
+                }
+
+                if !init.delete_components.is_empty() {
+                    sender.input(MainAppMsg::AddDeleteFilesTask(init.delete_components));
+                }
+
                 for addon in init.download_addons {
                     sender.input(MainAppMsg::AddDownloadAddonTask {
                         game_info: addon.game_info,
@@ -682,6 +913,7 @@ impl SimpleAsyncComponent for MainApp {
 
                 if !installed {
                     self.game_details.emit(GameDetailsComponentInput::SetStatus(None));
+                    self.game_details.emit(GameDetailsComponentInput::SetPreDownload(None));
                 }
 
                 else {
@@ -714,11 +946,40 @@ impl SimpleAsyncComponent for MainApp {
                             });
                         }
                     }
+
+                    self.game_details.emit(GameDetailsComponentInput::SetPreDownload(None));
+
+                    // Game diff is requested from the integration, which can take a while
+                    let game_path = paths.game.to_string_lossy().to_string();
+                    let sender = sender.clone();
+
+                    std::thread::spawn(move || {
+                        match download_game_task::get_pre_download_version(&info, &game_path) {
+                            Ok(version) => sender.input(MainAppMsg::SetPreDownloadVersion {
+                                game_info: info,
+                                version
+                            }),
+
+                            Err(err) => sender.input(MainAppMsg::ShowToast {
+                                title: tr!("game-find-diff-failed", {
+                                    "game-title" = info.get_title()
+                                }),
+                                message: Some(err.to_string())
+                            })
+                        }
+                    });
                 }
 
                 self.leaflet.navigate(adw::NavigationDirection::Forward);
             }
 
+            MainAppMsg::SetPreDownloadVersion { game_info, version } => {
+                // Details could be opened for another game already
+                if self.game_details_info == game_info {
+                    self.game_details.emit(GameDetailsComponentInput::SetPreDownload(version));
+                }
+            }
+
             MainAppMsg::HideDetails => {
                 self.leaflet.navigate(adw::NavigationDirection::Back);
             }
@@ -757,6 +1018,37 @@ impl SimpleAsyncComponent for MainApp {
                 }
             }
 
+            MainAppMsg::OpenGameSettings(game_info) => unsafe {
+                let controller = GAME_SETTINGS_APP.as_ref()
+                    .unwrap_unchecked();
+
+                controller.emit(GameSettingsAppMsg::SetGameInfo(game_info));
+
+                controller.widget().present();
+            }
+
+            MainAppMsg::OpenComponentsManager => unsafe {
+                let controller = COMPONENTS_MANAGER_APP.as_ref()
+                    .unwrap_unchecked();
+
+                controller.emit(ComponentsManagerAppMsg::Refresh);
+
+                controller.widget().present();
+            }
+
+            MainAppMsg::OpenPrefixManager { prefix, wine, dxvk } => unsafe {
+                let controller = PREFIX_MANAGER_APP.as_ref()
+                    .unwrap_unchecked();
+
+                controller.emit(PrefixManagerAppMsg::SetPrefix {
+                    prefix,
+                    wine,
+                    dxvk
+                });
+
+                controller.widget().present();
+            }
+
             // FIXME: doesn't look really safe
             MainAppMsg::SetEnabledAddons { game, addons } => {
                 let property = format!("games.settings.{}.addons.{}", game.get_name(), game.get_edition());
@@ -779,13 +1071,35 @@ impl SimpleAsyncComponent for MainApp {
                 self.flap.set_reveal_flap(!self.flap.reveals_flap());
             }
 
-            MainAppMsg::AddDownloadGameTask(game_info) => {
+            MainAppMsg::AddDownloadGameTask { game_info, check_space } => {
                 let config = config::get();
 
                 match download_game_task::get_download_game_task(&game_info, &config) {
                     Ok(result) => {
+                        if check_space {
+                            // Path of not installed games can be changed before downloading
+                            let destination_property = self.available_games_indexes.contains_key(&game_info)
+                                .then(|| format!("games.settings.{}.paths.{}.game", game_info.get_name(), game_info.get_edition()));
+
+                            let info = game_info.clone();
+
+                            let verified = disk_space::verify_task(&result.game_task, destination_property, &sender, move |check_space| {
+                                MainAppMsg::AddDownloadGameTask {
+                                    game_info: info.clone(),
+                                    check_space
+                                }
+                            });
+
+                            if !verified {
+                                return;
+                            }
+                        }
+
                         self.tasks_queue.emit(TasksQueueComponentInput::AddTask(result.game_task));
 
+                        self.queued_tasks_games.insert(game_info.clone());
+                        self.queued_outdated_games.remove(&game_info);
+
                         if let Some(index) = self.available_games_indexes.get(&game_info) {
                             self.available_games.guard().remove(index.current_index());
                             self.available_games_indexes.remove(&game_info);
@@ -821,6 +1135,110 @@ impl SimpleAsyncComponent for MainApp {
                 }
             }
 
+            MainAppMsg::AddPreDownloadGameTask { game_info, check_space } => {
+                let config = config::get();
+
+                match download_game_task::get_pre_download_game_task(&game_info, &config) {
+                    Ok(task) => {
+                        if check_space {
+                            let info = game_info.clone();
+
+                            let verified = disk_space::verify_task(&task, None, &sender, move |check_space| {
+                                MainAppMsg::AddPreDownloadGameTask {
+                                    game_info: info.clone(),
+                                    check_space
+                                }
+                            });
+
+                            if !verified {
+                                return;
+                            }
+                        }
+
+                        // Game stays installed while its next version is downloading
+                        self.tasks_queue.emit(TasksQueueComponentInput::AddTask(task));
+
+                        self.queued_tasks_games.insert(game_info);
+                    }
+
+                    Err(err) => sender.input(*err)
+                }
+            }
+
+            MainAppMsg::ImportGame(game_info) => {
+                let window = unsafe {
+                    WINDOW.as_ref().unwrap_unchecked()
+                };
+
+                let sender = sender.clone();
+
+                gtk::FileDialog::new().select_folder(Some(window), None::<&gtk::gio::Cancellable>, move |result| {
+                    if let Some(path) = result.ok().and_then(|folder| folder.path()) {
+                        sender.input(MainAppMsg::ImportGameFolder {
+                            game_info,
+                            path
+                        });
+                    }
+                });
+            }
+
+            MainAppMsg::ImportGameFolder { game_info, path } => {
+                match import_game::import_game(&game_info, &path) {
+                    Ok(result) => {
+                        if let Some(index) = self.available_games_indexes.get(&game_info) {
+                            self.available_games.guard().remove(index.current_index());
+                            self.available_games_indexes.remove(&game_info);
+                        }
+
+                        #[allow(clippy::map_entry)]
+                        if result.outdated {
+                            if !self.outdated_games_indexes.contains_key(&game_info) {
+                                self.outdated_games_indexes.insert(game_info.clone(), self.outdated_games.guard().push_back(game_info.clone()));
+
+                                self.outdated_games.broadcast(CardComponentInput::SetInstalled(false));
+                            }
+                        }
+
+                        else if !self.installed_games_indexes.contains_key(&game_info) {
+                            self.installed_games_indexes.insert(game_info.clone(), self.installed_games.guard().push_back(game_info.clone()));
+                        }
+
+                        sender.input(MainAppMsg::HideDetails);
+
+                        // Offer to verify imported files
+
+                        let window = unsafe {
+                            WINDOW.as_ref().unwrap_unchecked()
+                        };
+
+                        let dialog = adw::MessageDialog::new(
+                            Some(window),
+                            Some(&tr!("game-imported", {
+                                "game-title" = game_info.get_title(),
+                                "version" = result.version
+                            })),
+                            Some(&tr!("game-imported-verify"))
+                        );
+
+                        dialog.add_response("close", &tr!("dialog-close"));
+                        dialog.add_response("verify", &tr!("dialog-verify"));
+
+                        dialog.set_response_appearance("verify", adw::ResponseAppearance::Suggested);
+
+                        let sender = sender.clone();
+
+                        dialog.connect_response(Some("verify"), move |_, _| {
+                            sender.input(MainAppMsg::AddVerifyGameTask(game_info.clone()));
+                            sender.input(MainAppMsg::ShowTasksFlap);
+                        });
+
+                        dialog.present();
+                    }
+
+                    Err(err) => sender.input(*err)
+                }
+            }
+
             MainAppMsg::AddVerifyGameTask(game_info) => {
                 let config = config::get();
 
@@ -828,6 +1246,8 @@ impl SimpleAsyncComponent for MainApp {
                     Ok(task) => {
                         self.tasks_queue.emit(TasksQueueComponentInput::AddTask(task));
 
+                        self.queued_tasks_games.insert(game_info.clone());
+
                         if let Some(index) = self.installed_games_indexes.get(&game_info) {
                             self.installed_games.guard().remove(index.current_index());
                             self.installed_games_indexes.remove(&game_info);
@@ -846,13 +1266,258 @@ impl SimpleAsyncComponent for MainApp {
                 }
             }
 
-            MainAppMsg::FinishQueuedTask(info) => {
+            MainAppMsg::MoveGame(game_info) => {
+                let window = unsafe {
+                    WINDOW.as_ref().unwrap_unchecked()
+                };
+
+                let dialog = adw::MessageDialog::new(
+                    Some(window),
+                    Some(&tr!("game-move", {
+                        "game-title" = game_info.get_title()
+                    })),
+                    Some(&tr!("game-move-description"))
+                );
+
+                dialog.add_response("cancel", &tr!("dialog-cancel"));
+
+                for folder in move_game_task::GameFolder::list() {
+                    dialog.add_response(folder.name(), &tr!(&format!("game-move-{}", folder.name())));
+                }
+
+                let sender = sender.clone();
+
+                dialog.connect_response(None, move |_, response| {
+                    let Some(folder) = move_game_task::GameFolder::list().iter().find(|folder| folder.name() == response).copied() else {
+                        return;
+                    };
+
+                    let sender = sender.clone();
+                    let game_info = game_info.clone();
+
+                    gtk::FileDialog::new().select_folder(Some(window), None::<&gtk::gio::Cancellable>, move |result| {
+                        if let Some(path) = result.ok().and_then(|folder| folder.path()) {
+                            sender.input(MainAppMsg::AddMoveGameTask {
+                                game_info,
+                                folder,
+                                path
+                            });
+                        }
+                    });
+                });
+
+                dialog.present();
+            }
+
+            MainAppMsg::AddMoveGameTask { game_info, folder, path } => {
+                // Files can't be moved while they're used
+                if games::is_any_running() {
+                    sender.input(MainAppMsg::ShowToast {
+                        title: tr!("game-move-game-running"),
+                        message: None
+                    });
+
+                    return;
+                }
+
+                if self.queued_tasks_games.contains(&game_info) {
+                    sender.input(MainAppMsg::ShowToast {
+                        title: tr!("game-move-tasks-queued", {
+                            "game-title" = game_info.get_title()
+                        }),
+                        message: None
+                    });
+
+                    return;
+                }
+
+                let config = config::get();
+
+                match move_game_task::get_move_game_task(&game_info, folder, path, &config) {
+                    Ok(task) => {
+                        self.tasks_queue.emit(TasksQueueComponentInput::AddTask(task));
+
+                        self.queued_tasks_games.insert(game_info.clone());
+
+                        if let Some(index) = self.installed_games_indexes.get(&game_info) {
+                            self.installed_games.guard().remove(index.current_index());
+                            self.installed_games_indexes.remove(&game_info);
+                        }
+
+                        // Moved game is still outdated
+                        else if let Some(index) = self.outdated_games_indexes.get(&game_info) {
+                            self.outdated_games.guard().remove(index.current_index());
+                            self.outdated_games_indexes.remove(&game_info);
+
+                            self.queued_outdated_games.insert(game_info.clone());
+                        }
+
+                        #[allow(clippy::map_entry)]
+                        if !self.queued_games_indexes.contains_key(&game_info) {
+                            self.queued_games_indexes.insert(game_info.clone(), self.queued_games.guard().push_back(game_info.clone()));
+
+                            self.queued_games.broadcast(CardComponentInput::SetInstalled(false));
+                            self.queued_games.broadcast(CardComponentInput::SetClickable(false));
+                        }
+
+                        sender.input(MainAppMsg::HideDetails);
+                        sender.input(MainAppMsg::ShowTasksFlap);
+                    }
+
+                    Err(err) => sender.input(*err)
+                }
+            }
+
+            MainAppMsg::UninstallGame(game_info) => {
+                let config = config::get();
+
+                let folders = match uninstall_game_task::get_game_folders(&game_info, &config) {
+                    Ok(folders) => folders,
+                    Err(err) => {
+                        sender.input(*err);
+
+                        return;
+                    }
+                };
+
+                let shared_prefix = match uninstall_game_task::get_shared_prefix(&game_info, &config) {
+                    Ok(prefix) => prefix,
+                    Err(err) => {
+                        sender.input(*err);
+
+                        return;
+                    }
+                };
+
+                // Folders can be large so their size is calculated in background
+                let sender = sender.clone();
+
+                std::thread::spawn(move || {
+                    let size = folders.iter()
+                        .filter(|folder| folder.exists())
+                        .map(|folder| get_folder_size(folder).unwrap_or_default())
+                        .sum::<u64>();
+
+                    sender.input(MainAppMsg::ShowUninstallGameDialog {
+                        game_info,
+                        size,
+                        shared_prefix
+                    });
+                });
+            }
+
+            MainAppMsg::ShowUninstallGameDialog { game_info, size, shared_prefix } => {
+                let window = unsafe {
+                    WINDOW.as_ref().unwrap_unchecked()
+                };
+
+                let mut description = tr!("game-uninstall-description", {
+                    "size" = pretty_bytes(size)
+                });
+
+                if let Some(prefix) = shared_prefix {
+                    description = format!("{description}\n\n{}", tr!("game-uninstall-shared-prefix", {
+                        "path" = prefix.to_string_lossy().to_string()
+                    }));
+                }
+
+                let dialog = adw::MessageDialog::new(
+                    Some(window),
+                    Some(&tr!("game-uninstall", {
+                        "game-title" = game_info.get_title()
+                    })),
+                    Some(&description)
+                );
+
+                let delete_metadata = gtk::CheckButton::with_label(&tr!("game-uninstall-delete-metadata"));
+
+                dialog.set_extra_child(Some(&delete_metadata));
+
+                dialog.add_response("cancel", &tr!("dialog-cancel"));
+                dialog.add_response("uninstall", &tr!("dialog-uninstall"));
+
+                dialog.set_response_appearance("uninstall", adw::ResponseAppearance::Destructive);
+
+                let sender = sender.clone();
+
+                dialog.connect_response(Some("uninstall"), move |_, _| {
+                    sender.input(MainAppMsg::AddUninstallGameTask {
+                        game_info: game_info.clone(),
+                        delete_metadata: delete_metadata.is_active()
+                    });
+                });
+
+                dialog.present();
+            }
+
+            MainAppMsg::AddUninstallGameTask { game_info, delete_metadata } => {
+                let config = config::get();
+
+                match uninstall_game_task::get_uninstall_game_task(&game_info, delete_metadata, &config) {
+                    Ok(task) => {
+                        self.tasks_queue.emit(TasksQueueComponentInput::AddTask(task));
+
+                        self.queued_tasks_games.insert(game_info.clone());
+                        self.uninstalling_games.insert(game_info.clone());
+
+                        // Game is moved to the available ones when its files are deleted
+                        if let Some(index) = self.installed_games_indexes.get(&game_info) {
+                            self.installed_games.guard().remove(index.current_index());
+                            self.installed_games_indexes.remove(&game_info);
+                        }
+
+                        else if let Some(index) = self.outdated_games_indexes.get(&game_info) {
+                            self.outdated_games.guard().remove(index.current_index());
+                            self.outdated_games_indexes.remove(&game_info);
+
+                            self.queued_outdated_games.insert(game_info.clone());
+                        }
+
+                        #[allow(clippy::map_entry)]
+                        if !self.queued_games_indexes.contains_key(&game_info) {
+                            self.queued_games_indexes.insert(game_info.clone(), self.queued_games.guard().push_back(game_info.clone()));
+
+                            self.queued_games.broadcast(CardComponentInput::SetInstalled(false));
+                            self.queued_games.broadcast(CardComponentInput::SetClickable(false));
+                        }
+
+                        sender.input(MainAppMsg::HideDetails);
+                        sender.input(MainAppMsg::ShowTasksFlap);
+                    }
+
+                    Err(err) => sender.input(*err)
+                }
+            }
+
+            MainAppMsg::FinishQueuedTask { info, success } => {
+                self.queued_tasks_games.remove(&info);
+
                 if let Some(index) = self.queued_games_indexes.get(&info) {
                     self.queued_games.guard().remove(index.current_index());
                     self.queued_games_indexes.remove(&info);
 
+                    let uninstalled = self.uninstalling_games.remove(&info) && success;
+                    let outdated = self.queued_outdated_games.remove(&info);
+
+                    // Game files are deleted only if the uninstall task succeeded
                     #[allow(clippy::map_entry)]
-                    if !self.installed_games_indexes.contains_key(&info) {
+                    if uninstalled {
+                        if !self.available_games_indexes.contains_key(&info) {
+                            self.available_games_indexes.insert(info.clone(), self.available_games.guard().push_back(info));
+
+                            self.available_games.broadcast(CardComponentInput::SetInstalled(false));
+                        }
+                    }
+
+                    else if outdated {
+                        if !self.outdated_games_indexes.contains_key(&info) {
+                            self.outdated_games_indexes.insert(info.clone(), self.outdated_games.guard().push_back(info));
+
+                            self.outdated_games.broadcast(CardComponentInput::SetInstalled(false));
+                        }
+                    }
+
+                    else if !self.installed_games_indexes.contains_key(&info) {
                         self.installed_games_indexes.insert(info.clone(), self.installed_games.guard().push_back(info));
                     }
                 }
@@ -873,6 +1538,8 @@ impl SimpleAsyncComponent for MainApp {
                     Ok(task) => {
                         // TODO: should I move game to "queued"?
                         self.tasks_queue.emit(TasksQueueComponentInput::AddTask(task));
+
+                        self.queued_tasks_games.insert(game_info);
                     }
 
                     Err(err) => sender.input(*err)
@@ -894,6 +1561,8 @@ impl SimpleAsyncComponent for MainApp {
                     Ok(task) => {
                         // TODO: should I move game to "queued"?
                         self.tasks_queue.emit(TasksQueueComponentInput::AddTask(task));
+
+                        self.queued_tasks_games.insert(game_info);
                     }
 
                     Err(err) => sender.input(*err)
@@ -922,7 +1591,15 @@ impl SimpleAsyncComponent for MainApp {
                 })));
             }
 
-            MainAppMsg::AddApplyDxvkTask(version) => {
+            MainAppMsg::AddApplyDxvkTask { dxvk: version, prefix } => {
+                // Broken DXVK can be reverted by restoring the snapshot
+                if config::get().components.wine.prefix.snapshots.enabled && prefix.exists() {
+                    self.tasks_queue.emit(TasksQueueComponentInput::AddTask(Box::new(SnapshotPrefixQueuedTask {
+                        prefix_path: prefix.clone(),
+                        action: SnapshotAction::Create
+                    })));
+                }
+
                 self.tasks_queue.emit(TasksQueueComponentInput::AddTask(Box::new(ApplyDxvkQueuedTask {
                     card_info: CardInfo::Component {
                         name: version.name.clone(),
@@ -930,18 +1607,271 @@ impl SimpleAsyncComponent for MainApp {
                         developer: String::new()
                     },
                     dxvk_version: version,
-                    prefix_path: config::get().components.wine.prefix.path
+                    prefix_path: prefix
+                })));
+            }
+
+            MainAppMsg::AddDownloadVkd3dTask(version) => {
+                self.tasks_queue.emit(TasksQueueComponentInput::AddTask(Box::new(DownloadVkd3dQueuedTask {
+                    card_info: CardInfo::Component {
+                        name: version.name.clone(),
+                        title: version.title.clone(),
+                        developer: String::new()
+                    },
+                    version
+                })));
+            }
+
+            MainAppMsg::AddDownloadNvapiTask(version) => {
+                self.tasks_queue.emit(TasksQueueComponentInput::AddTask(Box::new(DownloadNvapiQueuedTask {
+                    card_info: CardInfo::Component {
+                        name: version.name.clone(),
+                        title: version.title.clone(),
+                        developer: String::new()
+                    },
+                    version
                 })));
             }
 
-            MainAppMsg::AddCreatePrefixTask { path, install_corefonts } => {
+            MainAppMsg::AddApplyDllsTask { component, folder, version, prefix } => {
+                self.tasks_queue.emit(TasksQueueComponentInput::AddTask(Box::new(ApplyDllsQueuedTask {
+                    card_info: CardInfo::Component {
+                        name: version.clone(),
+                        title: version.clone(),
+                        developer: String::new()
+                    },
+                    component,
+                    folder,
+                    version,
+                    prefix_path: prefix
+                })));
+            }
+
+            MainAppMsg::AddDeleteFilesTask(paths) => {
+                self.tasks_queue.emit(TasksQueueComponentInput::AddTask(Box::new(DeleteFilesQueuedTask {
+                    card_info: None,
+                    paths
+                })));
+            }
+
+            MainAppMsg::AddSnapshotPrefixTask { prefix, action } => {
+                self.tasks_queue.emit(TasksQueueComponentInput::AddTask(Box::new(SnapshotPrefixQueuedTask {
+                    prefix_path: prefix,
+                    action
+                })));
+            }
+
+            MainAppMsg::AddInstallVerbsTask { wine, verbs, prefix } => {
+                self.tasks_queue.emit(TasksQueueComponentInput::AddTask(Box::new(InstallVerbsQueuedTask {
+                    wine,
+                    verbs,
+                    prefix_path: prefix
+                })));
+            }
+
+            MainAppMsg::AskUpdateComponents(updates) => {
+                let window = unsafe {
+                    WINDOW.as_ref().unwrap_unchecked()
+                };
+
+                let dialog = adw::MessageDialog::new(
+                    Some(window),
+                    Some(&tr!("components-updates-available")),
+                    Some(&tr!("components-updates-available-description", {
+                        "components" = updates.titles().join(", ")
+                    }))
+                );
+
+                dialog.add_response("close", &tr!("dialog-close"));
+                dialog.add_response("update", &tr!("dialog-update"));
+
+                dialog.set_response_appearance("update", adw::ResponseAppearance::Suggested);
+
+                let sender = sender.clone();
+
+                dialog.connect_response(Some("update"), move |_, _| {
+                    sender.input(MainAppMsg::UpdateComponents(updates.clone()));
+                });
+
+                dialog.present();
+            }
+
+            MainAppMsg::UpdateComponents(updates) => {
+                let config = config::get();
+
+                let wine_version = updates.wine.as_ref()
+                    .map(|wine| wine.name.as_str())
+                    .unwrap_or(&config.components.wine.version);
+
+                // Prefixes which should be updated, and if DXVK should be
+                // applied to them. Proton uses its own builds of the components
+                let mut prefixes = Vec::new();
+
+                if !is_proton(wine_version) {
+                    prefixes.push((config.components.wine.prefix.path.clone(), true));
+                }
+
+                for (prefix, overrides) in config.games.get_editions_prefixes() {
+                    let edition_wine = overrides.wine.as_ref()
+                        .map(|wine| wine.version.as_str())
+                        .unwrap_or(wine_version);
+
+                    // Editions with their own DXVK version keep it
+                    if !is_proton(edition_wine) && !prefixes.iter().any(|(path, _)| path == &prefix) {
+                        prefixes.push((prefix, overrides.dxvk.is_none()));
+                    }
+                }
+
+                // Otherwise components will be applied during prefix creation
+                prefixes.retain(|(prefix, _)| prefix.exists());
+
+                let mut versions = Vec::new();
+
+                if let Some(wine) = &updates.wine {
+                    versions.push(("components.wine.version", wine.name.clone()));
+                }
+
+                if let Some(dxvk) = &updates.dxvk {
+                    versions.push(("components.dxvk.version", dxvk.name.clone()));
+                }
+
+                if let Some(vkd3d) = &updates.vkd3d {
+                    versions.push(("components.vkd3d.version", vkd3d.name.clone()));
+                }
+
+                if let Some(nvapi) = &updates.nvapi {
+                    versions.push(("components.nvapi.version", nvapi.name.clone()));
+                }
+
+                for (property, version) in versions {
+                    if let Err(err) = config::set(property, version) {
+                        sender.input(MainAppMsg::ShowToast {
+                            title: tr!("config-property-update-failed"),
+                            message: Some(err.to_string())
+                        });
+
+                        return;
+                    }
+                }
+
+                if let Some(wine) = updates.wine {
+                    if !wine.is_downloaded() {
+                        sender.input(MainAppMsg::AddDownloadWineTask(wine));
+                    }
+                }
+
+                if let Some(dxvk) = updates.dxvk {
+                    if !dxvk.is_downloaded() {
+                        sender.input(MainAppMsg::AddDownloadDxvkTask(dxvk.clone()));
+                    }
+
+                    for (prefix, apply_dxvk) in &prefixes {
+                        if *apply_dxvk {
+                            sender.input(MainAppMsg::AddApplyDxvkTask {
+                                dxvk: dxvk.clone(),
+                                prefix: prefix.clone()
+                            });
+                        }
+                    }
+                }
+
+                if let Some(vkd3d) = updates.vkd3d {
+                    if !vkd3d.is_downloaded() {
+                        sender.input(MainAppMsg::AddDownloadVkd3dTask(vkd3d.clone()));
+                    }
+
+                    for (prefix, _) in &prefixes {
+                        sender.input(MainAppMsg::AddApplyDllsTask {
+                            component: PrefixDlls::Vkd3d,
+                            folder: vkd3d.get_folder(),
+                            version: vkd3d.name.clone(),
+                            prefix: prefix.clone()
+                        });
+                    }
+                }
+
+                if let Some(nvapi) = updates.nvapi {
+                    if !nvapi.is_downloaded() {
+                        sender.input(MainAppMsg::AddDownloadNvapiTask(nvapi.clone()));
+                    }
+
+                    for (prefix, _) in &prefixes {
+                        sender.input(MainAppMsg::AddApplyDllsTask {
+                            component: PrefixDlls::Nvapi,
+                            folder: nvapi.get_folder(),
+                            version: nvapi.name.clone(),
+                            prefix: prefix.clone()
+                        });
+                    }
+                }
+
+                sender.input(MainAppMsg::ShowTasksFlap);
+            }
+
+            MainAppMsg::AddCreatePrefixTask { path, install_corefonts, wine, dxvk } => {
+                // Existing prefix will be updated so it's snapshotted before
+                if config::get().components.wine.prefix.snapshots.enabled && path.exists() {
+                    self.tasks_queue.emit(TasksQueueComponentInput::AddTask(Box::new(SnapshotPrefixQueuedTask {
+                        prefix_path: path.clone(),
+                        action: SnapshotAction::Create
+                    })));
+                }
+
                 self.tasks_queue.emit(TasksQueueComponentInput::AddTask(Box::new(CreatePrefixQueuedTask {
                     path,
-                    install_corefonts
+                    install_corefonts,
+                    wine,
+                    dxvk
                 })));
             }
 
             MainAppMsg::LaunchGame(info) => {
+                let config = config::get();
+
+                let (paths, overrides) = match launch_game::get_edition_settings(&info, &config) {
+                    Ok(settings) => settings,
+
+                    Err(err) => {
+                        sender.input(MainAppMsg::ShowToast {
+                            title: tr!("game-launch-failed", {
+                                "game-title" = info.get_title()
+                            }),
+                            message: Some(err.to_string())
+                        });
+
+                        return;
+                    }
+                };
+
+                let prefix = paths.get_prefix(&config);
+
+                let wine_version = overrides.wine.as_ref()
+                    .map(|wine| wine.version.as_str())
+                    .unwrap_or(&config.components.wine.version);
+
+                // Create wine prefix of the game edition if it doesn't exist yet.
+                // Proton creates its prefix by itself
+                if !prefix.exists() && !is_proton(wine_version) {
+                    sender.input(MainAppMsg::AddCreatePrefixTask {
+                        path: prefix,
+                        install_corefonts: config.components.wine.prefix.install_corefonts,
+                        wine: overrides.wine,
+                        dxvk: overrides.dxvk
+                    });
+
+                    sender.input(MainAppMsg::ShowToast {
+                        title: tr!("game-prefix-creating", {
+                            "game-title" = info.get_title()
+                        }),
+                        message: Some(tr!("game-prefix-creating-description"))
+                    });
+
+                    sender.input(MainAppMsg::HideDetails);
+                    sender.input(MainAppMsg::ShowTasksFlap);
+
+                    return;
+                }
+
                 if let Some(index) = self.installed_games_indexes.get(&info) {
                     self.installed_games.guard().remove(index.current_index());
 
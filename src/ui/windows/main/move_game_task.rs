@@ -0,0 +1,94 @@
+use std::path::PathBuf;
+
+use crate::tr;
+
+use crate::games;
+use crate::config;
+
+use crate::ui::components::game_card::CardInfo;
+use crate::ui::components::tasks_queue::move_folder_task::MoveFolderQueuedTask;
+
+use super::MainAppMsg;
+
+type HeapResult<T> = Result<T, Box<MainAppMsg>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Folders of the game edition which can be moved
+pub enum GameFolder {
+    Game,
+    Addons,
+    Deployment
+}
+
+impl GameFolder {
+    #[inline]
+    pub fn list() -> &'static [Self] {
+        &[Self::Game, Self::Addons, Self::Deployment]
+    }
+
+    /// Get name of the folder used in the config file
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Game       => "game",
+            Self::Addons     => "addons",
+            Self::Deployment => "deployment"
+        }
+    }
+}
+
+#[inline]
+pub fn get_move_game_task(game_info: &CardInfo, folder: GameFolder, path: PathBuf, config: &config::Config) -> HeapResult<Box<MoveFolderQueuedTask>> {
+    let game = unsafe {
+        games::get_unsafe(game_info.get_name())
+    };
+
+    let settings = config.games.get_game_settings(game)
+        .map_err(|err| Box::new(MainAppMsg::ShowToast {
+            title: tr!("game-get-settings-failed", {
+                "game-title" = game.manifest.game_title.clone()
+            }),
+            message: Some(err.to_string())
+        }))?;
+
+    let Some(paths) = settings.paths.get(game_info.get_edition()) else {
+        return Err(Box::new(MainAppMsg::ShowToast {
+            title: tr!("game-find-path-failed", {
+                "game-title" = game.manifest.game_title.clone()
+            }),
+            message: None
+        }));
+    };
+
+    let from = match folder {
+        GameFolder::Game       => paths.game.clone(),
+        GameFolder::Addons     => paths.addons.clone(),
+        GameFolder::Deployment => paths.deployment.clone()
+    };
+
+    // Folder is moved into the selected one keeping its name
+    let to = match from.file_name() {
+        Some(name) => path.join(name),
+        None => path
+    };
+
+    if to.starts_with(&from) {
+        return Err(Box::new(MainAppMsg::ShowToast {
+            title: tr!("game-move-into-itself"),
+            message: Some(to.to_string_lossy().to_string())
+        }));
+    }
+
+    if to.read_dir().is_ok_and(|mut entries| entries.next().is_some()) {
+        return Err(Box::new(MainAppMsg::ShowToast {
+            title: tr!("game-move-destination-not-empty"),
+            message: Some(to.to_string_lossy().to_string())
+        }));
+    }
+
+    Ok(Box::new(MoveFolderQueuedTask {
+        card_info: game_info.clone(),
+        from,
+        to,
+        property: format!("games.settings.{}.paths.{}.{}", game_info.get_name(), game_info.get_edition(), folder.name())
+    }))
+}
@@ -42,6 +42,7 @@ pub fn get_uninstall_addon_task(game_info: &CardInfo, addon: &Addon, group: &Add
         .collect();
 
     Ok(Box::new(DeleteFilesQueuedTask {
+        card_info: Some(game_info.clone()),
         paths
     }))
 }
@@ -0,0 +1,118 @@
+use std::path::PathBuf;
+
+use crate::tr;
+
+use crate::games;
+use crate::config;
+
+use crate::config::games::settings::edition_paths::GameEditionPaths;
+
+use crate::games::metadata::LauncherMetadata;
+
+use crate::ui::components::game_card::CardInfo;
+use crate::ui::components::tasks_queue::delete_files_task::DeleteFilesQueuedTask;
+
+use super::MainAppMsg;
+
+type HeapResult<T> = Result<T, Box<MainAppMsg>>;
+
+#[inline]
+fn get_paths(game_info: &CardInfo, config: &config::Config) -> HeapResult<GameEditionPaths> {
+    let game = unsafe {
+        games::get_unsafe(game_info.get_name())
+    };
+
+    let settings = config.games.get_game_settings(game)
+        .map_err(|err| Box::new(MainAppMsg::ShowToast {
+            title: tr!("game-get-settings-failed", {
+                "game-title" = game.manifest.game_title.clone()
+            }),
+            message: Some(err.to_string())
+        }))?;
+
+    settings.paths.get(game_info.get_edition())
+        .cloned()
+        .ok_or_else(|| Box::new(MainAppMsg::ShowToast {
+            title: tr!("game-find-path-failed", {
+                "game-title" = game.manifest.game_title.clone()
+            }),
+            message: None
+        }))
+}
+
+/// Get wine prefix of the game edition if it's used by other games
+///
+/// Such prefix is not deleted with the game
+pub fn get_shared_prefix(game_info: &CardInfo, config: &config::Config) -> HeapResult<Option<PathBuf>> {
+    let paths = get_paths(game_info, config)?;
+
+    let Some(prefix) = paths.prefix else {
+        return Ok(None);
+    };
+
+    if prefix == config.components.wine.prefix.path {
+        return Ok(Some(prefix));
+    }
+
+    // Edition's own prefix is listed as well
+    let users = config.games.get_editions_prefixes()
+        .into_iter()
+        .filter(|(path, _)| path == &prefix)
+        .count();
+
+    Ok((users > 1).then_some(prefix))
+}
+
+/// Get game, addons and deployment folders of the game
+///
+/// Wine prefix is included only if it's not used by other games
+pub fn get_game_folders(game_info: &CardInfo, config: &config::Config) -> HeapResult<Vec<PathBuf>> {
+    let paths = get_paths(game_info, config)?;
+
+    let mut folders = vec![paths.game, paths.addons, paths.deployment];
+
+    if let Some(prefix) = paths.prefix {
+        if get_shared_prefix(game_info, config)?.is_none() {
+            folders.push(prefix);
+        }
+    }
+
+    Ok(folders)
+}
+
+/// Get unfinished transitions of the game edition
+fn get_transitions(metadata: &LauncherMetadata) -> Vec<PathBuf> {
+    let mut transitions = metadata.transitions.clone();
+
+    if let Some(path) = metadata.pre_download.as_ref().and_then(|pre_download| pre_download.transition_path.as_ref()) {
+        if !transitions.contains(path) {
+            transitions.push(path.clone());
+        }
+    }
+
+    transitions
+}
+
+#[inline]
+pub fn get_uninstall_game_task(game_info: &CardInfo, delete_metadata: bool, config: &config::Config) -> HeapResult<Box<DeleteFilesQueuedTask>> {
+    let mut paths = get_game_folders(game_info, config)?;
+
+    let metadata = LauncherMetadata::load_for_game(game_info.get_name(), game_info.get_edition())
+        .map_err(|err| Box::new(MainAppMsg::ShowToast {
+            title: tr!("game-get-metadata-failed", {
+                "game-title" = game_info.get_title()
+            }),
+            message: Some(err.to_string())
+        }))?;
+
+    paths.extend(get_transitions(&metadata));
+
+    if delete_metadata {
+        paths.push(LauncherMetadata::get_path_for_game(game_info.get_name(), game_info.get_edition()));
+    }
+
+    Ok(Box::new(DeleteFilesQueuedTask {
+        card_info: Some(game_info.clone()),
+        paths
+    }))
+}
@@ -2,4 +2,7 @@ pub mod loading;
 pub mod main;
 pub mod preferences;
 pub mod game_addons_manager;
+pub mod game_settings;
+pub mod components_manager;
+pub mod prefix_manager;
 pub mod about;
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use relm4::prelude::*;
 use gtk::prelude::*;
 use adw::prelude::*;
@@ -7,26 +9,63 @@ use crate::tr;
 use crate::i18n;
 use crate::config;
 
+use crate::network::cache;
+use crate::utils::pretty_bytes;
+
 use crate::components::wine::Wine;
 use crate::components::dxvk::Dxvk;
+use crate::components::vkd3d::Vkd3d;
+use crate::components::nvapi::Nvapi;
 
 use crate::config::games::wine::prelude::*;
 use crate::config::games::enhancements::prelude::*;
+use crate::config::games::launch::{self, Launch, LaunchWrapper};
+use crate::config::components::updates::UpdatePolicy;
+use crate::config::components::wine::snapshots::SnapshotMode;
 
 pub static mut WINDOW: Option<adw::PreferencesWindow> = None;
 
 pub struct PreferencesApp {
     wine_versions: Vec<Wine>,
     dxvk_versions: Vec<Dxvk>,
+    vkd3d_versions: Vec<Vkd3d>,
+    nvapi_versions: Vec<Nvapi>,
 
     selected_wine: Wine,
-    selected_dxvk: Dxvk
+    selected_dxvk: Dxvk,
+    selected_vkd3d: Option<Vkd3d>,
+    selected_nvapi: Option<Nvapi>,
+
+    hud_options: HudOptions,
+
+    launch_group: adw::PreferencesGroup,
+    wrappers_rows: Vec<adw::ActionRow>,
+    launch: Launch,
+
+    cache_size: u64
 }
 
 #[derive(Debug, Clone)]
 pub enum PreferencesAppMsg {
     SelectWineVersion(u32),
     SelectDxvkVersion(u32),
+    SelectVkd3dVersion(u32),
+    SelectNvapiVersion(u32),
+
+    SelectDxvkHudPreset(u32),
+    SetDxvkHudElements(String),
+
+    SelectMangoHudSource(u32),
+    SelectMangoHudPreset(u32),
+    SetMangoHudOptions(String),
+    SetMangoHudFile(String),
+
+    SetLaunchTemplate(String),
+    AddWrapper(String),
+    RemoveWrapper(usize),
+    MoveWrapperUp(usize),
+
+    ClearCache,
 
     ShowToast {
         title: String,
@@ -126,6 +165,182 @@ impl SimpleAsyncComponent for PreferencesApp {
                     // }
                 },
 
+                add = &adw::PreferencesGroup {
+                    set_title: &tr!("preferences--downloads"),
+
+                    adw::SpinRow {
+                        set_title: &tr!("downloads-speed-limit"),
+                        set_subtitle: &tr!("downloads-speed-limit-description"),
+
+                        set_adjustment: Some(&gtk::Adjustment::new(
+                            config::get().general.downloads.speed_limit as f64,
+                            0.0, 1048576.0, 128.0, 1024.0, 0.0
+                        )),
+
+                        connect_value_notify[sender] => move |row| {
+                            if let Err(err) = config::set("general.downloads.speed_limit", row.value() as u64) {
+                                sender.input(PreferencesAppMsg::ShowToast {
+                                    title: tr!("config-property-update-failed"),
+                                    message: Some(err.to_string())
+                                })
+                            }
+                        }
+                    },
+
+                    adw::SwitchRow {
+                        set_title: &tr!("downloads-pause-while-playing"),
+                        set_subtitle: &tr!("downloads-pause-while-playing-description"),
+
+                        set_active: config::get().general.downloads.pause_while_playing,
+
+                        connect_active_notify[sender] => move |switch| {
+                            if let Err(err) = config::set("general.downloads.pause_while_playing", switch.is_active()) {
+                                sender.input(PreferencesAppMsg::ShowToast {
+                                    title: tr!("config-property-update-failed"),
+                                    message: Some(err.to_string())
+                                })
+                            }
+                        }
+                    },
+
+                    adw::ExpanderRow {
+                        set_title: &tr!("downloads-schedule"),
+                        set_subtitle: &tr!("downloads-schedule-description"),
+
+                        add_row = &adw::SwitchRow {
+                            set_title: &tr!("downloads-schedule-enabled"),
+
+                            set_active: config::get().general.downloads.schedule.enabled,
+
+                            connect_active_notify[sender] => move |switch| {
+                                if let Err(err) = config::set("general.downloads.schedule.enabled", switch.is_active()) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
+                            }
+                        },
+
+                        add_row = &adw::EntryRow {
+                            set_title: &tr!("downloads-schedule-from"),
+
+                            set_show_apply_button: true,
+
+                            set_text: &config::get().general.downloads.schedule.from,
+
+                            connect_apply[sender] => move |row| {
+                                let time = row.text().trim().to_string();
+
+                                if chrono::NaiveTime::parse_from_str(&time, "%H:%M").is_err() {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("downloads-schedule-invalid"),
+                                        message: None
+                                    });
+
+                                    return;
+                                }
+
+                                if let Err(err) = config::set("general.downloads.schedule.from", time) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
+                            }
+                        },
+
+                        add_row = &adw::EntryRow {
+                            set_title: &tr!("downloads-schedule-to"),
+
+                            set_show_apply_button: true,
+
+                            set_text: &config::get().general.downloads.schedule.to,
+
+                            connect_apply[sender] => move |row| {
+                                let time = row.text().trim().to_string();
+
+                                if chrono::NaiveTime::parse_from_str(&time, "%H:%M").is_err() {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("downloads-schedule-invalid"),
+                                        message: None
+                                    });
+
+                                    return;
+                                }
+
+                                if let Err(err) = config::set("general.downloads.schedule.to", time) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
+                            }
+                        }
+                    },
+
+                    adw::ExpanderRow {
+                        set_title: &tr!("downloads-cache"),
+                        set_subtitle: &tr!("downloads-cache-description"),
+
+                        add_row = &adw::SwitchRow {
+                            set_title: &tr!("downloads-cache-enabled"),
+
+                            set_active: config::get().general.cache.enabled,
+
+                            connect_active_notify[sender] => move |switch| {
+                                if let Err(err) = config::set("general.cache.enabled", switch.is_active()) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
+                            }
+                        },
+
+                        add_row = &adw::SpinRow {
+                            set_title: &tr!("downloads-cache-max-size"),
+                            set_subtitle: &tr!("downloads-cache-max-size-description"),
+
+                            set_adjustment: Some(&gtk::Adjustment::new(
+                                config::get().general.cache.max_size as f64,
+                                0.0, 1048576.0, 1024.0, 10240.0, 0.0
+                            )),
+
+                            connect_value_notify[sender] => move |row| {
+                                if let Err(err) = config::set("general.cache.max_size", row.value() as u64) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
+                            }
+                        },
+
+                        add_row = &adw::ActionRow {
+                            set_title: &tr!("downloads-cache-clear"),
+
+                            #[watch]
+                            set_subtitle: &tr!("downloads-cache-size", {
+                                "size" = pretty_bytes(model.cache_size)
+                            }),
+
+                            add_suffix = &gtk::Button {
+                                set_valign: gtk::Align::Center,
+
+                                add_css_class: "flat",
+
+                                adw::ButtonContent {
+                                    set_icon_name: "user-trash-symbolic",
+                                    set_label: &tr!("downloads-cache-clear-button")
+                                },
+
+                                connect_clicked => PreferencesAppMsg::ClearCache
+                            }
+                        }
+                    }
+                },
+
                 add = &adw::PreferencesGroup {
                     set_title: &tr!("preferences--wine"),
 
@@ -299,6 +514,116 @@ impl SimpleAsyncComponent for PreferencesApp {
                         }
                     },
 
+                    adw::ExpanderRow {
+                        set_title: &tr!("game-hud-options"),
+                        set_subtitle: &tr!("game-hud-options-description"),
+
+                        add_row = &adw::ComboRow {
+                            set_title: &tr!("game-hud-dxvk-preset"),
+
+                            set_model: Some(&gtk::StringList::new(&[
+                                &tr!("game-hud-preset-minimal"),
+                                &tr!("game-hud-preset-default"),
+                                &tr!("game-hud-preset-full"),
+                                &tr!("game-hud-preset-custom")
+                            ])),
+
+                            #[watch]
+                            #[block_signal(dxvk_hud_preset_handler)]
+                            set_selected: HudPreset::from_dxvk_elements(&model.hud_options.dxvk)
+                                .and_then(|preset| HudPreset::list().iter().position(|item| item == &preset))
+                                .unwrap_or(HudPreset::list().len()) as u32,
+
+                            connect_selected_notify[sender] => move |row| {
+                                sender.input(PreferencesAppMsg::SelectDxvkHudPreset(row.selected()));
+                            } @dxvk_hud_preset_handler
+                        },
+
+                        add_row = &adw::EntryRow {
+                            set_title: &tr!("game-hud-dxvk-elements"),
+                            set_show_apply_button: true,
+
+                            #[watch]
+                            set_text: &model.hud_options.dxvk.join(","),
+
+                            connect_apply[sender] => move |row| {
+                                sender.input(PreferencesAppMsg::SetDxvkHudElements(row.text().to_string()));
+                            }
+                        },
+
+                        add_row = &adw::ComboRow {
+                            set_title: &tr!("game-hud-mangohud-source"),
+
+                            set_model: Some(&gtk::StringList::new(&[
+                                &tr!("game-hud-mangohud-source-inline"),
+                                &tr!("game-hud-mangohud-source-file")
+                            ])),
+
+                            set_selected: match config::get().games.enhancements.hud_options.mangohud.source {
+                                MangoHudConfigSource::Inline => 0,
+                                MangoHudConfigSource::File   => 1
+                            },
+
+                            connect_selected_notify[sender] => move |row| {
+                                sender.input(PreferencesAppMsg::SelectMangoHudSource(row.selected()));
+                            }
+                        },
+
+                        add_row = &adw::ComboRow {
+                            set_title: &tr!("game-hud-mangohud-preset"),
+
+                            set_model: Some(&gtk::StringList::new(&[
+                                &tr!("game-hud-preset-minimal"),
+                                &tr!("game-hud-preset-default"),
+                                &tr!("game-hud-preset-full"),
+                                &tr!("game-hud-preset-custom")
+                            ])),
+
+                            #[watch]
+                            set_sensitive: model.hud_options.mangohud.source == MangoHudConfigSource::Inline,
+
+                            #[watch]
+                            #[block_signal(mangohud_preset_handler)]
+                            set_selected: HudPreset::from_mangohud_options(&model.hud_options.mangohud.options)
+                                .and_then(|preset| HudPreset::list().iter().position(|item| item == &preset))
+                                .unwrap_or(HudPreset::list().len()) as u32,
+
+                            connect_selected_notify[sender] => move |row| {
+                                sender.input(PreferencesAppMsg::SelectMangoHudPreset(row.selected()));
+                            } @mangohud_preset_handler
+                        },
+
+                        add_row = &adw::EntryRow {
+                            set_title: &tr!("game-hud-mangohud-options"),
+                            set_show_apply_button: true,
+
+                            #[watch]
+                            set_sensitive: model.hud_options.mangohud.source == MangoHudConfigSource::Inline,
+
+                            #[watch]
+                            set_text: &model.hud_options.mangohud.options,
+
+                            connect_apply[sender] => move |row| {
+                                sender.input(PreferencesAppMsg::SetMangoHudOptions(row.text().to_string()));
+                            }
+                        },
+
+                        add_row = &adw::EntryRow {
+                            set_title: &tr!("game-hud-mangohud-file"),
+                            set_show_apply_button: true,
+
+                            #[watch]
+                            set_sensitive: model.hud_options.mangohud.source == MangoHudConfigSource::File,
+
+                            #[watch]
+                            set_text: &model.hud_options.mangohud.file.to_string_lossy(),
+
+                            connect_apply[sender] => move |row| {
+                                sender.input(PreferencesAppMsg::SetMangoHudFile(row.text().to_string()));
+                            }
+                        }
+                    },
+
                     adw::ExpanderRow {
                         set_title: &tr!("game-fsr"),
                         set_subtitle: &tr!("game-fsr-description"),
@@ -376,66 +701,319 @@ impl SimpleAsyncComponent for PreferencesApp {
                         }
                     },
 
-                    adw::SwitchRow {
-                        set_title: &tr!("game-gamemode"),
-                        set_subtitle: &tr!("game-gamemode-description"),
+                    adw::ExpanderRow {
+                        set_title: &tr!("game-gamescope"),
+                        set_subtitle: &tr!("game-gamescope-description"),
 
-                        set_active: config::get().games.enhancements.gamemode,
+                        add_row = &adw::SwitchRow {
+                            set_title: &tr!("game-gamescope-enabled"),
+                            set_subtitle: &tr!("game-gamescope-enabled-description"),
 
-                        connect_active_notify[sender] => move |switch| {
-                            if let Err(err) = config::set("games.enhancements.gamemode", switch.is_active()) {
-                                sender.input(PreferencesAppMsg::ShowToast {
-                                    title: tr!("config-property-update-failed"),
-                                    message: Some(err.to_string())
-                                })
+                            set_active: config::get().games.enhancements.gamescope.enabled,
+
+                            connect_active_notify[sender] => move |switch| {
+                                if let Err(err) = config::set("games.enhancements.gamescope.enabled", switch.is_active()) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
                             }
-                        }
-                    }
-                },
+                        },
 
-                add = &adw::PreferencesGroup {
-                    set_title: &tr!("preferences--components"),
+                        add_row = &adw::ComboRow {
+                            set_title: &tr!("game-gamescope-window-mode"),
 
-                    adw::ComboRow {
-                        set_title: &tr!("components-wine"),
-                        set_subtitle: &tr!("components-wine-description"),
+                            set_model: Some(&gtk::StringList::new(&[
+                                &tr!("game-gamescope-window-mode-windowed"),
+                                &tr!("game-gamescope-window-mode-borderless"),
+                                &tr!("game-gamescope-window-mode-fullscreen")
+                            ])),
 
-                        set_model: Some(&{
-                            let strings = gtk::StringList::new(&[]);
+                            set_selected: match config::get().games.enhancements.gamescope.window_mode {
+                                GamescopeWindowMode::Windowed   => 0,
+                                GamescopeWindowMode::Borderless => 1,
+                                GamescopeWindowMode::Fullscreen => 2
+                            },
 
-                            strings.append(&tr!("components-wine-latest"));
+                            connect_selected_notify[sender] => move |row| {
+                                let mode = [
+                                    GamescopeWindowMode::Windowed,
+                                    GamescopeWindowMode::Borderless,
+                                    GamescopeWindowMode::Fullscreen
+                                ][row.selected() as usize];
 
-                            for version in &model.wine_versions {
-                                strings.append(&version.title);
-                            }
+                                let value = serde_json::to_value(mode).unwrap();
 
-                            strings
-                        }),
+                                if let Err(err) = config::set("games.enhancements.gamescope.window_mode", value) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
+                            }
+                        },
 
-                        set_selected: model.wine_versions.iter()
-                            .position(|version| version == &model.selected_wine)
-                            .unwrap_or(0) as u32,
+                        add_row = &adw::SpinRow {
+                            set_title: &tr!("game-gamescope-output-width"),
 
-                        connect_selected_notify[sender] => move |row| {
-                            sender.input(PreferencesAppMsg::SelectWineVersion(row.selected()));
-                        }
-                    },
+                            set_adjustment: Some(&gtk::Adjustment::new(
+                                config::get().games.enhancements.gamescope.output.width as f64,
+                                0.0, 7680.0, 10.0, 1.0, 0.0
+                            )),
 
-                    adw::ComboRow {
-                        set_title: &tr!("components-dxvk"),
-                        set_subtitle: &tr!("components-dxvk-description"),
+                            connect_value_notify[sender] => move |row| {
+                                if let Err(err) = config::set("games.enhancements.gamescope.output.width", row.value() as u64) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
+                            }
+                        },
 
-                        set_model: Some(&{
-                            let strings = gtk::StringList::new(&[]);
+                        add_row = &adw::SpinRow {
+                            set_title: &tr!("game-gamescope-output-height"),
 
-                            strings.append(&tr!("components-dxvk-latest"));
+                            set_adjustment: Some(&gtk::Adjustment::new(
+                                config::get().games.enhancements.gamescope.output.height as f64,
+                                0.0, 4320.0, 10.0, 1.0, 0.0
+                            )),
 
-                            for version in &model.dxvk_versions {
-                                strings.append(&version.name);
+                            connect_value_notify[sender] => move |row| {
+                                if let Err(err) = config::set("games.enhancements.gamescope.output.height", row.value() as u64) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
                             }
+                        },
 
-                            strings
-                        }),
+                        add_row = &adw::SpinRow {
+                            set_title: &tr!("game-gamescope-internal-width"),
+
+                            set_adjustment: Some(&gtk::Adjustment::new(
+                                config::get().games.enhancements.gamescope.internal.width as f64,
+                                0.0, 7680.0, 10.0, 1.0, 0.0
+                            )),
+
+                            connect_value_notify[sender] => move |row| {
+                                if let Err(err) = config::set("games.enhancements.gamescope.internal.width", row.value() as u64) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
+                            }
+                        },
+
+                        add_row = &adw::SpinRow {
+                            set_title: &tr!("game-gamescope-internal-height"),
+
+                            set_adjustment: Some(&gtk::Adjustment::new(
+                                config::get().games.enhancements.gamescope.internal.height as f64,
+                                0.0, 4320.0, 10.0, 1.0, 0.0
+                            )),
+
+                            connect_value_notify[sender] => move |row| {
+                                if let Err(err) = config::set("games.enhancements.gamescope.internal.height", row.value() as u64) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
+                            }
+                        },
+
+                        add_row = &adw::SpinRow {
+                            set_title: &tr!("game-gamescope-refresh-rate"),
+                            set_subtitle: &tr!("game-gamescope-refresh-rate-description"),
+
+                            set_adjustment: Some(&gtk::Adjustment::new(
+                                config::get().games.enhancements.gamescope.refresh_rate as f64,
+                                0.0, 360.0, 1.0, 1.0, 0.0
+                            )),
+
+                            connect_value_notify[sender] => move |row| {
+                                if let Err(err) = config::set("games.enhancements.gamescope.refresh_rate", row.value() as u64) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
+                            }
+                        },
+
+                        add_row = &adw::ComboRow {
+                            set_title: &tr!("game-gamescope-upscaler"),
+
+                            set_model: Some(&gtk::StringList::new(&[
+                                &tr!("game-gamescope-upscaler-linear"),
+                                &tr!("game-gamescope-upscaler-nearest"),
+                                "AMD FSR",
+                                "NVIDIA NIS",
+                                &tr!("game-gamescope-upscaler-pixel")
+                            ])),
+
+                            set_selected: GamescopeUpscaler::list().iter()
+                                .position(|upscaler| upscaler == &config::get().games.enhancements.gamescope.upscaler)
+                                .unwrap_or_default() as u32,
+
+                            connect_selected_notify[sender] => move |row| {
+                                let upscaler = GamescopeUpscaler::list()[row.selected() as usize];
+
+                                let value = serde_json::to_value(upscaler).unwrap();
+
+                                if let Err(err) = config::set("games.enhancements.gamescope.upscaler", value) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
+                            }
+                        },
+
+                        add_row = &adw::SpinRow {
+                            set_title: &tr!("game-gamescope-sharpness"),
+                            set_subtitle: &tr!("game-gamescope-sharpness-description"),
+
+                            set_adjustment: Some(&gtk::Adjustment::new(
+                                config::get().games.enhancements.gamescope.sharpness as f64,
+                                0.0, 20.0, 1.0, 1.0, 0.0
+                            )),
+
+                            connect_value_notify[sender] => move |row| {
+                                if let Err(err) = config::set("games.enhancements.gamescope.sharpness", row.value() as u64) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
+                            }
+                        },
+
+                        add_row = &adw::SwitchRow {
+                            set_title: &tr!("game-gamescope-hdr"),
+                            set_subtitle: &tr!("game-gamescope-hdr-description"),
+
+                            set_active: config::get().games.enhancements.gamescope.hdr,
+
+                            connect_active_notify[sender] => move |switch| {
+                                if let Err(err) = config::set("games.enhancements.gamescope.hdr", switch.is_active()) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
+                            }
+                        },
+
+                        add_row = &adw::SwitchRow {
+                            set_title: &tr!("game-gamescope-grab-cursor"),
+                            set_subtitle: &tr!("game-gamescope-grab-cursor-description"),
+
+                            set_active: config::get().games.enhancements.gamescope.grab_cursor,
+
+                            connect_active_notify[sender] => move |switch| {
+                                if let Err(err) = config::set("games.enhancements.gamescope.grab_cursor", switch.is_active()) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
+                            }
+                        }
+                    },
+
+                    adw::SwitchRow {
+                        set_title: &tr!("game-gamemode"),
+                        set_subtitle: &tr!("game-gamemode-description"),
+
+                        set_active: config::get().games.enhancements.gamemode,
+
+                        connect_active_notify[sender] => move |switch| {
+                            if let Err(err) = config::set("games.enhancements.gamemode", switch.is_active()) {
+                                sender.input(PreferencesAppMsg::ShowToast {
+                                    title: tr!("config-property-update-failed"),
+                                    message: Some(err.to_string())
+                                })
+                            }
+                        }
+                    }
+                },
+
+                #[local_ref]
+                add = launch_group -> adw::PreferencesGroup {
+                    set_title: &tr!("preferences--launch"),
+                    set_description: Some(&tr!("launch-description")),
+
+                    adw::EntryRow {
+                        set_title: &tr!("launch-template"),
+                        set_show_apply_button: true,
+
+                        set_text: &config::get().games.launch.template,
+
+                        connect_apply[sender] => move |row| {
+                            sender.input(PreferencesAppMsg::SetLaunchTemplate(row.text().to_string()));
+                        }
+                    },
+
+                    adw::EntryRow {
+                        set_title: &tr!("launch-wrapper-add"),
+                        set_show_apply_button: true,
+
+                        connect_apply[sender] => move |row| {
+                            sender.input(PreferencesAppMsg::AddWrapper(row.text().to_string()));
+
+                            row.set_text("");
+                        }
+                    }
+                },
+
+                add = &adw::PreferencesGroup {
+                    set_title: &tr!("preferences--components"),
+
+                    adw::ComboRow {
+                        set_title: &tr!("components-wine"),
+                        set_subtitle: &tr!("components-wine-description"),
+
+                        set_model: Some(&{
+                            let strings = gtk::StringList::new(&[]);
+
+                            strings.append(&tr!("components-wine-latest"));
+
+                            for version in &model.wine_versions {
+                                strings.append(&version.title);
+                            }
+
+                            strings
+                        }),
+
+                        set_selected: model.wine_versions.iter()
+                            .position(|version| version == &model.selected_wine)
+                            .unwrap_or(0) as u32,
+
+                        connect_selected_notify[sender] => move |row| {
+                            sender.input(PreferencesAppMsg::SelectWineVersion(row.selected()));
+                        }
+                    },
+
+                    adw::ComboRow {
+                        set_title: &tr!("components-dxvk"),
+                        set_subtitle: &tr!("components-dxvk-description"),
+
+                        set_model: Some(&{
+                            let strings = gtk::StringList::new(&[]);
+
+                            strings.append(&tr!("components-dxvk-latest"));
+
+                            for version in &model.dxvk_versions {
+                                strings.append(&version.name);
+                            }
+
+                            strings
+                        }),
 
                         set_selected: model.dxvk_versions.iter()
                             .position(|version| version == &model.selected_dxvk)
@@ -446,6 +1024,90 @@ impl SimpleAsyncComponent for PreferencesApp {
                         }
                     },
 
+                    adw::SwitchRow {
+                        set_title: &tr!("components-vkd3d-enabled"),
+                        set_subtitle: &tr!("components-vkd3d-enabled-description"),
+
+                        set_active: config::get().components.vkd3d.enabled,
+
+                        connect_active_notify[sender] => move |switch| {
+                            if let Err(err) = config::set("components.vkd3d.enabled", switch.is_active()) {
+                                sender.input(PreferencesAppMsg::ShowToast {
+                                    title: tr!("config-property-update-failed"),
+                                    message: Some(err.to_string())
+                                })
+                            }
+                        }
+                    },
+
+                    adw::ComboRow {
+                        set_title: &tr!("components-vkd3d"),
+                        set_subtitle: &tr!("components-vkd3d-description"),
+
+                        set_model: Some(&{
+                            let strings = gtk::StringList::new(&[]);
+
+                            strings.append(&tr!("components-vkd3d-latest"));
+
+                            for version in &model.vkd3d_versions {
+                                strings.append(&version.name);
+                            }
+
+                            strings
+                        }),
+
+                        set_selected: model.vkd3d_versions.iter()
+                            .position(|version| model.selected_vkd3d.as_ref() == Some(version))
+                            .map(|index| index + 1)
+                            .unwrap_or(0) as u32,
+
+                        connect_selected_notify[sender] => move |row| {
+                            sender.input(PreferencesAppMsg::SelectVkd3dVersion(row.selected()));
+                        }
+                    },
+
+                    adw::SwitchRow {
+                        set_title: &tr!("components-nvapi-enabled"),
+                        set_subtitle: &tr!("components-nvapi-enabled-description"),
+
+                        set_active: config::get().components.nvapi.enabled,
+
+                        connect_active_notify[sender] => move |switch| {
+                            if let Err(err) = config::set("components.nvapi.enabled", switch.is_active()) {
+                                sender.input(PreferencesAppMsg::ShowToast {
+                                    title: tr!("config-property-update-failed"),
+                                    message: Some(err.to_string())
+                                })
+                            }
+                        }
+                    },
+
+                    adw::ComboRow {
+                        set_title: &tr!("components-nvapi"),
+                        set_subtitle: &tr!("components-nvapi-description"),
+
+                        set_model: Some(&{
+                            let strings = gtk::StringList::new(&[]);
+
+                            strings.append(&tr!("components-nvapi-latest"));
+
+                            for version in &model.nvapi_versions {
+                                strings.append(&version.name);
+                            }
+
+                            strings
+                        }),
+
+                        set_selected: model.nvapi_versions.iter()
+                            .position(|version| model.selected_nvapi.as_ref() == Some(version))
+                            .map(|index| index + 1)
+                            .unwrap_or(0) as u32,
+
+                        connect_selected_notify[sender] => move |row| {
+                            sender.input(PreferencesAppMsg::SelectNvapiVersion(row.selected()));
+                        }
+                    },
+
                     adw::SwitchRow {
                         set_title: &tr!("components-install-corefonts"),
                         set_subtitle: &tr!("components-install-corefonts-description"),
@@ -460,6 +1122,125 @@ impl SimpleAsyncComponent for PreferencesApp {
                                 })
                             }
                         }
+                    },
+
+                    adw::ExpanderRow {
+                        set_title: &tr!("components-prefix-snapshots"),
+                        set_subtitle: &tr!("components-prefix-snapshots-description"),
+
+                        add_row = &adw::SwitchRow {
+                            set_title: &tr!("components-prefix-snapshots-enabled"),
+
+                            set_active: config::get().components.wine.prefix.snapshots.enabled,
+
+                            connect_active_notify[sender] => move |switch| {
+                                if let Err(err) = config::set("components.wine.prefix.snapshots.enabled", switch.is_active()) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
+                            }
+                        },
+
+                        add_row = &adw::ComboRow {
+                            set_title: &tr!("components-prefix-snapshots-mode"),
+                            set_subtitle: &tr!("components-prefix-snapshots-mode-description"),
+
+                            set_model: Some(&gtk::StringList::new(&[
+                                &tr!("components-prefix-snapshots-mode-copy"),
+                                &tr!("components-prefix-snapshots-mode-tar")
+                            ])),
+
+                            set_selected: match config::get().components.wine.prefix.snapshots.mode {
+                                SnapshotMode::Copy => 0,
+                                SnapshotMode::Tar  => 1
+                            },
+
+                            connect_selected_notify[sender] => move |row| {
+                                let mode = [
+                                    SnapshotMode::Copy,
+                                    SnapshotMode::Tar
+                                ][row.selected() as usize];
+
+                                let value = serde_json::to_value(mode).unwrap();
+
+                                if let Err(err) = config::set("components.wine.prefix.snapshots.mode", value) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
+                            }
+                        },
+
+                        add_row = &adw::SpinRow {
+                            set_title: &tr!("components-prefix-snapshots-keep"),
+
+                            set_adjustment: Some(&gtk::Adjustment::new(
+                                config::get().components.wine.prefix.snapshots.keep as f64,
+                                1.0, 50.0, 1.0, 1.0, 0.0
+                            )),
+
+                            connect_value_notify[sender] => move |row| {
+                                if let Err(err) = config::set("components.wine.prefix.snapshots.keep", row.value() as u64) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
+                            }
+                        }
+                    },
+
+                    adw::ComboRow {
+                        set_title: &tr!("components-updates-policy"),
+                        set_subtitle: &tr!("components-updates-policy-description"),
+
+                        set_model: Some(&gtk::StringList::new(&[
+                            &tr!("components-updates-policy-always"),
+                            &tr!("components-updates-policy-ask"),
+                            &tr!("components-updates-policy-never")
+                        ])),
+
+                        set_selected: match config::get().components.updates.policy {
+                            UpdatePolicy::Always => 0,
+                            UpdatePolicy::Ask    => 1,
+                            UpdatePolicy::Never  => 2
+                        },
+
+                        connect_selected_notify[sender] => move |row| {
+                            let policy = [
+                                UpdatePolicy::Always,
+                                UpdatePolicy::Ask,
+                                UpdatePolicy::Never
+                            ][row.selected() as usize];
+
+                            let value = serde_json::to_value(policy).unwrap();
+
+                            if let Err(err) = config::set("components.updates.policy", value) {
+                                sender.input(PreferencesAppMsg::ShowToast {
+                                    title: tr!("config-property-update-failed"),
+                                    message: Some(err.to_string())
+                                })
+                            }
+                        }
+                    },
+
+                    adw::SwitchRow {
+                        set_title: &tr!("components-delete-unused"),
+                        set_subtitle: &tr!("components-delete-unused-description"),
+
+                        set_active: config::get().components.updates.delete_unused,
+
+                        connect_active_notify[sender] => move |switch| {
+                            if let Err(err) = config::set("components.updates.delete_unused", switch.is_active()) {
+                                sender.input(PreferencesAppMsg::ShowToast {
+                                    title: tr!("config-property-update-failed"),
+                                    message: Some(err.to_string())
+                                })
+                            }
+                        }
                     }
                 }
             }
@@ -467,7 +1248,7 @@ impl SimpleAsyncComponent for PreferencesApp {
     }
 
     async fn init(parent: Self::Init, root: Self::Root, sender: AsyncComponentSender<Self>) -> AsyncComponentParts<Self> {
-        let model = Self {
+        let mut model = Self {
             wine_versions: Wine::versions()
                 .unwrap()
                 .into_iter()
@@ -480,12 +1261,39 @@ impl SimpleAsyncComponent for PreferencesApp {
                 .take(12)
                 .collect(),
 
+            // These components are optional so their channels may be unavailable
+            vkd3d_versions: Vkd3d::versions()
+                .unwrap_or_default()
+                .into_iter()
+                .take(12)
+                .collect(),
+
+            nvapi_versions: Nvapi::versions()
+                .unwrap_or_default()
+                .into_iter()
+                .take(12)
+                .collect(),
+
             selected_wine: Wine::from_config().unwrap(),
-            selected_dxvk: Dxvk::from_config().unwrap()
+            selected_dxvk: Dxvk::from_config().unwrap(),
+            selected_vkd3d: Vkd3d::from_config().ok(),
+            selected_nvapi: Nvapi::from_config().ok(),
+
+            hud_options: config::get().games.enhancements.hud_options,
+
+            launch_group: adw::PreferencesGroup::new(),
+            wrappers_rows: Vec::new(),
+            launch: config::get().games.launch,
+
+            cache_size: cache::get_size().unwrap_or_default()
         };
 
+        let launch_group = &model.launch_group;
+
         let widgets = view_output!();
 
+        model.update_wrappers_rows(&sender);
+
         widgets.window.set_transient_for(Some(&parent));
 
         unsafe {
@@ -527,6 +1335,143 @@ impl SimpleAsyncComponent for PreferencesApp {
                 }
             }
 
+            PreferencesAppMsg::SelectVkd3dVersion(index) => {
+                let version = if index == 0 {
+                    String::from("latest")
+                } else {
+                    self.vkd3d_versions[index as usize - 1].name.clone()
+                };
+
+                if let Err(err) = config::set("components.vkd3d.version", version) {
+                    sender.input(PreferencesAppMsg::ShowToast {
+                        title: tr!("config-property-update-failed"),
+                        message: Some(err.to_string())
+                    })
+                }
+            }
+
+            PreferencesAppMsg::SelectNvapiVersion(index) => {
+                let version = if index == 0 {
+                    String::from("latest")
+                } else {
+                    self.nvapi_versions[index as usize - 1].name.clone()
+                };
+
+                if let Err(err) = config::set("components.nvapi.version", version) {
+                    sender.input(PreferencesAppMsg::ShowToast {
+                        title: tr!("config-property-update-failed"),
+                        message: Some(err.to_string())
+                    })
+                }
+            }
+
+            PreferencesAppMsg::SelectDxvkHudPreset(index) => {
+                // Custom elements are set using the entry row
+                if let Some(preset) = HudPreset::list().get(index as usize) {
+                    self.hud_options.dxvk = preset.dxvk_elements();
+
+                    self.save_hud_options(&sender);
+                }
+            }
+
+            PreferencesAppMsg::SetDxvkHudElements(elements) => {
+                self.hud_options.dxvk = elements.split(',')
+                    .map(str::trim)
+                    .filter(|element| !element.is_empty())
+                    .map(String::from)
+                    .collect();
+
+                self.save_hud_options(&sender);
+            }
+
+            PreferencesAppMsg::SelectMangoHudSource(index) => {
+                self.hud_options.mangohud.source = match index {
+                    0 => MangoHudConfigSource::Inline,
+                    _ => MangoHudConfigSource::File
+                };
+
+                self.save_hud_options(&sender);
+            }
+
+            PreferencesAppMsg::SelectMangoHudPreset(index) => {
+                if let Some(preset) = HudPreset::list().get(index as usize) {
+                    self.hud_options.mangohud.options = preset.mangohud_options().to_string();
+
+                    self.save_hud_options(&sender);
+                }
+            }
+
+            PreferencesAppMsg::SetMangoHudOptions(options) => {
+                self.hud_options.mangohud.options = options.trim().to_string();
+
+                self.save_hud_options(&sender);
+            }
+
+            PreferencesAppMsg::SetMangoHudFile(file) => {
+                self.hud_options.mangohud.file = PathBuf::from(file.trim());
+
+                self.save_hud_options(&sender);
+            }
+
+            PreferencesAppMsg::SetLaunchTemplate(template) => {
+                if !launch::is_valid_template(&template) {
+                    sender.input(PreferencesAppMsg::ShowToast {
+                        title: tr!("launch-template-invalid"),
+                        message: None
+                    });
+
+                    return;
+                }
+
+                self.launch.template = template.trim().to_string();
+
+                self.save_launch(&sender);
+            }
+
+            PreferencesAppMsg::AddWrapper(command) => {
+                if command.trim().is_empty() {
+                    return;
+                }
+
+                let wrapper = LaunchWrapper::from_command(command);
+
+                if !self.launch.wrappers.contains(&wrapper) {
+                    self.launch.wrappers.push(wrapper);
+                }
+
+                self.save_launch(&sender);
+                self.update_wrappers_rows(&sender);
+            }
+
+            PreferencesAppMsg::RemoveWrapper(index) => {
+                if index < self.launch.wrappers.len() {
+                    self.launch.wrappers.remove(index);
+                }
+
+                self.save_launch(&sender);
+                self.update_wrappers_rows(&sender);
+            }
+
+            PreferencesAppMsg::MoveWrapperUp(index) => {
+                if index > 0 && index < self.launch.wrappers.len() {
+                    self.launch.wrappers.swap(index - 1, index);
+                }
+
+                self.save_launch(&sender);
+                self.update_wrappers_rows(&sender);
+            }
+
+            PreferencesAppMsg::ClearCache => {
+                if let Err(err) = cache::clear() {
+                    sender.input(PreferencesAppMsg::ShowToast {
+                        title: tr!("cache-clear-failed"),
+                        message: Some(err.to_string())
+                    })
+                }
+
+                self.cache_size = cache::get_size().unwrap_or_default();
+            }
+
             PreferencesAppMsg::ShowToast { title, message } => {
                 let window = unsafe {
                     WINDOW.as_ref().unwrap_unchecked()
@@ -566,3 +1511,63 @@ impl SimpleAsyncComponent for PreferencesApp {
         }
     }
 }
+
+impl PreferencesApp {
+    /// Write launch settings to the config file
+    fn save_launch(&self, sender: &AsyncComponentSender<Self>) {
+        if let Err(err) = config::set("games.launch", serde_json::to_value(&self.launch).unwrap()) {
+            sender.input(PreferencesAppMsg::ShowToast {
+                title: tr!("config-property-update-failed"),
+                message: Some(err.to_string())
+            });
+        }
+    }
+
+    /// Recreate launch wrappers rows
+    fn update_wrappers_rows(&mut self, sender: &AsyncComponentSender<Self>) {
+        for row in self.wrappers_rows.drain(..) {
+            self.launch_group.remove(&row);
+        }
+
+        for (i, wrapper) in self.launch.wrappers.iter().enumerate() {
+            let row = adw::ActionRow::new();
+
+            row.set_title(&wrapper.get_command());
+
+            let buttons = [
+                ("go-up-symbolic", PreferencesAppMsg::MoveWrapperUp(i)),
+                ("user-trash-symbolic", PreferencesAppMsg::RemoveWrapper(i))
+            ];
+
+            for (icon, msg) in buttons {
+                let button = gtk::Button::new();
+
+                button.set_icon_name(icon);
+                button.set_valign(gtk::Align::Center);
+                button.add_css_class("flat");
+
+                // First wrapper can't be moved up
+                button.set_sensitive(i > 0 || !matches!(msg, PreferencesAppMsg::MoveWrapperUp(_)));
+
+                let sender = sender.clone();
+
+                button.connect_clicked(move |_| sender.input(msg.clone()));
+
+                row.add_suffix(&button);
+            }
+
+            self.launch_group.add(&row);
+            self.wrappers_rows.push(row);
+        }
+    }
+
+    /// Write HUD options to the config file
+    fn save_hud_options(&self, sender: &AsyncComponentSender<Self>) {
+        if let Err(err) = config::set("games.enhancements.hud_options", serde_json::to_value(&self.hud_options).unwrap()) {
+            sender.input(PreferencesAppMsg::ShowToast {
+                title: tr!("config-property-update-failed"),
+                message: Some(err.to_string())
+            });
+        }
+    }
+}
@@ -0,0 +1,629 @@
+use std::path::PathBuf;
+
+use relm4::prelude::*;
+
+use gtk::prelude::*;
+use adw::prelude::*;
+
+use crate::tr;
+
+use crate::config;
+use crate::games;
+use crate::config::games::settings::edition_overrides::ComponentVersion;
+
+use crate::utils::pretty_bytes;
+
+use crate::components::wine::Wine;
+use crate::components::vkd3d::Vkd3d;
+use crate::components::nvapi::Nvapi;
+use crate::components::prefix_tools::{self, PrefixTool, PrefixVerb};
+use crate::components::prefix_snapshots::{self, Snapshot};
+
+use crate::ui::components::tasks_queue::snapshot_prefix_task::SnapshotAction;
+
+use super::main::MainAppMsg;
+
+pub static mut WINDOW: Option<adw::Window> = None;
+
+#[derive(Debug)]
+pub struct PrefixManagerApp {
+    pub verbs_group: adw::PreferencesGroup,
+    pub verbs_rows: Vec<adw::ActionRow>,
+
+    pub snapshots_group: adw::PreferencesGroup,
+    pub snapshots_rows: Vec<adw::ActionRow>,
+
+    pub components_row: adw::ExpanderRow,
+    pub fonts_row: adw::ExpanderRow,
+    pub overrides_row: adw::ExpanderRow,
+    pub info_rows: Vec<(adw::ExpanderRow, adw::ActionRow)>,
+
+    pub prefix: PathBuf,
+    pub wine: Option<ComponentVersion>,
+    pub dxvk: Option<ComponentVersion>,
+
+    /// Resolved wine version used by the prefix
+    pub resolved_wine: Option<Wine>
+}
+
+#[derive(Debug, Clone)]
+pub enum PrefixManagerAppMsg {
+    /// Set prefix to manage
+    ///
+    /// Wine and DXVK versions are taken from
+    /// the global config if not specified
+    SetPrefix {
+        prefix: PathBuf,
+        wine: Option<ComponentVersion>,
+        dxvk: Option<ComponentVersion>
+    },
+
+    Refresh,
+
+    RunTool(PrefixTool),
+    InstallVerb(PrefixVerb),
+
+    Repair,
+    Reset,
+
+    CreateSnapshot,
+    RestoreSnapshot(Snapshot),
+    DeleteSnapshot(Snapshot)
+}
+
+impl PrefixManagerApp {
+    #[inline]
+    fn is_created(&self) -> bool {
+        self.prefix.exists()
+    }
+
+    #[inline]
+    fn is_proton(&self) -> bool {
+        self.resolved_wine.as_ref().is_some_and(|wine| wine.get_proton_script().is_some())
+    }
+
+    fn add_info_row(&mut self, parent: &adw::ExpanderRow, title: &str, subtitle: &str) {
+        let row = adw::ActionRow::new();
+
+        row.set_title(title);
+        row.set_subtitle(subtitle);
+
+        parent.add_row(&row);
+
+        self.info_rows.push((parent.clone(), row));
+    }
+}
+
+#[relm4::component(pub, async)]
+impl SimpleAsyncComponent for PrefixManagerApp {
+    type Init = adw::Window;
+    type Input = PrefixManagerAppMsg;
+    type Output = MainAppMsg;
+
+    view! {
+        window = adw::Window {
+            set_default_size: (700, 620),
+            set_title: Some(&tr!("prefix-manager")),
+
+            set_hide_on_close: true,
+            set_modal: true,
+
+            add_css_class?: crate::APP_DEBUG.then_some("devel"),
+
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+
+                adw::HeaderBar {
+                    add_css_class: "flat",
+
+                    pack_start = &gtk::Button {
+                        set_icon_name: "view-refresh-symbolic",
+
+                        connect_clicked => PrefixManagerAppMsg::Refresh
+                    }
+                },
+
+                adw::PreferencesPage {
+                    add = &adw::PreferencesGroup {
+                        set_title: &tr!("prefix-manager-tools"),
+
+                        #[watch]
+                        set_description: Some(&if model.is_created() {
+                            model.prefix.to_string_lossy().to_string()
+                        } else {
+                            tr!("prefix-manager-not-created")
+                        }),
+
+                        #[watch]
+                        set_sensitive: model.is_created(),
+
+                        adw::ActionRow {
+                            set_title: "winecfg",
+                            set_subtitle: &tr!("prefix-manager-winecfg"),
+
+                            set_activatable: true,
+
+                            add_suffix = &gtk::Image {
+                                set_icon_name: Some("go-next-symbolic")
+                            },
+
+                            connect_activated => PrefixManagerAppMsg::RunTool(PrefixTool::Winecfg)
+                        },
+
+                        adw::ActionRow {
+                            set_title: "regedit",
+                            set_subtitle: &tr!("prefix-manager-regedit"),
+
+                            set_activatable: true,
+
+                            add_suffix = &gtk::Image {
+                                set_icon_name: Some("go-next-symbolic")
+                            },
+
+                            connect_activated => PrefixManagerAppMsg::RunTool(PrefixTool::Regedit)
+                        },
+
+                        adw::ActionRow {
+                            set_title: "taskmgr",
+                            set_subtitle: &tr!("prefix-manager-taskmgr"),
+
+                            set_activatable: true,
+
+                            add_suffix = &gtk::Image {
+                                set_icon_name: Some("go-next-symbolic")
+                            },
+
+                            connect_activated => PrefixManagerAppMsg::RunTool(PrefixTool::Taskmgr)
+                        }
+                    },
+
+                    add = &adw::PreferencesGroup {
+                        set_title: &tr!("prefix-manager-maintenance"),
+
+                        adw::ActionRow {
+                            set_title: &tr!("prefix-manager-repair"),
+                            set_subtitle: &tr!("prefix-manager-repair-description"),
+
+                            // Proton creates and updates its prefix by itself
+                            #[watch]
+                            set_sensitive: !model.is_proton(),
+
+                            add_suffix = &gtk::Button {
+                                set_label: &tr!("prefix-manager-repair"),
+                                set_valign: gtk::Align::Center,
+
+                                connect_clicked => PrefixManagerAppMsg::Repair
+                            }
+                        },
+
+                        adw::ActionRow {
+                            set_title: &tr!("prefix-manager-reset"),
+                            set_subtitle: &tr!("prefix-manager-reset-description"),
+
+                            #[watch]
+                            set_sensitive: model.is_created(),
+
+                            add_suffix = &gtk::Button {
+                                add_css_class: "destructive-action",
+
+                                set_label: &tr!("prefix-manager-reset"),
+                                set_valign: gtk::Align::Center,
+
+                                connect_clicked => PrefixManagerAppMsg::Reset
+                            }
+                        }
+                    },
+
+                    #[local_ref]
+                    add = snapshots_group -> adw::PreferencesGroup {
+                        set_title: &tr!("prefix-manager-snapshots"),
+                        set_description: Some(&tr!("prefix-manager-snapshots-description")),
+
+                        #[wrap(Some)]
+                        set_header_suffix = &gtk::Button {
+                            add_css_class: "flat",
+
+                            set_icon_name: "list-add-symbolic",
+                            set_tooltip_text: Some(&tr!("prefix-manager-snapshot-create")),
+                            set_valign: gtk::Align::Center,
+
+                            #[watch]
+                            set_sensitive: model.is_created(),
+
+                            connect_clicked => PrefixManagerAppMsg::CreateSnapshot
+                        }
+                    },
+
+                    #[local_ref]
+                    add = verbs_group -> adw::PreferencesGroup {
+                        set_title: &tr!("prefix-manager-verbs"),
+
+                        #[watch]
+                        set_description: prefix_tools::find_winetricks()
+                            .is_none()
+                            .then(|| tr!("prefix-manager-winetricks-missing"))
+                            .as_deref(),
+
+                        #[watch]
+                        set_sensitive: model.is_created()
+                    },
+
+                    add = &adw::PreferencesGroup {
+                        set_title: &tr!("prefix-manager-applied"),
+
+                        #[local_ref]
+                        components_row -> adw::ExpanderRow {
+                            set_title: &tr!("prefix-manager-components")
+                        },
+
+                        #[local_ref]
+                        fonts_row -> adw::ExpanderRow {
+                            set_title: &tr!("prefix-manager-fonts")
+                        },
+
+                        #[local_ref]
+                        overrides_row -> adw::ExpanderRow {
+                            set_title: &tr!("prefix-manager-dll-overrides")
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    async fn init(parent: Self::Init, root: Self::Root, sender: AsyncComponentSender<Self>) -> AsyncComponentParts<Self> {
+        let model = Self {
+            verbs_group: adw::PreferencesGroup::new(),
+            verbs_rows: Vec::new(),
+
+            snapshots_group: adw::PreferencesGroup::new(),
+            snapshots_rows: Vec::new(),
+
+            components_row: adw::ExpanderRow::new(),
+            fonts_row: adw::ExpanderRow::new(),
+            overrides_row: adw::ExpanderRow::new(),
+            info_rows: Vec::new(),
+
+            prefix: config::get().components.wine.prefix.path,
+            wine: None,
+            dxvk: None,
+
+            resolved_wine: None
+        };
+
+        let verbs_group = &model.verbs_group;
+        let snapshots_group = &model.snapshots_group;
+        let components_row = &model.components_row;
+        let fonts_row = &model.fonts_row;
+        let overrides_row = &model.overrides_row;
+
+        let widgets = view_output!();
+
+        widgets.window.set_transient_for(Some(&parent));
+
+        unsafe {
+            WINDOW = Some(widgets.window.clone());
+        }
+
+        AsyncComponentParts { model, widgets }
+    }
+
+    async fn update(&mut self, msg: Self::Input, sender: AsyncComponentSender<Self>) {
+        match msg {
+            PrefixManagerAppMsg::SetPrefix { prefix, wine, dxvk } => {
+                self.prefix = prefix;
+                self.wine = wine;
+                self.dxvk = dxvk;
+
+                sender.input(PrefixManagerAppMsg::Refresh);
+            }
+
+            PrefixManagerAppMsg::Refresh => {
+                for row in self.verbs_rows.drain(..) {
+                    self.verbs_group.remove(&row);
+                }
+
+                for row in self.snapshots_rows.drain(..) {
+                    self.snapshots_group.remove(&row);
+                }
+
+                for (parent, row) in self.info_rows.drain(..) {
+                    parent.remove(&row);
+                }
+
+                // Snapshots
+
+                for snapshot in prefix_snapshots::list(&self.prefix).unwrap_or_default() {
+                    let row = adw::ActionRow::new();
+
+                    row.set_title(&snapshot.get_created_at_text());
+                    row.set_subtitle(&pretty_bytes(snapshot.get_size()));
+
+                    let actions = [
+                        ("edit-undo-symbolic", tr!("prefix-manager-snapshot-restore"), PrefixManagerAppMsg::RestoreSnapshot(snapshot.clone())),
+                        ("user-trash-symbolic", tr!("prefix-manager-snapshot-delete"), PrefixManagerAppMsg::DeleteSnapshot(snapshot))
+                    ];
+
+                    for (icon, tooltip, msg) in actions {
+                        let button = gtk::Button::new();
+
+                        button.set_icon_name(icon);
+                        button.set_tooltip_text(Some(&tooltip));
+                        button.set_valign(gtk::Align::Center);
+                        button.add_css_class("flat");
+
+                        if matches!(msg, PrefixManagerAppMsg::DeleteSnapshot(_)) {
+                            button.add_css_class("error");
+                        }
+
+                        let sender = sender.clone();
+
+                        button.connect_clicked(move |_| sender.input(msg.clone()));
+
+                        row.add_suffix(&button);
+                    }
+
+                    self.snapshots_group.add(&row);
+                    self.snapshots_rows.push(row);
+                }
+
+                let wine = match &self.wine {
+                    Some(wine) => Wine::from_version(&wine.build, &wine.version),
+                    None => Wine::from_config()
+                };
+
+                self.resolved_wine = match wine {
+                    Ok(wine) => Some(wine),
+                    Err(err) => {
+                        sender.output(MainAppMsg::ShowToast {
+                            title: tr!("prefix-manager-wine-failed"),
+                            message: Some(err.to_string())
+                        }).unwrap();
+
+                        return;
+                    }
+                };
+
+                let Some(wine) = self.resolved_wine.clone() else {
+                    return;
+                };
+
+                // Runtimes
+
+                let applied_verbs = prefix_tools::get_applied_verbs(&wine, &self.prefix);
+                let winetricks_installed = prefix_tools::find_winetricks().is_some();
+
+                for verb in PrefixVerb::list() {
+                    let row = adw::ActionRow::new();
+
+                    row.set_title(verb.title());
+                    row.set_subtitle(verb.name());
+
+                    if applied_verbs.iter().any(|applied| applied == verb.name()) {
+                        let icon = gtk::Image::from_icon_name("object-select-symbolic");
+
+                        icon.set_tooltip_text(Some(&tr!("prefix-manager-verb-installed")));
+
+                        row.add_suffix(&icon);
+                    }
+
+                    else {
+                        let button = gtk::Button::new();
+
+                        button.set_icon_name("folder-download-symbolic");
+                        button.set_tooltip_text(Some(&tr!("prefix-manager-verb-install")));
+                        button.set_valign(gtk::Align::Center);
+                        button.set_sensitive(winetricks_installed);
+                        button.add_css_class("flat");
+
+                        let sender = sender.clone();
+                        let verb = *verb;
+
+                        button.connect_clicked(move |_| sender.input(PrefixManagerAppMsg::InstallVerb(verb)));
+
+                        row.add_suffix(&button);
+                    }
+
+                    self.verbs_group.add(&row);
+                    self.verbs_rows.push(row);
+                }
+
+                // Components installed to the prefix
+
+                let wine_prefix = prefix_tools::get_wine_prefix(&wine, &self.prefix);
+
+                let components = [
+                    ("DXVK", wincompatlib::dxvk::Dxvk::get_version(&wine_prefix).ok().flatten()),
+                    ("VKD3D-Proton", Vkd3d::get_installed_version(&wine_prefix).ok().flatten()),
+                    ("DXVK-NVAPI", Nvapi::get_installed_version(&wine_prefix).ok().flatten())
+                ];
+
+                let components_row = self.components_row.clone();
+
+                for (title, version) in components {
+                    let version = version.unwrap_or_else(|| tr!("prefix-manager-not-installed"));
+
+                    self.add_info_row(&components_row, title, &version);
+                }
+
+                // Fonts
+
+                let fonts = prefix_tools::get_fonts(&wine, &self.prefix);
+                let fonts_row = self.fonts_row.clone();
+
+                fonts_row.set_subtitle(&tr!("prefix-manager-count", {
+                    "count" = fonts.len()
+                }));
+
+                for font in fonts {
+                    self.add_info_row(&fonts_row, &font, "");
+                }
+
+                // DLL overrides
+
+                let overrides = prefix_tools::get_dll_overrides(&wine, &self.prefix);
+                let overrides_row = self.overrides_row.clone();
+
+                overrides_row.set_subtitle(&tr!("prefix-manager-count", {
+                    "count" = overrides.len()
+                }));
+
+                for (name, value) in overrides {
+                    self.add_info_row(&overrides_row, &name, &value);
+                }
+            }
+
+            PrefixManagerAppMsg::RunTool(tool) => {
+                let Some(wine) = &self.resolved_wine else {
+                    return;
+                };
+
+                match prefix_tools::run_tool(wine, &self.prefix, tool) {
+                    // Wait for the tool in background to not leave a zombie process
+                    Ok(mut child) => {
+                        std::thread::spawn(move || child.wait());
+                    }
+
+                    Err(err) => {
+                        sender.output(MainAppMsg::ShowToast {
+                            title: tr!("prefix-manager-tool-failed", {
+                                "tool" = tool.name()
+                            }),
+                            message: Some(err.to_string())
+                        }).unwrap();
+                    }
+                }
+            }
+
+            PrefixManagerAppMsg::InstallVerb(verb) => {
+                let Some(wine) = self.resolved_wine.clone() else {
+                    return;
+                };
+
+                sender.output(MainAppMsg::AddInstallVerbsTask {
+                    wine,
+                    verbs: vec![verb],
+                    prefix: self.prefix.clone()
+                }).unwrap();
+
+                sender.output(MainAppMsg::ShowTasksFlap).unwrap();
+            }
+
+            PrefixManagerAppMsg::Repair => {
+                sender.output(MainAppMsg::AddCreatePrefixTask {
+                    path: self.prefix.clone(),
+                    install_corefonts: config::get().components.wine.prefix.install_corefonts,
+                    wine: self.wine.clone(),
+                    dxvk: self.dxvk.clone()
+                }).unwrap();
+
+                sender.output(MainAppMsg::ShowTasksFlap).unwrap();
+            }
+
+            PrefixManagerAppMsg::Reset => {
+                let window = unsafe {
+                    WINDOW.as_ref().unwrap_unchecked()
+                };
+
+                let dialog = adw::MessageDialog::new(
+                    Some(window),
+                    Some(&tr!("prefix-manager-reset")),
+                    Some(&tr!("prefix-manager-reset-confirm", {
+                        "path" = self.prefix.to_string_lossy().to_string()
+                    }))
+                );
+
+                dialog.add_response("cancel", &tr!("dialog-cancel"));
+                dialog.add_response("reset", &tr!("prefix-manager-reset"));
+
+                dialog.set_response_appearance("reset", adw::ResponseAppearance::Destructive);
+
+                let prefix = self.prefix.clone();
+                let wine = self.wine.clone();
+                let dxvk = self.dxvk.clone();
+                let is_proton = self.is_proton();
+
+                dialog.connect_response(Some("reset"), move |_, _| {
+                    // Reset prefix can be reverted by restoring the snapshot
+                    if config::get().components.wine.prefix.snapshots.enabled {
+                        sender.output(MainAppMsg::AddSnapshotPrefixTask {
+                            prefix: prefix.clone(),
+                            action: SnapshotAction::Create
+                        }).unwrap();
+                    }
+
+                    sender.output(MainAppMsg::AddDeleteFilesTask(vec![prefix.clone()])).unwrap();
+
+                    // Proton will create new prefix on the next launch
+                    if !is_proton {
+                        sender.output(MainAppMsg::AddCreatePrefixTask {
+                            path: prefix.clone(),
+                            install_corefonts: config::get().components.wine.prefix.install_corefonts,
+                            wine: wine.clone(),
+                            dxvk: dxvk.clone()
+                        }).unwrap();
+                    }
+
+                    sender.output(MainAppMsg::ShowTasksFlap).unwrap();
+                });
+
+                dialog.present();
+            }
+
+            PrefixManagerAppMsg::CreateSnapshot => {
+                sender.output(MainAppMsg::AddSnapshotPrefixTask {
+                    prefix: self.prefix.clone(),
+                    action: SnapshotAction::Create
+                }).unwrap();
+
+                sender.output(MainAppMsg::ShowTasksFlap).unwrap();
+            }
+
+            PrefixManagerAppMsg::RestoreSnapshot(snapshot) => {
+                if games::is_any_running() {
+                    sender.output(MainAppMsg::ShowToast {
+                        title: tr!("prefix-manager-snapshot-restore-game-running"),
+                        message: None
+                    }).unwrap();
+
+                    return;
+                }
+
+                let window = unsafe {
+                    WINDOW.as_ref().unwrap_unchecked()
+                };
+
+                let dialog = adw::MessageDialog::new(
+                    Some(window),
+                    Some(&tr!("prefix-manager-snapshot-restore")),
+                    Some(&tr!("prefix-manager-snapshot-restore-confirm", {
+                        "date" = snapshot.get_created_at_text()
+                    }))
+                );
+
+                dialog.add_response("cancel", &tr!("dialog-cancel"));
+                dialog.add_response("restore", &tr!("prefix-manager-snapshot-restore"));
+
+                dialog.set_response_appearance("restore", adw::ResponseAppearance::Destructive);
+
+                let prefix = self.prefix.clone();
+
+                dialog.connect_response(Some("restore"), move |_, _| {
+                    sender.output(MainAppMsg::AddSnapshotPrefixTask {
+                        prefix: prefix.clone(),
+                        action: SnapshotAction::Restore(snapshot.clone())
+                    }).unwrap();
+
+                    sender.output(MainAppMsg::ShowTasksFlap).unwrap();
+                });
+
+                dialog.present();
+            }
+
+            PrefixManagerAppMsg::DeleteSnapshot(snapshot) => {
+                sender.output(MainAppMsg::AddDeleteFilesTask(vec![snapshot.path])).unwrap();
+                sender.output(MainAppMsg::ShowTasksFlap).unwrap();
+            }
+        }
+    }
+}
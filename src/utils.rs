@@ -1,3 +1,5 @@
+use std::path::Path;
+
 pub fn pretty_bytes(bytes: u64) -> String {
     if bytes <= 1024 {
         format!("{bytes} B")
@@ -41,3 +43,22 @@ pub fn pretty_seconds(mut seconds: u64) -> String {
 
     format!("{hours}:{minutes}:{seconds}")
 }
+
+/// Get total size of the files in the folder
+pub fn get_folder_size(path: impl AsRef<Path>) -> std::io::Result<u64> {
+    let mut size = 0;
+
+    for entry in path.as_ref().read_dir()?.flatten() {
+        let file_type = entry.file_type()?;
+
+        if file_type.is_dir() {
+            size += get_folder_size(entry.path())?;
+        }
+
+        else if file_type.is_file() {
+            size += entry.metadata()?.len();
+        }
+    }
+
+    Ok(size)
+}